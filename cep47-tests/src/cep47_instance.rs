@@ -1,16 +1,50 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use blake2::{
     digest::{Update, VariableOutput},
     VarBlake2b,
 };
 use casper_types::{
-    account::AccountHash, bytesrepr::ToBytes, runtime_args, CLTyped, Key, RuntimeArgs, U256,
+    account::AccountHash,
+    bytesrepr::{self, Bytes, FromBytes, ToBytes},
+    runtime_args, CLType, CLTyped, ContractHash, Key, PublicKey, RuntimeArgs, U256,
 };
 use test_env::{TestContract, TestEnv};
 
 pub type TokenId = U256;
 pub type Meta = BTreeMap<String, String>;
+pub type TypedMeta = BTreeMap<String, (u8, String)>;
+
+pub const RARITY_SCALE: u64 = 1_000_000;
+
+#[derive(Debug, PartialEq)]
+pub struct MintReceipt {
+    pub token_ids: Vec<TokenId>,
+    pub new_total_supply: U256,
+    pub block_time: u64,
+}
+
+impl CLTyped for MintReceipt {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl FromBytes for MintReceipt {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (token_ids, remainder) = Vec::<TokenId>::from_bytes(bytes)?;
+        let (new_total_supply, remainder) = U256::from_bytes(remainder)?;
+        let (block_time, remainder) = u64::from_bytes(remainder)?;
+        Ok((
+            MintReceipt {
+                token_ids,
+                new_total_supply,
+                block_time,
+            },
+            remainder,
+        ))
+    }
+}
 
 pub struct CEP47Instance(TestContract);
 
@@ -23,16 +57,149 @@ impl CEP47Instance {
         symbol: &str,
         meta: Meta,
     ) -> CEP47Instance {
-        CEP47Instance(TestContract::new(
+        CEP47Instance::new_with_token_id_salt(env, contract_name, sender, name, symbol, meta, None)
+    }
+
+    pub fn new_with_token_id_salt(
+        env: &TestEnv,
+        contract_name: &str,
+        sender: AccountHash,
+        name: &str,
+        symbol: &str,
+        meta: Meta,
+        token_id_salt: Option<String>,
+    ) -> CEP47Instance {
+        CEP47Instance::deploy_with_args(
             env,
-            "cep47-token.wasm",
             contract_name,
             sender,
             runtime_args! {
                 "name" => name,
                 "symbol" => symbol,
-                "meta" => meta
+                "meta" => meta,
+                "token_id_salt" => token_id_salt,
+                "immutable_metadata" => false,
+                "require_explicit_ids" => false,
+                "creator" => Option::<Key>::None,
+                "genesis_mints" => Vec::<(Key, Meta)>::new()
+            },
+        )
+    }
+
+    pub fn new_with_immutable_metadata(
+        env: &TestEnv,
+        contract_name: &str,
+        sender: AccountHash,
+        name: &str,
+        symbol: &str,
+        meta: Meta,
+    ) -> CEP47Instance {
+        CEP47Instance::deploy_with_args(
+            env,
+            contract_name,
+            sender,
+            runtime_args! {
+                "name" => name,
+                "symbol" => symbol,
+                "meta" => meta,
+                "token_id_salt" => Option::<String>::None,
+                "immutable_metadata" => true,
+                "require_explicit_ids" => false,
+                "creator" => Option::<Key>::None,
+                "genesis_mints" => Vec::<(Key, Meta)>::new()
+            },
+        )
+    }
+
+    pub fn new_with_creator<T: Into<Key>>(
+        env: &TestEnv,
+        contract_name: &str,
+        sender: AccountHash,
+        name: &str,
+        symbol: &str,
+        meta: Meta,
+        creator: T,
+    ) -> CEP47Instance {
+        CEP47Instance::deploy_with_args(
+            env,
+            contract_name,
+            sender,
+            runtime_args! {
+                "name" => name,
+                "symbol" => symbol,
+                "meta" => meta,
+                "token_id_salt" => Option::<String>::None,
+                "immutable_metadata" => false,
+                "require_explicit_ids" => false,
+                "creator" => Some(creator.into()),
+                "genesis_mints" => Vec::<(Key, Meta)>::new()
+            },
+        )
+    }
+
+    pub fn new_with_genesis_mints(
+        env: &TestEnv,
+        contract_name: &str,
+        sender: AccountHash,
+        name: &str,
+        symbol: &str,
+        meta: Meta,
+        genesis_mints: Vec<(Key, Meta)>,
+    ) -> CEP47Instance {
+        CEP47Instance::deploy_with_args(
+            env,
+            contract_name,
+            sender,
+            runtime_args! {
+                "name" => name,
+                "symbol" => symbol,
+                "meta" => meta,
+                "token_id_salt" => Option::<String>::None,
+                "immutable_metadata" => false,
+                "require_explicit_ids" => false,
+                "creator" => Option::<Key>::None,
+                "genesis_mints" => genesis_mints
+            },
+        )
+    }
+
+    pub fn new_with_require_explicit_ids(
+        env: &TestEnv,
+        contract_name: &str,
+        sender: AccountHash,
+        name: &str,
+        symbol: &str,
+        meta: Meta,
+    ) -> CEP47Instance {
+        CEP47Instance::deploy_with_args(
+            env,
+            contract_name,
+            sender,
+            runtime_args! {
+                "name" => name,
+                "symbol" => symbol,
+                "meta" => meta,
+                "token_id_salt" => Option::<String>::None,
+                "immutable_metadata" => false,
+                "require_explicit_ids" => true,
+                "creator" => Option::<Key>::None,
+                "genesis_mints" => Vec::<(Key, Meta)>::new()
             },
+        )
+    }
+
+    fn deploy_with_args(
+        env: &TestEnv,
+        contract_name: &str,
+        sender: AccountHash,
+        args: RuntimeArgs,
+    ) -> CEP47Instance {
+        CEP47Instance(TestContract::new(
+            env,
+            "cep47-token.wasm",
+            contract_name,
+            sender,
+            args,
         ))
     }
 
@@ -43,7 +210,12 @@ impl CEP47Instance {
             runtime_args! {
             "name" => name,
             "symbol" => symbol,
-            "meta" => meta},
+            "meta" => meta,
+            "token_id_salt" => Option::<String>::None,
+            "immutable_metadata" => false,
+            "require_explicit_ids" => false,
+            "creator" => Option::<Key>::None,
+            "genesis_mints" => Vec::<(Key, Meta)>::new()},
         );
     }
 
@@ -59,7 +231,7 @@ impl CEP47Instance {
             "mint",
             runtime_args! {
                 "recipient" => recipient.into(),
-                "token_ids" => vec![token_id],
+                "token_ids" => Some(vec![token_id]),
                 "token_metas" => vec![token_meta]
             },
         )
@@ -78,7 +250,7 @@ impl CEP47Instance {
             "mint_copies",
             runtime_args! {
                 "recipient" => recipient.into(),
-                "token_ids" => token_ids,
+                "token_ids" => Some(token_ids),
                 "token_meta" => token_meta,
                 "count" => count
             },
@@ -97,130 +269,1576 @@ impl CEP47Instance {
             "mint",
             runtime_args! {
                 "recipient" => recipient.into(),
-                "token_ids" => token_ids,
+                "token_ids" => Some(token_ids),
                 "token_metas" => token_metas
             },
         )
     }
 
-    pub fn burn_one<T: Into<Key>>(&self, sender: AccountHash, owner: T, token_id: TokenId) {
+    pub fn mint_idempotent<T: Into<Key>>(
+        &self,
+        sender: AccountHash,
+        recipient: T,
+        token_metas: Vec<Meta>,
+        idempotency_key: &str,
+    ) {
         self.0.call_contract(
             sender,
-            "burn",
+            "mint_idempotent",
             runtime_args! {
-                "owner" => owner.into(),
-                "token_ids" => vec![token_id]
+                "recipient" => recipient.into(),
+                "token_ids" => Option::<Vec<TokenId>>::None,
+                "token_metas" => token_metas,
+                "idempotency_key" => String::from(idempotency_key)
             },
         )
     }
 
-    pub fn burn_many<T: Into<Key>>(&self, sender: AccountHash, owner: T, token_ids: Vec<TokenId>) {
+    pub fn mint_for_self(&self, sender: AccountHash, token_metas: Vec<Meta>) {
         self.0.call_contract(
             sender,
-            "burn",
+            "mint_for_self",
             runtime_args! {
-                "owner" => owner.into(),
-                "token_ids" => token_ids
+                "token_metas" => token_metas
             },
         )
     }
 
-    pub fn transfer<T: Into<Key>>(
+    pub fn validate_mint<T: Into<Key>>(
         &self,
         sender: AccountHash,
         recipient: T,
-        token_ids: Vec<TokenId>,
+        token_ids: Option<Vec<TokenId>>,
+        token_metas: Vec<Meta>,
     ) {
         self.0.call_contract(
             sender,
-            "transfer",
+            "validate_mint",
             runtime_args! {
                 "recipient" => recipient.into(),
-                "token_ids" => token_ids
+                "token_ids" => token_ids,
+                "token_metas" => token_metas
             },
         )
     }
 
-    pub fn transfer_from<T: Into<Key>>(
+    pub fn enqueue_mint<T: Into<Key>>(&self, sender: AccountHash, recipient: T, token_meta: Meta) {
+        self.0.call_contract(
+            sender,
+            "enqueue_mint",
+            runtime_args! {
+                "recipient" => recipient.into(),
+                "token_meta" => token_meta
+            },
+        )
+    }
+
+    pub fn process_mint_queue(&self, sender: AccountHash, count: u32) {
+        self.0.call_contract(
+            sender,
+            "process_mint_queue",
+            runtime_args! {
+                "count" => count
+            },
+        )
+    }
+
+    pub fn mint_queue_len(&self) -> u64 {
+        let head: u64 = self.0.query_named_key(String::from("mint_queue_head"));
+        let tail: u64 = self.0.query_named_key(String::from("mint_queue_tail"));
+        tail - head
+    }
+
+    pub fn mint_random<T: Into<Key>>(
         &self,
         sender: AccountHash,
-        owner: T,
         recipient: T,
-        token_ids: Vec<TokenId>,
+        templates: Vec<(u32, Meta)>,
     ) {
         self.0.call_contract(
             sender,
-            "transfer_from",
+            "mint_random",
             runtime_args! {
-                "sender" => owner.into(),
                 "recipient" => recipient.into(),
-                "token_ids" => token_ids
+                "templates" => templates
             },
         )
     }
 
-    pub fn approve<T: Into<Key>>(&self, sender: AccountHash, spender: T, token_ids: Vec<TokenId>) {
+    pub fn forge(&self, sender: AccountHash, burn_ids: Vec<TokenId>, new_meta: Meta) {
         self.0.call_contract(
             sender,
-            "approve",
-            runtime_args! {"spender" => spender.into(), "token_ids" => token_ids},
+            "forge",
+            runtime_args! {
+                "burn_ids" => burn_ids,
+                "new_meta" => new_meta
+            },
         )
     }
 
-    pub fn get_approved<T: Into<Key>>(&self, owner: T, token_id: TokenId) -> Option<Key> {
-        self.0.query_dictionary(
-            "allowances",
-            key_and_value_to_str::<String>(&owner.into(), &token_id.to_string()),
+    pub fn mint_copies_multi<T: Into<Key>>(
+        &self,
+        sender: AccountHash,
+        recipient: T,
+        metas_and_counts: Vec<(Meta, u32)>,
+    ) {
+        self.0.call_contract(
+            sender,
+            "mint_copies_multi",
+            runtime_args! {
+                "recipient" => recipient.into(),
+                "metas_and_counts" => metas_and_counts
+            },
         )
     }
 
-    pub fn update_token_meta(&self, sender: AccountHash, token_id: TokenId, token_meta: Meta) {
+    pub fn mint_generated<T: Into<Key>>(
+        &self,
+        sender: AccountHash,
+        recipient: T,
+        token_metas: Vec<Meta>,
+    ) {
         self.0.call_contract(
             sender,
-            "update_token_meta",
+            "mint",
             runtime_args! {
-                "token_id" => token_id,
-                "token_meta" => token_meta
+                "recipient" => recipient.into(),
+                "token_ids" => Option::<Vec<TokenId>>::None,
+                "token_metas" => token_metas
             },
         )
     }
 
-    pub fn get_token_by_index<T: Into<Key>>(&self, account: T, index: U256) -> Option<TokenId> {
-        self.0.query_dictionary(
-            "owned_tokens_by_index",
-            key_and_value_to_str(&account.into(), &index),
+    pub fn mint_with_expiry<T: Into<Key>>(
+        &self,
+        sender: AccountHash,
+        recipient: T,
+        token_id: TokenId,
+        token_meta: Meta,
+        expires_at: u64,
+    ) {
+        self.0.call_contract(
+            sender,
+            "mint_with_expiry",
+            runtime_args! {
+                "recipient" => recipient.into(),
+                "token_ids" => Some(vec![token_id]),
+                "token_metas" => vec![token_meta],
+                "expires_at" => expires_at
+            },
         )
     }
 
-    pub fn balance_of<T: Into<Key>>(&self, account: T) -> U256 {
-        self.0
-            .query_dictionary("balances", key_to_str(&account.into()))
-            .unwrap_or_default()
-    }
-
-    pub fn owner_of(&self, token_id: TokenId) -> Option<Key> {
-        self.0.query_dictionary("owners", token_id.to_string())
+    pub fn token_expiry(&self, token_id: TokenId) -> Option<u64> {
+        self.0.query_dictionary("expiries", token_id.to_string())
     }
 
-    pub fn token_meta(&self, token_id: TokenId) -> Option<Meta> {
-        self.0.query_dictionary("metadata", token_id.to_string())
+    pub fn burn_one<T: Into<Key>>(&self, sender: AccountHash, owner: T, token_id: TokenId) {
+        self.0.call_contract(
+            sender,
+            "burn",
+            runtime_args! {
+                "owner" => owner.into(),
+                "token_ids" => vec![token_id]
+            },
+        )
     }
 
-    pub fn name(&self) -> String {
-        self.0.query_named_key(String::from("name"))
+    pub fn grant_token_role<T: Into<Key>>(
+        &self,
+        sender: AccountHash,
+        token_id: TokenId,
+        role: &str,
+        account: T,
+    ) {
+        self.0.call_contract(
+            sender,
+            "grant_token_role",
+            runtime_args! {
+                "token_id" => token_id,
+                "role" => String::from(role),
+                "account" => account.into()
+            },
+        )
     }
 
-    pub fn symbol(&self) -> String {
-        self.0.query_named_key(String::from("symbol"))
+    pub fn revoke_token_role<T: Into<Key>>(
+        &self,
+        sender: AccountHash,
+        token_id: TokenId,
+        role: &str,
+        account: T,
+    ) {
+        self.0.call_contract(
+            sender,
+            "revoke_token_role",
+            runtime_args! {
+                "token_id" => token_id,
+                "role" => String::from(role),
+                "account" => account.into()
+            },
+        )
     }
 
-    pub fn total_supply(&self) -> U256 {
-        self.0.query_named_key(String::from("total_supply"))
+    pub fn simulate_transfer<T: Into<Key>>(
+        &self,
+        sender: AccountHash,
+        owner: T,
+        recipient: T,
+        token_ids: Vec<TokenId>,
+    ) {
+        self.0.call_contract(
+            sender,
+            "simulate_transfer",
+            runtime_args! {
+                "owner" => owner.into(),
+                "recipient" => recipient.into(),
+                "token_ids" => token_ids
+            },
+        )
     }
 
-    pub fn meta(&self) -> Meta {
+    pub fn burn_if_meta(&self, sender: AccountHash, token_id: TokenId, key: &str, expected: &str) {
+        self.0.call_contract(
+            sender,
+            "burn_if_meta",
+            runtime_args! {
+                "token_id" => token_id,
+                "key" => key.to_string(),
+                "expected" => expected.to_string()
+            },
+        )
+    }
+
+    pub fn burn_many<T: Into<Key>>(&self, sender: AccountHash, owner: T, token_ids: Vec<TokenId>) {
+        self.0.call_contract(
+            sender,
+            "burn",
+            runtime_args! {
+                "owner" => owner.into(),
+                "token_ids" => token_ids
+            },
+        )
+    }
+
+    pub fn redeem(&self, sender: AccountHash, token_id: TokenId, shipping_ref: &str) {
+        self.0.call_contract(
+            sender,
+            "redeem",
+            runtime_args! {
+                "token_id" => token_id,
+                "shipping_ref" => shipping_ref
+            },
+        )
+    }
+
+    pub fn transfer<T: Into<Key>>(
+        &self,
+        sender: AccountHash,
+        recipient: T,
+        token_ids: Vec<TokenId>,
+    ) {
+        self.0.call_contract(
+            sender,
+            "transfer",
+            runtime_args! {
+                "recipient" => recipient.into(),
+                "token_ids" => token_ids
+            },
+        )
+    }
+
+    pub fn transfer_from<T: Into<Key>>(
+        &self,
+        sender: AccountHash,
+        owner: T,
+        recipient: T,
+        token_ids: Vec<TokenId>,
+    ) {
+        self.0.call_contract(
+            sender,
+            "transfer_from",
+            runtime_args! {
+                "sender" => owner.into(),
+                "recipient" => recipient.into(),
+                "token_ids" => token_ids
+            },
+        )
+    }
+
+    pub fn transfer_and_approve<T: Into<Key>, O: Into<Key>>(
+        &self,
+        sender: AccountHash,
+        recipient: T,
+        token_id: TokenId,
+        operator: O,
+    ) {
+        self.0.call_contract(
+            sender,
+            "transfer_and_approve",
+            runtime_args! {
+                "recipient" => recipient.into(),
+                "token_id" => token_id,
+                "operator" => operator.into()
+            },
+        )
+    }
+
+    pub fn transfer_keep_operator<T: Into<Key>, O: Into<Key>>(
+        &self,
+        sender: AccountHash,
+        recipient: T,
+        token_id: TokenId,
+        operator: O,
+    ) {
+        self.0.call_contract(
+            sender,
+            "transfer_keep_operator",
+            runtime_args! {
+                "recipient" => recipient.into(),
+                "token_id" => token_id,
+                "operator" => operator.into()
+            },
+        )
+    }
+
+    pub fn safe_transfer_from<T: Into<Key>, R: Into<Key>>(
+        &self,
+        sender: AccountHash,
+        owner: T,
+        recipient: R,
+        token_ids: Vec<TokenId>,
+    ) {
+        self.0.call_contract(
+            sender,
+            "safe_transfer_from",
+            runtime_args! {
+                "sender" => owner.into(),
+                "recipient" => recipient.into(),
+                "token_ids" => token_ids
+            },
+        )
+    }
+
+    pub fn approve<T: Into<Key>>(&self, sender: AccountHash, spender: T, token_ids: Vec<TokenId>) {
+        self.0.call_contract(
+            sender,
+            "approve",
+            runtime_args! {"spender" => spender.into(), "token_ids" => token_ids},
+        )
+    }
+
+    pub fn has_approval<T: Into<Key>>(&self, owner: T, token_id: TokenId) -> bool {
+        self.get_approved(owner, token_id).is_some()
+    }
+
+    pub fn revoke(&self, sender: AccountHash, token_ids: Vec<TokenId>) {
+        self.0
+            .call_contract(sender, "revoke", runtime_args! {"token_ids" => token_ids})
+    }
+
+    pub fn get_approved<T: Into<Key>>(&self, owner: T, token_id: TokenId) -> Option<Key> {
+        self.0.query_dictionary(
+            "allowances",
+            key_and_value_to_str::<String>(&owner.into(), &token_id.to_string()),
+        )
+    }
+
+    pub fn approve_for_all_many(&self, sender: AccountHash, operators: Vec<Key>) {
+        self.0.call_contract(
+            sender,
+            "approve_for_all_many",
+            runtime_args! {"operators" => operators},
+        )
+    }
+
+    pub fn revoke_for_all_many(&self, sender: AccountHash, operators: Vec<Key>) {
+        self.0.call_contract(
+            sender,
+            "revoke_for_all_many",
+            runtime_args! {"operators" => operators},
+        )
+    }
+
+    pub fn set_approval_for_all<T: Into<Key>>(
+        &self,
+        sender: AccountHash,
+        operator: T,
+        approved: bool,
+    ) {
+        self.0.call_contract(
+            sender,
+            "set_approval_for_all",
+            runtime_args! {
+                "operator" => operator.into(),
+                "approved" => approved
+            },
+        )
+    }
+
+    pub fn is_approved_for_all<T: Into<Key>>(&self, owner: T, operator: T) -> bool {
+        self.0
+            .query_dictionary(
+                "operators",
+                key_and_value_to_str(&owner.into(), &operator.into()),
+            )
+            .unwrap_or_default()
+    }
+
+    pub fn can_transfer_all<T: Into<Key>>(
+        &self,
+        spender: T,
+        owner: T,
+        token_ids: Vec<TokenId>,
+    ) -> bool {
+        let spender = spender.into();
+        let owner = owner.into();
+        token_ids.iter().all(|token_id| {
+            self.owner_of(*token_id) == Some(owner)
+                && (spender == owner
+                    || self.is_approved_for_all(owner, spender)
+                    || self.get_approved(owner, *token_id) == Some(spender))
+        })
+    }
+
+    pub fn are_approved_for_all<T: Into<Key>>(&self, pairs: Vec<(T, T)>) -> Vec<bool> {
+        pairs
+            .into_iter()
+            .map(|(owner, operator)| self.is_approved_for_all(owner, operator))
+            .collect()
+    }
+
+    pub fn delegate_approval<T: Into<Key>>(
+        &self,
+        sender: AccountHash,
+        token_id: TokenId,
+        new_spender: T,
+    ) {
+        self.0.call_contract(
+            sender,
+            "delegate_approval",
+            runtime_args! {
+                "token_id" => token_id,
+                "new_spender" => new_spender.into()
+            },
+        )
+    }
+
+    pub fn approval_written<T: Into<Key>>(&self, owner: T, token_id: TokenId) -> bool {
+        self.0.dictionary_item_written(
+            "allowances",
+            key_and_value_to_str::<String>(&owner.into(), &token_id.to_string()),
+        )
+    }
+
+    pub fn set_paused(&self, sender: AccountHash, paused: bool) {
+        self.0.call_contract(
+            sender,
+            "set_paused",
+            runtime_args! {
+                "paused" => paused
+            },
+        )
+    }
+
+    pub fn set_admins(&self, sender: AccountHash, admins: Vec<Key>, threshold: u8) {
+        self.0.call_contract(
+            sender,
+            "set_admins",
+            runtime_args! {
+                "admins" => admins,
+                "threshold" => threshold
+            },
+        )
+    }
+
+    pub fn renounce_admin(&self, sender: AccountHash) {
+        self.0
+            .call_contract(sender, "renounce_admin", runtime_args! {})
+    }
+
+    pub fn admins_renounced(&self) -> bool {
+        self.0.query_named_key(String::from("admins_renounced"))
+    }
+
+    pub fn is_admin<T: Into<Key>>(&self, account: T) -> bool {
+        let admins: Vec<Key> = self.0.query_named_key(String::from("admin_list"));
+        !self.admins_renounced() && admins.contains(&account.into())
+    }
+
+    pub fn admin_threshold(&self) -> u8 {
+        self.0.query_named_key(String::from("admin_threshold"))
+    }
+
+    pub fn paused(&self) -> bool {
+        self.0.query_named_key(String::from("paused"))
+    }
+
+    pub fn set_transfers_paused(&self, sender: AccountHash, paused: bool) {
+        self.0.call_contract(
+            sender,
+            "set_transfers_paused",
+            runtime_args! {
+                "paused" => paused
+            },
+        )
+    }
+
+    pub fn set_max_meta_bytes(&self, sender: AccountHash, limit: u32) {
+        self.0.call_contract(
+            sender,
+            "set_max_meta_bytes",
+            runtime_args! {
+                "limit" => limit
+            },
+        )
+    }
+
+    pub fn set_metadata_delta_events(&self, sender: AccountHash, enabled: bool) {
+        self.0.call_contract(
+            sender,
+            "set_metadata_delta_events",
+            runtime_args! {
+                "enabled" => enabled
+            },
+        )
+    }
+
+    pub fn set_notify_on_mint(&self, sender: AccountHash, enabled: bool) {
+        self.0.call_contract(
+            sender,
+            "set_notify_on_mint",
+            runtime_args! {
+                "enabled" => enabled
+            },
+        )
+    }
+
+    pub fn set_transfer_restricted(&self, sender: AccountHash, enabled: bool) {
+        self.0.call_contract(
+            sender,
+            "set_transfer_restricted",
+            runtime_args! {
+                "enabled" => enabled
+            },
+        )
+    }
+
+    pub fn set_transfer_allowlisted(&self, sender: AccountHash, accounts: Vec<Key>, allowed: bool) {
+        self.0.call_contract(
+            sender,
+            "set_transfer_allowlisted",
+            runtime_args! {
+                "accounts" => accounts,
+                "allowed" => allowed
+            },
+        )
+    }
+
+    pub fn set_stamp_on_transfer(&self, sender: AccountHash, enabled: bool) {
+        self.0.call_contract(
+            sender,
+            "set_stamp_on_transfer",
+            runtime_args! {
+                "enabled" => enabled
+            },
+        )
+    }
+
+    pub fn announce_supply(&self, sender: AccountHash) {
+        self.0
+            .call_contract(sender, "announce_supply", runtime_args! {})
+    }
+
+    pub fn set_debug_errors(&self, sender: AccountHash, enabled: bool) {
+        self.0.call_contract(
+            sender,
+            "set_debug_errors",
+            runtime_args! {
+                "enabled" => enabled
+            },
+        )
+    }
+
+    pub fn last_error(&self) -> Option<String> {
+        self.0.query_named_key(String::from("last_error"))
+    }
+
+    pub fn set_burn_meta_events(&self, sender: AccountHash, enabled: bool) {
+        self.0.call_contract(
+            sender,
+            "set_burn_meta_events",
+            runtime_args! {
+                "enabled" => enabled
+            },
+        )
+    }
+
+    pub fn update_token_meta(&self, sender: AccountHash, token_id: TokenId, token_meta: Meta) {
+        self.0.call_contract(
+            sender,
+            "update_token_meta",
+            runtime_args! {
+                "token_id" => token_id,
+                "token_meta" => token_meta
+            },
+        )
+    }
+
+    pub fn update_token_typed_meta(
+        &self,
+        sender: AccountHash,
+        token_id: TokenId,
+        token_meta: TypedMeta,
+    ) {
+        self.0.call_contract(
+            sender,
+            "update_token_typed_meta",
+            runtime_args! {
+                "token_id" => token_id,
+                "token_meta" => token_meta
+            },
+        )
+    }
+
+    pub fn token_typed_meta(&self, token_id: TokenId) -> Option<TypedMeta> {
+        self.0
+            .query_dictionary("typed_metadata", token_id.to_string())
+    }
+
+    pub fn set_unique_meta_key(&self, sender: AccountHash, key: Option<String>) {
+        self.0.call_contract(
+            sender,
+            "set_unique_meta_key",
+            runtime_args! {
+                "key" => key
+            },
+        )
+    }
+
+    pub fn set_token_frozen(&self, sender: AccountHash, token_id: TokenId, frozen: bool) {
+        self.0.call_contract(
+            sender,
+            "set_token_frozen",
+            runtime_args! {
+                "token_id" => token_id,
+                "frozen" => frozen
+            },
+        )
+    }
+
+    pub fn is_frozen(&self, token_id: TokenId) -> bool {
+        self.0
+            .query_dictionary("frozen_tokens", token_id.to_string())
+            .unwrap_or_default()
+    }
+
+    pub fn lock_mint_id(&self, sender: AccountHash, token_id: TokenId) {
+        self.0.call_contract(
+            sender,
+            "lock_mint_id",
+            runtime_args! {
+                "token_id" => token_id
+            },
+        )
+    }
+
+    pub fn unlock_mint_id(&self, sender: AccountHash, token_id: TokenId) {
+        self.0.call_contract(
+            sender,
+            "unlock_mint_id",
+            runtime_args! {
+                "token_id" => token_id
+            },
+        )
+    }
+
+    pub fn is_mint_id_locked(&self, token_id: TokenId) -> bool {
+        self.0
+            .query_dictionary("mint_locked_ids", token_id.to_string())
+            .unwrap_or_default()
+    }
+
+    pub fn set_meta_signer(&self, sender: AccountHash, signer: Option<PublicKey>) {
+        self.0.call_contract(
+            sender,
+            "set_meta_signer",
+            runtime_args! {
+                "signer" => signer
+            },
+        )
+    }
+
+    pub fn update_token_meta_signed(
+        &self,
+        sender: AccountHash,
+        token_id: TokenId,
+        token_meta: Meta,
+        nonce: u64,
+        signature: Bytes,
+    ) {
+        self.0.call_contract(
+            sender,
+            "update_token_meta_signed",
+            runtime_args! {
+                "token_id" => token_id,
+                "token_meta" => token_meta,
+                "nonce" => nonce,
+                "signature" => signature
+            },
+        )
+    }
+
+    pub fn total_approvals(&self) -> U256 {
+        self.0.query_named_key(String::from("total_approvals"))
+    }
+
+    pub fn set_token_royalties(&self, sender: AccountHash, entries: Vec<(TokenId, Key, u16)>) {
+        self.0.call_contract(
+            sender,
+            "set_token_royalties",
+            runtime_args! {
+                "entries" => entries
+            },
+        )
+    }
+
+    pub fn token_royalty(&self, token_id: TokenId) -> Option<(Key, u16)> {
+        self.0
+            .query_dictionary("token_royalties", token_id.to_string())
+            .or_else(|| self.owner_of(token_id).map(|_| (self.creator(), 0)))
+    }
+
+    pub fn set_transfer_fee<T: Into<Key>>(&self, sender: AccountHash, bps: u16, treasury: T) {
+        self.0.call_contract(
+            sender,
+            "set_transfer_fee",
+            runtime_args! {
+                "bps" => bps,
+                "treasury" => Some(treasury.into())
+            },
+        )
+    }
+
+    pub fn set_token_fee(&self, sender: AccountHash, token_id: TokenId, bps: Option<u16>) {
+        self.0.call_contract(
+            sender,
+            "set_token_fee",
+            runtime_args! {
+                "token_id" => token_id,
+                "bps" => bps
+            },
+        )
+    }
+
+    pub fn token_fee(&self, token_id: TokenId) -> Option<u16> {
+        self.0.query_dictionary("token_fees", token_id.to_string())
+    }
+
+    pub fn transfer_with_fee<T: Into<Key>>(
+        &self,
+        sender: AccountHash,
+        recipient: T,
+        token_ids: Vec<TokenId>,
+        sale_price: U256,
+    ) {
+        self.0.call_contract(
+            sender,
+            "transfer_with_fee",
+            runtime_args! {
+                "recipient" => recipient.into(),
+                "token_ids" => token_ids,
+                "sale_price" => sale_price
+            },
+        )
+    }
+
+    pub fn set_transfer_cooldown(&self, sender: AccountHash, cooldown: u64) {
+        self.0.call_contract(
+            sender,
+            "set_transfer_cooldown",
+            runtime_args! {
+                "cooldown" => cooldown
+            },
+        )
+    }
+
+    pub fn set_mint_lock_period(&self, sender: AccountHash, period: u64) {
+        self.0.call_contract(
+            sender,
+            "set_mint_lock_period",
+            runtime_args! {
+                "period" => period
+            },
+        )
+    }
+
+    pub fn last_transfer_time(&self, token_id: TokenId) -> Option<u64> {
+        self.0
+            .query_dictionary("last_transfer", token_id.to_string())
+    }
+
+    pub fn set_max_approvals_per_owner(&self, sender: AccountHash, limit: Option<u32>) {
+        self.0.call_contract(
+            sender,
+            "set_max_approvals_per_owner",
+            runtime_args! {
+                "limit" => limit
+            },
+        )
+    }
+
+    pub fn approval_count<T: Into<Key>>(&self, owner: T) -> u32 {
+        self.0
+            .query_dictionary("approval_counts", key_to_str(&owner.into()))
+            .unwrap_or_default()
+    }
+
+    pub fn set_max_mints_per_block(&self, sender: AccountHash, limit: Option<u32>) {
+        self.0.call_contract(
+            sender,
+            "set_max_mints_per_block",
+            runtime_args! {
+                "limit" => limit
+            },
+        )
+    }
+
+    pub fn set_recall_window(&self, sender: AccountHash, window: u64) {
+        self.0.call_contract(
+            sender,
+            "set_recall_window",
+            runtime_args! {
+                "window" => window
+            },
+        )
+    }
+
+    pub fn admin_burn(&self, sender: AccountHash, token_ids: Vec<TokenId>) {
+        self.0.call_contract(
+            sender,
+            "admin_burn",
+            runtime_args! {
+                "token_ids" => token_ids
+            },
+        )
+    }
+
+    pub fn recall(&self, sender: AccountHash, token_id: TokenId) {
+        self.0.call_contract(
+            sender,
+            "recall",
+            runtime_args! {
+                "token_id" => token_id
+            },
+        )
+    }
+
+    pub fn mint_time(&self, token_id: TokenId) -> Option<u64> {
+        self.0.query_dictionary("mint_times", token_id.to_string())
+    }
+
+    pub fn tokens_minted_between(
+        &self,
+        from: u64,
+        to: u64,
+        start: U256,
+        limit: u32,
+    ) -> Vec<TokenId> {
+        self.all_tokens(start, limit)
+            .into_iter()
+            .filter(|token_id| {
+                self.mint_time(*token_id)
+                    .map_or(false, |mint_time| from <= mint_time && mint_time <= to)
+            })
+            .collect()
+    }
+
+    pub fn minter_of(&self, token_id: TokenId) -> Option<Key> {
+        self.0.query_dictionary("minters", token_id.to_string())
+    }
+
+    pub fn set_total_supply_cap(&self, sender: AccountHash, cap: Option<U256>) {
+        self.0.call_contract(
+            sender,
+            "set_total_supply_cap",
+            runtime_args! {
+                "cap" => cap
+            },
+        )
+    }
+
+    pub fn get_token_by_index<T: Into<Key>>(&self, account: T, index: U256) -> Option<TokenId> {
+        self.0.query_dictionary(
+            "owned_tokens_by_index",
+            key_and_value_to_str(&account.into(), &index),
+        )
+    }
+
+    pub fn lock_approval(&self, sender: AccountHash, token_id: TokenId, until: u64) {
+        self.0.call_contract(
+            sender,
+            "lock_approval",
+            runtime_args! {
+                "token_id" => token_id,
+                "until" => until
+            },
+        )
+    }
+
+    pub fn my_approvals<T: Into<Key>>(&self, account: T, cap: u32) -> Vec<(TokenId, Key)> {
+        let account = account.into();
+        let balance = self.balance_of(account);
+        let mut approvals = Vec::new();
+        let mut index = U256::zero();
+        while index < balance && index < U256::from(cap) {
+            if let Some(token_id) = self.get_token_by_index(account, index) {
+                if let Some(spender) = self.get_approved(account, token_id) {
+                    approvals.push((token_id, spender));
+                }
+            }
+            index = index + 1;
+        }
+        approvals
+    }
+
+    pub fn holdings_diff<T: Into<Key>>(
+        &self,
+        a: T,
+        b: T,
+        cap: u32,
+    ) -> (Vec<TokenId>, Vec<TokenId>) {
+        let (a, b) = (a.into(), b.into());
+        if a == b {
+            return (Vec::new(), Vec::new());
+        }
+        (self.owner_tokens(a, cap), self.owner_tokens(b, cap))
+    }
+
+    pub fn owner_tokens<T: Into<Key>>(&self, account: T, cap: u32) -> Vec<TokenId> {
+        let account = account.into();
+        let balance = self.balance_of(account);
+        let mut token_ids = Vec::new();
+        let mut index = U256::zero();
+        while index < balance && token_ids.len() < cap as usize {
+            if let Some(token_id) = self.get_token_by_index(account, index) {
+                token_ids.push(token_id);
+            }
+            index = index + 1;
+        }
+        token_ids
+    }
+
+    pub fn holder_count(&self) -> u64 {
+        self.0.query_named_key(String::from("holder_count"))
+    }
+
+    pub fn aggregate_balance(&self, owners: Vec<Key>) -> U256 {
+        let owners: BTreeSet<Key> = owners.into_iter().collect();
+        owners
+            .into_iter()
+            .fold(U256::zero(), |sum, owner| sum + self.balance_of(owner))
+    }
+
+    pub fn ownership_histogram(&self, buckets: Vec<U256>, start: u64, limit: u32) -> Vec<u32> {
+        let end = std::cmp::min(start + limit as u64, self.holder_count());
+        let mut counts = vec![0u32; buckets.len()];
+        for index in start..end {
+            let holder: Key = self
+                .0
+                .query_dictionary("holders_by_index", index.to_string())
+                .unwrap();
+            let balance = self.balance_of(holder);
+            if let Some(bucket) = buckets.iter().rposition(|threshold| *threshold <= balance) {
+                counts[bucket] += 1;
+            }
+        }
+        counts
+    }
+
+    pub fn owner_token_meta_at<T: Into<Key>>(
+        &self,
+        account: T,
+        index: U256,
+    ) -> Option<(TokenId, Meta)> {
+        self.get_token_by_index(account, index)
+            .map(|token_id| (token_id, self.token_meta(token_id).unwrap_or_default()))
+    }
+
+    pub fn all_tokens(&self, start: U256, limit: u32) -> Vec<TokenId> {
+        let total_supply = self.total_supply();
+        let mut token_ids = Vec::new();
+        let mut index = start;
+        while index < total_supply && token_ids.len() < limit as usize {
+            token_ids.push(
+                self.0
+                    .query_dictionary("all_tokens_by_index", index.to_string())
+                    .unwrap(),
+            );
+            index = index + 1;
+        }
+        token_ids
+    }
+
+    pub fn burned_tokens(&self, start: U256, limit: u32) -> Vec<TokenId> {
+        let burned_count: U256 = self.0.query_named_key(String::from("burned_count"));
+        let mut token_ids = Vec::new();
+        let mut index = start;
+        while index < burned_count && token_ids.len() < limit as usize {
+            token_ids.push(
+                self.0
+                    .query_dictionary("burned_tokens_by_index", index.to_string())
+                    .unwrap(),
+            );
+            index = index + 1;
+        }
+        token_ids
+    }
+
+    pub fn tokens_with_attribute(
+        &self,
+        key: &str,
+        value: &str,
+        start: U256,
+        limit: u32,
+    ) -> Vec<TokenId> {
+        self.all_tokens(start, limit)
+            .into_iter()
+            .filter(|token_id| {
+                self.token_meta(*token_id).map_or(false, |meta| {
+                    meta.get(key).map(String::as_str) == Some(value)
+                })
+            })
+            .collect()
+    }
+
+    pub fn holds_exactly<T: Into<Key>>(&self, owner: T, token_ids: Vec<TokenId>) -> bool {
+        let owner = owner.into();
+        let token_ids: BTreeSet<TokenId> = token_ids.into_iter().collect();
+        self.balance_of(owner) == U256::from(token_ids.len())
+            && token_ids
+                .iter()
+                .all(|token_id| self.owner_of(*token_id) == Some(owner))
+    }
+
+    pub fn owner_has_attribute<T: Into<Key>>(
+        &self,
+        account: T,
+        key: &str,
+        value: &str,
+        cap: u32,
+    ) -> bool {
+        let account = account.into();
+        let balance = self.balance_of(account);
+        let mut index = U256::zero();
+        while index < balance && index < U256::from(cap) {
+            if let Some(token_id) = self.get_token_by_index(account, index) {
+                if self.token_meta(token_id).map_or(false, |meta| {
+                    meta.get(key).map(String::as_str) == Some(value)
+                }) {
+                    return true;
+                }
+            }
+            index = index + 1;
+        }
+        false
+    }
+
+    pub fn balance_of<T: Into<Key>>(&self, account: T) -> U256 {
+        self.0
+            .query_dictionary("balances", key_to_str(&account.into()))
+            .unwrap_or_default()
+    }
+
+    pub fn minted_count_of<T: Into<Key>>(&self, account: T) -> U256 {
+        self.0
+            .query_dictionary("minted_count", key_to_str(&account.into()))
+            .unwrap_or_default()
+    }
+
+    pub fn owner_of(&self, token_id: TokenId) -> Option<Key> {
+        self.0.query_dictionary("owners", token_id.to_string())
+    }
+
+    pub fn token_meta(&self, token_id: TokenId) -> Option<Meta> {
+        self.0
+            .query_dictionary("metadata", token_id.to_string())
+            .or_else(|| {
+                self.token_template(token_id)
+                    .and_then(|template_id| self.meta_template(template_id))
+            })
+    }
+
+    pub fn token_template(&self, token_id: TokenId) -> Option<u32> {
+        self.0
+            .query_dictionary("token_templates", token_id.to_string())
+    }
+
+    pub fn meta_template(&self, template_id: u32) -> Option<Meta> {
+        self.0
+            .query_dictionary("meta_templates", template_id.to_string())
+    }
+
+    pub fn set_meta_template(&self, sender: AccountHash, template_id: u32, meta: Meta) {
+        self.0.call_contract(
+            sender,
+            "set_meta_template",
+            runtime_args! {
+                "template_id" => template_id,
+                "meta" => meta
+            },
+        )
+    }
+
+    pub fn mint_from_template<T: Into<Key>>(
+        &self,
+        sender: AccountHash,
+        recipient: T,
+        template_id: u32,
+        count: u32,
+    ) {
+        self.0.call_contract(
+            sender,
+            "mint_from_template",
+            runtime_args! {
+                "recipient" => recipient.into(),
+                "template_id" => template_id,
+                "count" => count
+            },
+        )
+    }
+
+    pub fn reset_owner_index<T: Into<Key>>(&self, sender: AccountHash, owner: T, end: U256) {
+        self.0.call_contract(
+            sender,
+            "reset_owner_index",
+            runtime_args! {
+                "owner" => owner.into(),
+                "end" => end
+            },
+        )
+    }
+
+    pub fn rebuild_indices(&self, sender: AccountHash, token_ids: Vec<TokenId>) {
+        self.0.call_contract(
+            sender,
+            "rebuild_indices",
+            runtime_args! {
+                "token_ids" => token_ids
+            },
+        )
+    }
+
+    pub fn corrupt_balance<T: Into<Key>>(&self, account: T, balance: U256) {
+        self.0
+            .write_dictionary_item("balances", key_to_str(&account.into()), balance)
+    }
+
+    pub fn corrupt_token_by_index<T: Into<Key>>(&self, account: T, index: U256, token_id: TokenId) {
+        self.0.write_dictionary_item(
+            "owned_tokens_by_index",
+            key_and_value_to_str(&account.into(), &index),
+            token_id,
+        )
+    }
+
+    pub fn set_min_supply(&self, sender: AccountHash, min_supply: Option<U256>) {
+        self.0.call_contract(
+            sender,
+            "set_min_supply",
+            runtime_args! {
+                "min_supply" => min_supply
+            },
+        )
+    }
+
+    pub fn set_events_enabled(&self, sender: AccountHash, enabled: bool) {
+        self.0.call_contract(
+            sender,
+            "set_events_enabled",
+            runtime_args! {
+                "enabled" => enabled
+            },
+        )
+    }
+
+    pub fn set_recipient_policy(&self, sender: AccountHash, policy: u8) {
+        self.0.call_contract(
+            sender,
+            "set_recipient_policy",
+            runtime_args! {
+                "policy" => policy
+            },
+        )
+    }
+
+    pub fn recipient_policy(&self) -> u8 {
+        self.0.query_named_key(String::from("recipient_policy"))
+    }
+
+    pub fn creator(&self) -> Key {
+        self.0.query_named_key(String::from("creator"))
+    }
+
+    pub fn immutable_metadata(&self) -> bool {
+        self.0.query_named_key(String::from("immutable_metadata"))
+    }
+
+    pub fn require_explicit_ids(&self) -> bool {
+        self.0.query_named_key(String::from("require_explicit_ids"))
+    }
+
+    pub fn metadata_hash(&self, token_ids: Vec<TokenId>) -> [u8; 32] {
+        let mut hasher = VarBlake2b::new(32).unwrap();
+        for token_id in token_ids {
+            hasher.update(self.token_meta(token_id).to_bytes().unwrap());
+        }
+        let mut ret = [0u8; 32];
+        hasher.finalize_variable(|hash| ret.clone_from_slice(hash));
+        ret
+    }
+
+    pub fn is_burned(&self, token_id: TokenId) -> bool {
+        self.0
+            .query_dictionary("burned_tokens", token_id.to_string())
+            .unwrap_or_default()
+    }
+
+    pub fn exist_many(&self, token_ids: Vec<TokenId>) -> Vec<bool> {
+        token_ids
+            .into_iter()
+            .map(|token_id| self.owner_of(token_id).is_some())
+            .collect()
+    }
+
+    pub fn meta_versions(&self, token_ids: Vec<TokenId>) -> Vec<Option<u64>> {
+        token_ids
+            .into_iter()
+            .map(|token_id| {
+                self.owner_of(token_id).map(|_| {
+                    self.0
+                        .query_dictionary("meta_versions", token_id.to_string())
+                        .unwrap_or_default()
+                })
+            })
+            .collect()
+    }
+
+    pub fn owners_of(&self, token_ids: Vec<TokenId>) -> Vec<(TokenId, Option<Key>)> {
+        token_ids
+            .into_iter()
+            .map(|token_id| (token_id, self.owner_of(token_id)))
+            .collect()
+    }
+
+    pub fn was_ever_minted(&self, token_id: TokenId) -> bool {
+        self.owner_of(token_id).is_some() || self.is_burned(token_id)
+    }
+
+    pub fn token_detail(&self, token_id: TokenId) -> Option<(Key, Meta, Meta)> {
+        let owner = self.owner_of(token_id)?;
+        let token_meta = self.token_meta(token_id).unwrap_or_default();
+        Some((owner, token_meta, self.meta()))
+    }
+
+    pub fn meta_resolver_version(&self) -> u32 {
+        self.0
+            .query_named_key(String::from("meta_resolver_version"))
+    }
+
+    pub fn set_view_recorder<T: Into<Key>>(&self, sender: AccountHash, recorder: Option<T>) {
+        self.0.call_contract(
+            sender,
+            "set_view_recorder",
+            runtime_args! {
+                "recorder" => recorder.map(Into::into)
+            },
+        )
+    }
+
+    pub fn record_view(&self, sender: AccountHash, token_id: TokenId) {
+        self.0.call_contract(
+            sender,
+            "record_view",
+            runtime_args! {"token_id" => token_id},
+        )
+    }
+
+    pub fn rarity_score(&self, token_id: TokenId, trait_keys: Vec<&str>) -> U256 {
+        let token_meta = self.token_meta(token_id).unwrap_or_default();
+        let mut score = U256::zero();
+        for key in trait_keys {
+            if let Some(value) = token_meta.get(key) {
+                let count: u64 = self
+                    .0
+                    .query_dictionary("trait_counts", trait_key_to_str(key, value))
+                    .unwrap_or_default();
+                if count > 0 {
+                    score += U256::from(RARITY_SCALE) / U256::from(count);
+                }
+            }
+        }
+        score
+    }
+
+    pub fn view_count(&self, token_id: TokenId) -> u64 {
+        self.0
+            .query_dictionary("view_counts", token_id.to_string())
+            .unwrap_or_default()
+    }
+
+    pub fn set_dynamic_meta_contract(&self, sender: AccountHash, contract: Option<ContractHash>) {
+        self.0.call_contract(
+            sender,
+            "set_dynamic_meta_contract",
+            runtime_args! {
+                "contract" => contract
+            },
+        )
+    }
+
+    pub fn mint_with_receipt<T: Into<Key>>(
+        &self,
+        env: &TestEnv,
+        sender: AccountHash,
+        recipient: T,
+        token_ids: Option<Vec<TokenId>>,
+        token_metas: Vec<Meta>,
+    ) -> MintReceipt {
+        env.run_session(
+            sender,
+            "mint-receipt-proxy.wasm",
+            runtime_args! {
+                "contract_hash" => ContractHash::new(self.0.contract_hash()),
+                "recipient" => recipient.into(),
+                "token_ids" => token_ids,
+                "token_metas" => token_metas
+            },
+        );
+        env.query_account_named_key(sender, &[String::from("mint_receipt_result")])
+    }
+
+    pub fn mint_to_many(
+        &self,
+        env: &TestEnv,
+        sender: AccountHash,
+        recipients_and_metas: Vec<(Key, Meta)>,
+    ) -> Vec<(Key, TokenId)> {
+        env.run_session(
+            sender,
+            "mint-to-many-proxy.wasm",
+            runtime_args! {
+                "contract_hash" => ContractHash::new(self.0.contract_hash()),
+                "recipients_and_metas" => recipients_and_metas
+            },
+        );
+        env.query_account_named_key(sender, &[String::from("mint_to_many_result")])
+    }
+
+    // Returns the peeked ids; the proxy then mints `token_metas` to `recipient`.
+    pub fn peek_then_mint<T: Into<Key>>(
+        &self,
+        env: &TestEnv,
+        sender: AccountHash,
+        recipient: T,
+        token_metas: Vec<Meta>,
+    ) -> Vec<TokenId> {
+        env.run_session(
+            sender,
+            "peek-token-ids-proxy.wasm",
+            runtime_args! {
+                "contract_hash" => ContractHash::new(self.0.contract_hash()),
+                "recipient" => recipient.into(),
+                "token_metas" => token_metas
+            },
+        );
+        env.query_account_named_key(sender, &[String::from("peek_token_ids_result")])
+    }
+
+    pub fn dictionary_names(&self, env: &TestEnv, sender: AccountHash) -> Vec<String> {
+        env.run_session(
+            sender,
+            "dictionary-names-proxy.wasm",
+            runtime_args! {
+                "contract_hash" => ContractHash::new(self.0.contract_hash())
+            },
+        );
+        env.query_account_named_key(sender, &[String::from("dictionary_names_result")])
+    }
+
+    // Dictionary seeds are stored as unit values; the query panics for any
+    // other named key.
+    pub fn assert_dictionary_exists(&self, name: &str) {
+        self.0.query_named_key::<()>(String::from(name))
+    }
+
+    pub fn resolved_token_meta(
+        &self,
+        env: &TestEnv,
+        sender: AccountHash,
+        token_id: TokenId,
+    ) -> Option<Meta> {
+        self.call_token_meta_proxy(env, sender, "token_meta", token_id)
+    }
+
+    pub fn token_meta_resolved(
+        &self,
+        env: &TestEnv,
+        sender: AccountHash,
+        token_id: TokenId,
+    ) -> Option<Meta> {
+        self.call_token_meta_proxy(env, sender, "token_meta_resolved", token_id)
+    }
+
+    fn call_token_meta_proxy(
+        &self,
+        env: &TestEnv,
+        sender: AccountHash,
+        entry_point: &str,
+        token_id: TokenId,
+    ) -> Option<Meta> {
+        env.run_session(
+            sender,
+            "token-meta-proxy.wasm",
+            runtime_args! {
+                "contract_hash" => ContractHash::new(self.0.contract_hash()),
+                "entry_point" => String::from(entry_point),
+                "token_id" => token_id
+            },
+        );
+        env.query_account_named_key(sender, &[String::from("token_meta_result")])
+    }
+
+    pub fn set_default_meta(&self, sender: AccountHash, meta: Meta) {
+        self.0
+            .call_contract(sender, "set_default_meta", runtime_args! {"meta" => meta})
+    }
+
+    pub fn name(&self) -> String {
+        self.0.query_named_key(String::from("name"))
+    }
+
+    pub fn symbol(&self) -> String {
+        self.0.query_named_key(String::from("symbol"))
+    }
+
+    pub fn set_recent_events_capacity(&self, sender: AccountHash, capacity: u32) {
+        self.0.call_contract(
+            sender,
+            "set_recent_events_capacity",
+            runtime_args! {
+                "capacity" => capacity
+            },
+        )
+    }
+
+    pub fn recent_events(&self, count: u32) -> Vec<BTreeMap<String, String>> {
+        let capacity: u32 = self
+            .0
+            .query_named_key(String::from("recent_events_capacity"));
+        let total: u64 = self.0.query_named_key(String::from("recent_events_count"));
+        let count = total.min(capacity as u64).min(count as u64);
+        (total - count..total)
+            .filter_map(|index| {
+                self.0.query_dictionary::<Bytes>(
+                    "recent_events",
+                    (index % capacity as u64).to_string(),
+                )
+            })
+            .map(|bytes| BTreeMap::<String, String>::from_bytes(&bytes).unwrap().0)
+            .collect()
+    }
+
+    pub fn supports_interface(&self, interface_id: &str) -> bool {
+        self.0
+            .query_dictionary("supported_interfaces", interface_id.to_string())
+            .unwrap_or_default()
+    }
+
+    pub fn feature_flags(&self) -> u32 {
+        let flag = |name: &str| -> bool { self.0.query_named_key(String::from(name)) };
+        let transfer_fee = self
+            .0
+            .query_named_key::<u16>(String::from("transfer_fee_bps"))
+            > 0
+            && self
+                .0
+                .query_named_key::<Option<Key>>(String::from("fee_treasury"))
+                .is_some();
+        [
+            flag("paused"),
+            flag("transfers_paused"),
+            flag("immutable_metadata"),
+            flag("metadata_delta_events"),
+            flag("burn_meta_events"),
+            flag("events_enabled"),
+            transfer_fee,
+            self.total_supply_cap().is_some(),
+            self.0
+                .query_named_key::<Option<ContractHash>>(String::from("dynamic_meta_contract"))
+                .is_some(),
+            self.0
+                .query_named_key::<Option<PublicKey>>(String::from("meta_signer"))
+                .is_some(),
+            self.0
+                .query_named_key::<Option<String>>(String::from("unique_meta_key"))
+                .is_some(),
+            flag("debug_errors"),
+            flag("transfer_restricted"),
+            flag("notify_on_mint"),
+        ]
+        .iter()
+        .enumerate()
+        .filter(|(_, enabled)| **enabled)
+        .fold(0, |flags, (bit, _)| flags | 1 << bit)
+    }
+
+    pub fn event_schema_version(&self) -> u32 {
+        self.0.query_named_key(String::from("event_schema_version"))
+    }
+
+    pub fn genesis_supply(&self) -> U256 {
+        self.0.query_named_key(String::from("genesis_supply"))
+    }
+
+    pub fn total_supply(&self) -> U256 {
+        self.0.query_named_key(String::from("total_supply"))
+    }
+
+    pub fn total_supply_cap(&self) -> Option<U256> {
+        self.0.query_named_key(String::from("total_supply_cap"))
+    }
+
+    pub fn remaining_supply(&self) -> Option<U256> {
+        self.total_supply_cap()
+            .map(|cap| cap.saturating_sub(self.total_supply()))
+    }
+
+    pub fn meta(&self) -> Meta {
         self.0.query_named_key(String::from("meta"))
     }
+
+    pub fn set_nonce(&self, sender: AccountHash, value: u32) {
+        self.0.call_contract(
+            sender,
+            "set_nonce",
+            runtime_args! {
+                "value" => value
+            },
+        )
+    }
+
+    pub fn last_mint_time(&self) -> u64 {
+        self.0.query_named_key(String::from("last_mint_time"))
+    }
+
+    pub fn nonce(&self) -> u32 {
+        self.0.query_named_key(String::from("nonce"))
+    }
+
+    pub fn max_mints_per_block(&self) -> Option<u32> {
+        self.0.query_named_key(String::from("max_mints_per_block"))
+    }
 }
 
 pub fn key_to_str(key: &Key) -> String {
@@ -231,6 +1849,24 @@ pub fn key_to_str(key: &Key) -> String {
     }
 }
 
+pub fn generated_token_id(seed: &[u8], nonce: u32) -> TokenId {
+    let mut hasher = VarBlake2b::new(32).unwrap();
+    hasher.update(seed);
+    hasher.update(nonce.to_bytes().unwrap());
+    let mut ret = [0u8; 32];
+    hasher.finalize_variable(|hash| ret.clone_from_slice(hash));
+    TokenId::from_big_endian(&ret)
+}
+
+pub fn trait_key_to_str(key: &str, value: &str) -> String {
+    let mut hasher = VarBlake2b::new(32).unwrap();
+    hasher.update(key.to_bytes().unwrap());
+    hasher.update(value.to_bytes().unwrap());
+    let mut ret = [0u8; 32];
+    hasher.finalize_variable(|hash| ret.clone_from_slice(hash));
+    hex::encode(ret)
+}
+
 pub fn key_and_value_to_str<T: CLTyped + ToBytes>(key: &Key, value: &T) -> String {
     let mut hasher = VarBlake2b::new(32).unwrap();
     hasher.update(key.to_bytes().unwrap());
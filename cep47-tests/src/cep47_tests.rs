@@ -1,9 +1,17 @@
 use std::collections::BTreeMap;
 
-use casper_types::{account::AccountHash, Key, U256};
-use test_env::TestEnv;
+use blake2::{
+    digest::{Update, VariableOutput},
+    VarBlake2b,
+};
+use casper_types::{
+    account::AccountHash,
+    bytesrepr::{Bytes, ToBytes},
+    crypto, runtime_args, ContractHash, Key, PublicKey, RuntimeArgs, SecretKey, U256, U512,
+};
+use test_env::{TestContract, TestEnv};
 
-use crate::cep47_instance::{CEP47Instance, Meta, TokenId};
+use crate::cep47_instance::{generated_token_id, CEP47Instance, Meta, TokenId, TypedMeta};
 
 const NAME: &str = "DragonsNFT";
 const SYMBOL: &str = "DGNFT";
@@ -55,6 +63,13 @@ fn test_deploy() {
     assert_eq!(token.symbol(), SYMBOL);
     assert_eq!(token.meta(), meta::contract_meta());
     assert_eq!(token.total_supply(), U256::zero());
+    assert_eq!(token.genesis_supply(), U256::zero());
+}
+
+#[test]
+fn test_event_schema_version() {
+    let (_, token, _) = deploy();
+    assert_eq!(token.event_schema_version(), 13);
 }
 
 #[test]
@@ -386,3 +401,3726 @@ fn test_token_metadata_update() {
     token.update_token_meta(owner, token_id, meta::gold_dragon());
     assert_eq!(token.token_meta(token_id).unwrap(), meta::gold_dragon());
 }
+
+#[test]
+fn test_mint_rate_limit_within_block() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+
+    token.set_max_mints_per_block(owner, Some(2));
+    assert_eq!(token.max_mints_per_block(), Some(2));
+
+    token.mint_one(owner, user, TokenId::zero(), meta::red_dragon());
+    token.mint_one(owner, user, TokenId::one(), meta::blue_dragon());
+    assert_eq!(token.total_supply(), U256::from(2));
+}
+
+#[test]
+#[should_panic]
+fn test_mint_rate_limit_exceeded_in_same_block() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+
+    token.set_max_mints_per_block(owner, Some(2));
+    token.mint_one(owner, user, TokenId::zero(), meta::red_dragon());
+    token.mint_many(
+        owner,
+        user,
+        vec![TokenId::one(), TokenId::from(2)],
+        vec![meta::blue_dragon(), meta::black_dragon()],
+    );
+}
+
+#[test]
+fn test_mint_rate_limit_resets_in_next_block() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+
+    token.set_max_mints_per_block(owner, Some(1));
+    env.set_block_time(1);
+    token.mint_one(owner, user, TokenId::zero(), meta::red_dragon());
+    env.set_block_time(2);
+    token.mint_one(owner, user, TokenId::one(), meta::blue_dragon());
+    assert_eq!(token.total_supply(), U256::from(2));
+}
+
+#[test]
+#[should_panic]
+fn test_set_max_mints_per_block_by_non_admin() {
+    let (env, token, _) = deploy();
+    let user = env.next_user();
+
+    token.set_max_mints_per_block(user, Some(1));
+}
+
+#[test]
+fn test_recall_within_window() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+    let token_id = TokenId::zero();
+
+    token.set_recall_window(owner, 500);
+    env.set_block_time(1000);
+    token.mint_one(owner, user, token_id, meta::red_dragon());
+    assert_eq!(token.mint_time(token_id), Some(1000));
+    assert_eq!(token.minter_of(token_id), Some(Key::Account(owner)));
+
+    env.set_block_time(1500);
+    token.recall(owner, token_id);
+    assert_eq!(token.owner_of(token_id).unwrap(), Key::Account(owner));
+    assert_eq!(token.balance_of(Key::Account(user)), U256::zero());
+    assert_eq!(token.balance_of(Key::Account(owner)), U256::one());
+}
+
+#[test]
+fn test_recall_during_mint_lock() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+    let token_id = TokenId::zero();
+
+    token.set_recall_window(owner, 500);
+    token.set_mint_lock_period(owner, 1000);
+    env.set_block_time(1000);
+    token.mint_one(owner, user, token_id, meta::red_dragon());
+
+    env.set_block_time(1200);
+    token.recall(owner, token_id);
+    assert_eq!(token.owner_of(token_id).unwrap(), Key::Account(owner));
+    assert_eq!(token.balance_of(Key::Account(user)), U256::zero());
+}
+
+#[test]
+#[should_panic]
+fn test_recall_past_window() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+    let token_id = TokenId::zero();
+
+    token.set_recall_window(owner, 500);
+    env.set_block_time(1000);
+    token.mint_one(owner, user, token_id, meta::red_dragon());
+
+    env.set_block_time(1501);
+    token.recall(owner, token_id);
+}
+
+#[test]
+#[should_panic]
+fn test_recall_by_non_admin() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+    let token_id = TokenId::zero();
+
+    token.set_recall_window(owner, 500);
+    token.mint_one(owner, user, token_id, meta::red_dragon());
+    token.recall(user, token_id);
+}
+
+#[test]
+fn test_approval_for_all() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_ids = vec![TokenId::zero(), TokenId::one()];
+
+    token.mint_many(
+        owner,
+        ali,
+        token_ids.clone(),
+        vec![meta::red_dragon(), meta::gold_dragon()],
+    );
+    token.set_approval_for_all(ali, owner, true);
+    assert!(token.is_approved_for_all(ali, owner));
+
+    token.transfer_from(owner, ali, bob, token_ids.clone());
+    assert_eq!(token.balance_of(Key::Account(bob)), U256::from(2));
+
+    token.set_approval_for_all(ali, owner, false);
+    assert!(!token.is_approved_for_all(ali, owner));
+}
+
+#[test]
+fn test_are_approved_for_all() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.set_approval_for_all(ali, owner, true);
+    token.set_approval_for_all(bob, ali, true);
+
+    assert_eq!(
+        token.are_approved_for_all(vec![(ali, owner), (bob, ali), (ali, bob), (owner, ali)]),
+        vec![true, true, false, false]
+    );
+}
+
+#[test]
+fn test_mint_generated_ids_with_salt_are_reproducible() {
+    let env = TestEnv::new();
+    let owner = env.next_user();
+    let user = env.next_user();
+    let salt = "dragons";
+    let first = CEP47Instance::new_with_token_id_salt(
+        &env,
+        "first",
+        owner,
+        NAME,
+        SYMBOL,
+        meta::contract_meta(),
+        Some(salt.to_string()),
+    );
+    let second = CEP47Instance::new_with_token_id_salt(
+        &env,
+        "second",
+        owner,
+        NAME,
+        SYMBOL,
+        meta::contract_meta(),
+        Some(salt.to_string()),
+    );
+
+    env.set_block_time(1);
+    first.mint_generated(owner, user, vec![meta::red_dragon(), meta::blue_dragon()]);
+    env.set_block_time(2);
+    second.mint_generated(owner, user, vec![meta::red_dragon(), meta::blue_dragon()]);
+
+    for nonce in 0..2 {
+        let token_id = generated_token_id(salt.as_bytes(), nonce);
+        assert_eq!(first.owner_of(token_id).unwrap(), Key::Account(user));
+        assert_eq!(second.owner_of(token_id).unwrap(), Key::Account(user));
+    }
+    assert_eq!(first.nonce(), 2);
+    assert_eq!(second.nonce(), 2);
+}
+
+#[test]
+fn test_mint_generated_ids_by_block_time() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+    let block_time: u64 = 1000;
+
+    env.set_block_time(block_time);
+    token.mint_generated(owner, user, vec![meta::red_dragon(), meta::blue_dragon()]);
+    assert_eq!(token.total_supply(), U256::from(2));
+    assert_eq!(token.balance_of(Key::Account(user)), U256::from(2));
+    assert_eq!(token.nonce(), 2);
+
+    for nonce in 0..2 {
+        let token_id = generated_token_id(&block_time.to_le_bytes(), nonce);
+        assert_eq!(token.owner_of(token_id).unwrap(), Key::Account(user));
+    }
+}
+
+#[test]
+fn test_remaining_supply_capped() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+
+    token.set_total_supply_cap(owner, Some(U256::from(3)));
+    assert_eq!(token.remaining_supply(), Some(U256::from(3)));
+
+    token.mint_many(
+        owner,
+        user,
+        vec![TokenId::zero(), TokenId::one()],
+        vec![meta::red_dragon(), meta::blue_dragon()],
+    );
+    assert_eq!(token.remaining_supply(), Some(U256::one()));
+}
+
+#[test]
+fn test_remaining_supply_uncapped() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+
+    token.mint_one(owner, user, TokenId::zero(), meta::red_dragon());
+    assert_eq!(token.remaining_supply(), None);
+}
+
+#[test]
+#[should_panic]
+fn test_mint_over_supply_cap() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+
+    token.set_total_supply_cap(owner, Some(U256::one()));
+    token.mint_many(
+        owner,
+        user,
+        vec![TokenId::zero(), TokenId::one()],
+        vec![meta::red_dragon(), meta::blue_dragon()],
+    );
+}
+
+#[test]
+fn test_config_update_events() {
+    let (env, token, owner) = deploy();
+
+    token.set_total_supply_cap(owner, Some(U256::from(10)));
+    let events = env.last_events();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0]["event_type"], "cep47_config_update");
+    assert_eq!(events[0]["field"], "total_supply_cap");
+    assert_eq!(events[0]["old_value"], "");
+    assert_eq!(events[0]["new_value"], "10");
+
+    token.set_recall_window(owner, 500);
+    let events = env.last_events();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0]["field"], "recall_window");
+    assert_eq!(events[0]["old_value"], "0");
+    assert_eq!(events[0]["new_value"], "500");
+}
+
+#[test]
+fn test_redundant_approve_skips_write() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+    let token_id = TokenId::zero();
+
+    token.mint_one(owner, user, token_id, meta::red_dragon());
+
+    token.approve(user, owner, vec![token_id]);
+    assert!(token.approval_written(user, token_id));
+
+    token.approve(user, owner, vec![token_id]);
+    assert!(!token.approval_written(user, token_id));
+    assert_eq!(env.last_events().len(), 1);
+    assert_eq!(
+        token.get_approved(user, token_id).unwrap(),
+        Key::Account(owner)
+    );
+}
+
+#[test]
+fn test_delegate_approval() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = TokenId::zero();
+
+    token.mint_one(owner, ali, token_id, meta::red_dragon());
+    token.approve(ali, owner, vec![token_id]);
+
+    token.delegate_approval(owner, token_id, bob);
+    assert_eq!(
+        token.get_approved(ali, token_id).unwrap(),
+        Key::Account(bob)
+    );
+
+    token.transfer_from(bob, ali, bob, vec![token_id]);
+    assert_eq!(token.owner_of(token_id).unwrap(), Key::Account(bob));
+}
+
+#[test]
+#[should_panic]
+fn test_delegate_approval_by_non_approved() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = TokenId::zero();
+
+    token.mint_one(owner, ali, token_id, meta::red_dragon());
+    token.approve(ali, owner, vec![token_id]);
+
+    token.delegate_approval(bob, token_id, bob);
+}
+
+#[test]
+#[should_panic]
+fn test_delegate_approval_while_paused() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = TokenId::zero();
+
+    token.mint_one(owner, ali, token_id, meta::red_dragon());
+    token.approve(ali, bob, vec![token_id]);
+    token.set_paused(owner, true);
+    token.delegate_approval(bob, token_id, owner);
+}
+
+#[test]
+fn test_minted_count_unaffected_by_transfer() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_ids = vec![TokenId::zero(), TokenId::one()];
+
+    token.mint_many(
+        owner,
+        ali,
+        token_ids.clone(),
+        vec![meta::red_dragon(), meta::gold_dragon()],
+    );
+    token.mint_one(owner, ali, TokenId::from(2), meta::blue_dragon());
+    assert_eq!(token.minted_count_of(Key::Account(ali)), U256::from(3));
+
+    token.transfer(ali, bob, token_ids);
+    token.burn_one(ali, ali, TokenId::from(2));
+    assert_eq!(token.balance_of(Key::Account(ali)), U256::zero());
+    assert_eq!(token.minted_count_of(Key::Account(ali)), U256::from(3));
+    assert_eq!(token.minted_count_of(Key::Account(bob)), U256::zero());
+}
+
+#[test]
+fn test_all_tokens_after_mints_and_burns() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.mint_many(
+        owner,
+        ali,
+        vec![TokenId::zero(), TokenId::one()],
+        vec![meta::red_dragon(), meta::blue_dragon()],
+    );
+    token.mint_many(
+        owner,
+        bob,
+        vec![TokenId::from(2), TokenId::from(3)],
+        vec![meta::black_dragon(), meta::gold_dragon()],
+    );
+    assert_eq!(
+        token.all_tokens(U256::zero(), 10),
+        vec![
+            TokenId::zero(),
+            TokenId::one(),
+            TokenId::from(2),
+            TokenId::from(3)
+        ]
+    );
+
+    token.burn_one(ali, ali, TokenId::zero());
+    token.burn_one(bob, bob, TokenId::from(2));
+    assert_eq!(
+        token.all_tokens(U256::zero(), 10),
+        vec![TokenId::from(3), TokenId::one()]
+    );
+    assert_eq!(token.all_tokens(U256::one(), 10), vec![TokenId::one()]);
+    assert_eq!(token.all_tokens(U256::zero(), 1), vec![TokenId::from(3)]);
+}
+
+#[test]
+fn test_transfer_after_cooldown() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = TokenId::zero();
+
+    token.set_transfer_cooldown(owner, 100);
+    token.mint_one(owner, ali, token_id, meta::red_dragon());
+
+    env.set_block_time(1000);
+    token.transfer(ali, bob, vec![token_id]);
+    assert_eq!(token.last_transfer_time(token_id), Some(1000));
+
+    env.set_block_time(1100);
+    token.transfer(bob, ali, vec![token_id]);
+    assert_eq!(token.owner_of(token_id).unwrap(), Key::Account(ali));
+}
+
+#[test]
+#[should_panic]
+fn test_transfer_within_cooldown() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = TokenId::zero();
+
+    token.set_transfer_cooldown(owner, 100);
+    token.mint_one(owner, ali, token_id, meta::red_dragon());
+
+    env.set_block_time(1000);
+    token.transfer(ali, bob, vec![token_id]);
+
+    env.set_block_time(1099);
+    token.transfer(bob, ali, vec![token_id]);
+}
+
+#[test]
+fn test_redeem() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+    let token_id = TokenId::zero();
+
+    token.mint_one(owner, user, token_id, meta::red_dragon());
+    token.redeem(user, token_id, "PARCEL-42");
+    assert_eq!(token.owner_of(token_id), None);
+    assert_eq!(token.total_supply(), U256::zero());
+
+    let events = env.last_events();
+    let redeemed = events
+        .iter()
+        .find(|event| event["event_type"] == "cep47_redeem_token")
+        .unwrap();
+    assert_eq!(redeemed["token_id"], token_id.to_string());
+    assert_eq!(redeemed["owner"], Key::Account(user).to_string());
+    assert_eq!(redeemed["shipping_ref"], "PARCEL-42");
+}
+
+#[test]
+#[should_panic]
+fn test_redeem_not_owned() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = TokenId::zero();
+
+    token.mint_one(owner, ali, token_id, meta::red_dragon());
+    token.redeem(bob, token_id, "PARCEL-42");
+}
+
+#[test]
+fn test_token_meta_without_dynamic_contract() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+    let token_id = TokenId::zero();
+
+    token.mint_one(owner, user, token_id, meta::red_dragon());
+    assert_eq!(
+        token.resolved_token_meta(&env, owner, token_id),
+        Some(meta::red_dragon())
+    );
+}
+
+#[test]
+fn test_token_meta_with_dynamic_contract() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+    let token_id = TokenId::zero();
+    let stub = TestContract::new(
+        &env,
+        "dynamic-meta-stub.wasm",
+        "dynamic_meta_stub",
+        owner,
+        runtime_args! {},
+    );
+
+    token.mint_one(owner, user, token_id, meta::red_dragon());
+    token.set_dynamic_meta_contract(owner, Some(ContractHash::new(stub.contract_hash())));
+
+    let mut expected = Meta::new();
+    expected.insert("token_id".to_string(), token_id.to_string());
+    expected.insert("holder".to_string(), Key::Account(user).to_string());
+    assert_eq!(
+        token.resolved_token_meta(&env, owner, token_id),
+        Some(expected)
+    );
+    assert_eq!(token.token_meta(token_id), Some(meta::red_dragon()));
+
+    token.set_dynamic_meta_contract(owner, None);
+    assert_eq!(
+        token.resolved_token_meta(&env, owner, token_id),
+        Some(meta::red_dragon())
+    );
+}
+
+#[test]
+fn test_recipient_policy_allow_all() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+    let contract = Key::Hash([7u8; 32]);
+
+    assert_eq!(token.recipient_policy(), 0);
+    token.mint_one(owner, user, TokenId::zero(), meta::red_dragon());
+    token.mint_one(owner, contract, TokenId::one(), meta::gold_dragon());
+    token.transfer(user, contract, vec![TokenId::zero()]);
+    assert_eq!(token.owner_of(TokenId::zero()).unwrap(), contract);
+}
+
+#[test]
+fn test_recipient_policy_accounts_only() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = TokenId::zero();
+
+    token.set_recipient_policy(owner, 1);
+    token.mint_one(owner, ali, token_id, meta::red_dragon());
+    token.transfer(ali, bob, vec![token_id]);
+    assert_eq!(token.owner_of(token_id).unwrap(), Key::Account(bob));
+}
+
+#[test]
+#[should_panic]
+fn test_recipient_policy_accounts_only_rejects_mint_to_contract() {
+    let (_, token, owner) = deploy();
+
+    token.set_recipient_policy(owner, 1);
+    token.mint_one(
+        owner,
+        Key::Hash([7u8; 32]),
+        TokenId::zero(),
+        meta::red_dragon(),
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_recipient_policy_accounts_only_rejects_transfer_to_contract() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+    let token_id = TokenId::zero();
+
+    token.mint_one(owner, user, token_id, meta::red_dragon());
+    token.set_recipient_policy(owner, 1);
+    token.transfer(user, Key::Hash([7u8; 32]), vec![token_id]);
+}
+
+#[test]
+fn test_recipient_policy_contracts_only() {
+    let (_, token, owner) = deploy();
+    let contract = Key::Hash([7u8; 32]);
+
+    token.set_recipient_policy(owner, 2);
+    token.mint_one(owner, contract, TokenId::zero(), meta::red_dragon());
+    assert_eq!(token.owner_of(TokenId::zero()).unwrap(), contract);
+}
+
+#[test]
+#[should_panic]
+fn test_recipient_policy_contracts_only_rejects_account() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+
+    token.set_recipient_policy(owner, 2);
+    token.mint_one(owner, user, TokenId::zero(), meta::red_dragon());
+}
+
+#[test]
+#[should_panic]
+fn test_set_invalid_recipient_policy() {
+    let (_, token, owner) = deploy();
+    token.set_recipient_policy(owner, 3);
+}
+
+#[test]
+fn test_rebuild_indices() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let ali_tokens = vec![TokenId::zero(), TokenId::one(), TokenId::from(2)];
+
+    token.mint_many(
+        owner,
+        ali,
+        ali_tokens.clone(),
+        vec![
+            meta::red_dragon(),
+            meta::blue_dragon(),
+            meta::black_dragon(),
+        ],
+    );
+    token.mint_one(owner, bob, TokenId::from(3), meta::gold_dragon());
+
+    token.corrupt_balance(ali, U256::one());
+    token.corrupt_token_by_index(ali, U256::zero(), TokenId::from(3));
+    token.corrupt_balance(bob, U256::zero());
+    assert_eq!(token.balance_of(ali), U256::one());
+    assert_eq!(
+        token.get_token_by_index(ali, U256::zero()),
+        Some(TokenId::from(3))
+    );
+
+    token.reset_owner_index(owner, ali, U256::from(3));
+    token.reset_owner_index(owner, bob, U256::one());
+    assert_eq!(token.balance_of(ali), U256::zero());
+    assert_eq!(token.get_token_by_index(ali, U256::from(2)), None);
+
+    let mut token_ids = ali_tokens.clone();
+    token_ids.push(TokenId::from(3));
+    token.rebuild_indices(owner, token_ids);
+
+    assert_eq!(token.balance_of(ali), U256::from(3));
+    assert_eq!(token.balance_of(bob), U256::one());
+    for (index, token_id) in ali_tokens.iter().enumerate() {
+        assert_eq!(
+            token.get_token_by_index(ali, U256::from(index)),
+            Some(*token_id)
+        );
+    }
+    assert_eq!(
+        token.get_token_by_index(bob, U256::zero()),
+        Some(TokenId::from(3))
+    );
+
+    token.transfer(ali, bob, vec![TokenId::one()]);
+    assert_eq!(token.balance_of(ali), U256::from(2));
+    assert_eq!(token.balance_of(bob), U256::from(2));
+}
+
+#[test]
+fn test_rebuild_indices_across_batches() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let ali_tokens = vec![TokenId::zero(), TokenId::one(), TokenId::from(2)];
+
+    token.mint_many(
+        owner,
+        ali,
+        ali_tokens.clone(),
+        vec![
+            meta::red_dragon(),
+            meta::blue_dragon(),
+            meta::black_dragon(),
+        ],
+    );
+    token.corrupt_balance(ali, U256::one());
+
+    token.reset_owner_index(owner, ali, U256::from(3));
+    token.rebuild_indices(owner, vec![TokenId::zero(), TokenId::one()]);
+    token.rebuild_indices(owner, vec![TokenId::one(), TokenId::from(2)]);
+
+    assert_eq!(token.balance_of(ali), U256::from(3));
+    for (index, token_id) in ali_tokens.iter().enumerate() {
+        assert_eq!(
+            token.get_token_by_index(ali, U256::from(index)),
+            Some(*token_id)
+        );
+    }
+    assert_eq!(token.get_token_by_index(ali, U256::from(3)), None);
+}
+
+#[test]
+fn test_reset_owner_index_past_gap() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let ali_tokens = vec![TokenId::zero(), TokenId::one(), TokenId::from(2)];
+
+    token.mint_many(
+        owner,
+        ali,
+        ali_tokens.clone(),
+        vec![
+            meta::red_dragon(),
+            meta::blue_dragon(),
+            meta::black_dragon(),
+        ],
+    );
+    token.corrupt_token_by_index(ali, U256::from(4), TokenId::one());
+    assert_eq!(token.get_token_by_index(ali, U256::from(3)), None);
+
+    token.reset_owner_index(owner, ali, U256::from(5));
+    assert_eq!(token.balance_of(ali), U256::zero());
+    assert_eq!(token.get_token_by_index(ali, U256::from(4)), None);
+
+    token.rebuild_indices(owner, ali_tokens.clone());
+    assert_eq!(token.balance_of(ali), U256::from(3));
+    for (index, token_id) in ali_tokens.iter().enumerate() {
+        assert_eq!(
+            token.get_token_by_index(ali, U256::from(index)),
+            Some(*token_id)
+        );
+    }
+    assert_eq!(token.get_token_by_index(ali, U256::from(4)), None);
+}
+
+#[test]
+#[should_panic]
+fn test_rebuild_indices_by_non_admin() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+
+    token.mint_one(owner, user, TokenId::zero(), meta::red_dragon());
+    token.rebuild_indices(user, vec![TokenId::zero()]);
+}
+
+#[test]
+#[should_panic]
+fn test_rebuild_indices_with_unknown_token() {
+    let (_, token, owner) = deploy();
+    token.rebuild_indices(owner, vec![TokenId::zero()]);
+}
+
+#[test]
+fn test_burn_down_to_min_supply() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+    let token_ids = vec![TokenId::zero(), TokenId::one(), TokenId::from(2)];
+
+    token.mint_many(
+        owner,
+        user,
+        token_ids,
+        vec![
+            meta::red_dragon(),
+            meta::blue_dragon(),
+            meta::black_dragon(),
+        ],
+    );
+    token.set_min_supply(owner, Some(U256::from(2)));
+    token.burn_one(user, user, TokenId::zero());
+    assert_eq!(token.total_supply(), U256::from(2));
+}
+
+#[test]
+#[should_panic]
+fn test_burn_below_min_supply() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+    let token_ids = vec![TokenId::zero(), TokenId::one(), TokenId::from(2)];
+
+    token.mint_many(
+        owner,
+        user,
+        token_ids,
+        vec![
+            meta::red_dragon(),
+            meta::blue_dragon(),
+            meta::black_dragon(),
+        ],
+    );
+    token.set_min_supply(owner, Some(U256::from(2)));
+    token.burn_many(user, user, vec![TokenId::zero(), TokenId::one()]);
+}
+
+#[test]
+fn test_token_metadata_delta() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+    let token_id = TokenId::zero();
+
+    let mut old_meta = meta::red_dragon();
+    old_meta.insert("size".to_string(), "huge".to_string());
+    old_meta.insert("age".to_string(), "old".to_string());
+    token.mint_one(owner, user, token_id, old_meta);
+    token.set_metadata_delta_events(owner, true);
+
+    let mut new_meta = meta::gold_dragon();
+    new_meta.insert("size".to_string(), "huge".to_string());
+    new_meta.insert("wings".to_string(), "two".to_string());
+    token.update_token_meta(owner, token_id, new_meta.clone());
+    assert_eq!(token.token_meta(token_id).unwrap(), new_meta);
+
+    let events = env.last_events();
+    assert_eq!(events.len(), 3);
+    assert!(events
+        .iter()
+        .all(|event| event["event_type"] == "cep47_metadata_delta"
+            && event["token_id"] == token_id.to_string()));
+
+    let change_of = |key: &str| {
+        events
+            .iter()
+            .find(|event| event["key"] == key)
+            .map(|event| (event["change"].clone(), event.get("value").cloned()))
+    };
+    assert_eq!(
+        change_of("color"),
+        Some(("set".to_string(), Some("gold".to_string())))
+    );
+    assert_eq!(
+        change_of("wings"),
+        Some(("set".to_string(), Some("two".to_string())))
+    );
+    assert_eq!(change_of("age"), Some(("removed".to_string(), None)));
+    assert_eq!(change_of("size"), None);
+}
+
+#[test]
+fn test_transfer_before_expiry() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = TokenId::zero();
+
+    env.set_block_time(1000);
+    token.mint_with_expiry(owner, ali, token_id, meta::red_dragon(), 2000);
+    assert_eq!(token.token_expiry(token_id), Some(2000));
+
+    env.set_block_time(1999);
+    token.transfer(ali, bob, vec![token_id]);
+    assert_eq!(token.owner_of(token_id).unwrap(), Key::Account(bob));
+}
+
+#[test]
+#[should_panic]
+fn test_transfer_after_expiry() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = TokenId::zero();
+
+    env.set_block_time(1000);
+    token.mint_with_expiry(owner, ali, token_id, meta::red_dragon(), 2000);
+
+    env.set_block_time(2000);
+    token.transfer(ali, bob, vec![token_id]);
+}
+
+#[test]
+fn test_burn_after_expiry() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+    let token_id = TokenId::zero();
+
+    env.set_block_time(1000);
+    token.mint_with_expiry(owner, user, token_id, meta::red_dragon(), 2000);
+
+    env.set_block_time(3000);
+    token.burn_one(user, user, token_id);
+    assert_eq!(token.owner_of(token_id), None);
+    assert_eq!(token.token_expiry(token_id), None);
+}
+
+#[test]
+fn test_revoke_subset() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_ids = vec![TokenId::zero(), TokenId::one(), TokenId::from(2)];
+
+    token.mint_many(
+        owner,
+        ali,
+        token_ids.clone(),
+        vec![
+            meta::red_dragon(),
+            meta::blue_dragon(),
+            meta::black_dragon(),
+        ],
+    );
+    token.approve(ali, bob, token_ids);
+    token.revoke(ali, vec![TokenId::zero(), TokenId::from(2)]);
+
+    assert_eq!(token.get_approved(ali, TokenId::zero()), None);
+    assert_eq!(
+        token.get_approved(ali, TokenId::one()),
+        Some(Key::Account(bob))
+    );
+    assert_eq!(token.get_approved(ali, TokenId::from(2)), None);
+
+    let events = env.last_events();
+    assert_eq!(events.len(), 2);
+    assert!(events
+        .iter()
+        .all(|event| event["event_type"] == "cep47_revoke_token"));
+}
+
+#[test]
+#[should_panic]
+fn test_revoke_not_owned_token() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.mint_one(owner, ali, TokenId::zero(), meta::red_dragon());
+    token.mint_one(owner, bob, TokenId::one(), meta::blue_dragon());
+    token.approve(ali, bob, vec![TokenId::zero()]);
+    token.revoke(ali, vec![TokenId::zero(), TokenId::one()]);
+}
+
+#[test]
+#[should_panic]
+fn test_transfer_within_mint_lock() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = TokenId::zero();
+
+    token.set_mint_lock_period(owner, 500);
+    env.set_block_time(1000);
+    token.mint_one(owner, ali, token_id, meta::red_dragon());
+
+    env.set_block_time(1499);
+    token.transfer(ali, bob, vec![token_id]);
+}
+
+#[test]
+fn test_transfer_after_mint_lock() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = TokenId::zero();
+
+    token.set_mint_lock_period(owner, 500);
+    env.set_block_time(1000);
+    token.mint_one(owner, ali, token_id, meta::red_dragon());
+    token.mint_one(owner, ali, TokenId::one(), meta::blue_dragon());
+    token.burn_one(ali, ali, TokenId::one());
+
+    env.set_block_time(1500);
+    token.transfer(ali, bob, vec![token_id]);
+    assert_eq!(token.owner_of(token_id).unwrap(), Key::Account(bob));
+}
+
+#[test]
+fn test_token_detail() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+    let token_id = TokenId::zero();
+
+    token.mint_one(owner, user, token_id, meta::red_dragon());
+    assert_eq!(
+        token.token_detail(token_id),
+        Some((
+            Key::Account(user),
+            meta::red_dragon(),
+            meta::contract_meta()
+        ))
+    );
+    assert_eq!(token.token_detail(TokenId::one()), None);
+}
+
+fn deploy_receiver(env: &TestEnv, sender: AccountHash, name: &str, accept: bool) -> Key {
+    TestContract::new(
+        env,
+        "cep47-receiver-stub.wasm",
+        name,
+        sender,
+        runtime_args! {
+            "accept" => accept
+        },
+    );
+    env.query_account_named_key(sender, &[format!("{}_package_hash_wrapped", name)])
+}
+
+#[test]
+fn test_safe_transfer_to_accepting_contract() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+    let token_id = TokenId::zero();
+    let receiver = deploy_receiver(&env, owner, "accepting_receiver", true);
+
+    token.mint_one(owner, user, token_id, meta::red_dragon());
+    token.safe_transfer_from(user, user, receiver, vec![token_id]);
+    assert_eq!(token.owner_of(token_id).unwrap(), receiver);
+}
+
+#[test]
+#[should_panic]
+fn test_safe_transfer_to_rejecting_contract() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+    let token_id = TokenId::zero();
+    let receiver = deploy_receiver(&env, owner, "rejecting_receiver", false);
+
+    token.mint_one(owner, user, token_id, meta::red_dragon());
+    token.safe_transfer_from(user, user, receiver, vec![token_id]);
+}
+
+#[test]
+fn test_safe_transfer_to_account() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = TokenId::zero();
+
+    token.mint_one(owner, ali, token_id, meta::red_dragon());
+    token.safe_transfer_from(ali, ali, bob, vec![token_id]);
+    assert_eq!(token.owner_of(token_id).unwrap(), Key::Account(bob));
+}
+
+#[test]
+#[should_panic]
+fn test_update_meta_with_immutable_metadata() {
+    let env = TestEnv::new();
+    let owner = env.next_user();
+    let user = env.next_user();
+    let token = CEP47Instance::new_with_immutable_metadata(
+        &env,
+        NAME,
+        owner,
+        NAME,
+        SYMBOL,
+        meta::contract_meta(),
+    );
+    let token_id = TokenId::zero();
+
+    assert!(token.immutable_metadata());
+    token.mint_one(owner, user, token_id, meta::red_dragon());
+    assert_eq!(token.token_meta(token_id).unwrap(), meta::red_dragon());
+    token.update_token_meta(owner, token_id, meta::gold_dragon());
+}
+
+#[test]
+fn test_mutable_metadata_by_default() {
+    let (_, token, _) = deploy();
+    assert!(!token.immutable_metadata());
+}
+
+#[test]
+fn test_tokens_with_attribute() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+    let token_ids: Vec<TokenId> = (0..5).map(TokenId::from).collect();
+
+    token.mint_many(
+        owner,
+        user,
+        token_ids,
+        vec![
+            meta::red_dragon(),
+            meta::blue_dragon(),
+            meta::red_dragon(),
+            meta::gold_dragon(),
+            meta::red_dragon(),
+        ],
+    );
+
+    assert_eq!(
+        token.tokens_with_attribute("color", "red", U256::zero(), 5),
+        vec![TokenId::zero(), TokenId::from(2), TokenId::from(4)]
+    );
+    assert_eq!(
+        token.tokens_with_attribute("color", "red", U256::one(), 3),
+        vec![TokenId::from(2)]
+    );
+    assert_eq!(
+        token.tokens_with_attribute("color", "gold", U256::zero(), 5),
+        vec![TokenId::from(3)]
+    );
+    assert!(token
+        .tokens_with_attribute("color", "green", U256::zero(), 5)
+        .is_empty());
+    assert!(token
+        .tokens_with_attribute("size", "red", U256::zero(), 5)
+        .is_empty());
+}
+
+#[test]
+fn test_has_approval() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.mint_many(
+        owner,
+        ali,
+        vec![TokenId::zero(), TokenId::one()],
+        vec![meta::red_dragon(), meta::blue_dragon()],
+    );
+    token.approve(ali, bob, vec![TokenId::zero()]);
+
+    assert!(token.has_approval(ali, TokenId::zero()));
+    assert!(!token.has_approval(ali, TokenId::one()));
+}
+
+#[test]
+fn test_mint_copies_multi() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+
+    token.mint_copies_multi(
+        owner,
+        user,
+        vec![
+            (meta::red_dragon(), 3),
+            (meta::gold_dragon(), 1),
+            (meta::blue_dragon(), 0),
+        ],
+    );
+    assert_eq!(token.total_supply(), U256::from(4));
+    assert_eq!(token.balance_of(user), U256::from(4));
+
+    let metas: Vec<Meta> = token
+        .all_tokens(U256::zero(), 4)
+        .into_iter()
+        .map(|token_id| token.token_meta(token_id).unwrap())
+        .collect();
+    assert_eq!(
+        metas,
+        vec![
+            meta::red_dragon(),
+            meta::red_dragon(),
+            meta::red_dragon(),
+            meta::gold_dragon()
+        ]
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_mint_copies_multi_above_cap() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+
+    token.set_total_supply_cap(owner, Some(U256::from(3)));
+    token.mint_copies_multi(
+        owner,
+        user,
+        vec![(meta::red_dragon(), 2), (meta::gold_dragon(), 2)],
+    );
+}
+
+#[test]
+fn test_metadata_hash() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+    let token_ids = vec![TokenId::zero(), TokenId::one()];
+
+    token.mint_many(
+        owner,
+        user,
+        token_ids.clone(),
+        vec![meta::red_dragon(), meta::blue_dragon()],
+    );
+    let hash = token.metadata_hash(token_ids.clone());
+    assert_eq!(hash, token.metadata_hash(token_ids.clone()));
+    assert_ne!(
+        hash,
+        token.metadata_hash(vec![TokenId::one(), TokenId::zero()])
+    );
+
+    token.update_token_meta(owner, TokenId::one(), meta::gold_dragon());
+    assert_ne!(hash, token.metadata_hash(token_ids));
+}
+
+#[test]
+fn test_events_disabled() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = TokenId::zero();
+
+    token.mint_one(owner, ali, token_id, meta::red_dragon());
+    assert_eq!(env.last_events().len(), 1);
+
+    token.set_events_enabled(owner, false);
+    let events = env.last_events();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0]["event_type"], "cep47_config_update");
+    assert_eq!(events[0]["new_value"], "false");
+
+    token.mint_one(owner, ali, TokenId::one(), meta::blue_dragon());
+    assert!(env.last_events().is_empty());
+    token.transfer(ali, bob, vec![token_id]);
+    assert!(env.last_events().is_empty());
+    assert_eq!(token.owner_of(token_id).unwrap(), Key::Account(bob));
+    token.burn_one(ali, ali, TokenId::one());
+    assert!(env.last_events().is_empty());
+    assert_eq!(token.total_supply(), U256::one());
+
+    token.set_events_enabled(owner, true);
+    assert_eq!(env.last_events()[0]["new_value"], "true");
+    token.transfer(bob, ali, vec![token_id]);
+    assert_eq!(env.last_events().len(), 1);
+}
+
+#[test]
+fn test_was_ever_minted() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+
+    token.mint_many(
+        owner,
+        user,
+        vec![TokenId::zero(), TokenId::one()],
+        vec![meta::red_dragon(), meta::blue_dragon()],
+    );
+    token.burn_one(user, user, TokenId::one());
+
+    assert!(token.was_ever_minted(TokenId::zero()));
+    assert!(!token.is_burned(TokenId::zero()));
+    assert!(token.was_ever_minted(TokenId::one()));
+    assert!(token.is_burned(TokenId::one()));
+    assert!(!token.was_ever_minted(TokenId::from(2)));
+    assert!(!token.is_burned(TokenId::from(2)));
+}
+
+#[test]
+fn test_mint_for_self() {
+    let (env, token, _) = deploy();
+    let user = env.next_user();
+
+    token.mint_for_self(user, vec![meta::red_dragon(), meta::gold_dragon()]);
+    assert_eq!(token.balance_of(user), U256::from(2));
+    for token_id in token.all_tokens(U256::zero(), 2) {
+        assert_eq!(token.owner_of(token_id).unwrap(), Key::Account(user));
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_mint_for_self_respects_recipient_policy() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+
+    token.set_recipient_policy(owner, 2);
+    token.mint_for_self(user, vec![meta::red_dragon()]);
+}
+
+#[test]
+#[should_panic]
+fn test_mint_for_self_respects_supply_cap() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+
+    token.set_total_supply_cap(owner, Some(U256::one()));
+    token.mint_for_self(user, vec![meta::red_dragon(), meta::gold_dragon()]);
+}
+
+#[test]
+fn test_approval_changed_event() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let dan = env.next_user();
+    let token_id = TokenId::zero();
+
+    token.mint_one(owner, ali, token_id, meta::red_dragon());
+    token.approve(ali, bob, vec![token_id]);
+    assert!(!env
+        .last_events()
+        .iter()
+        .any(|event| event["event_type"] == "cep47_approval_changed"));
+
+    token.approve(ali, dan, vec![token_id]);
+    let events = env.last_events();
+    let changed = events
+        .iter()
+        .find(|event| event["event_type"] == "cep47_approval_changed")
+        .unwrap();
+    assert_eq!(changed["owner"], Key::Account(ali).to_string());
+    assert_eq!(changed["token_id"], token_id.to_string());
+    assert_eq!(changed["old_spender"], Key::Account(bob).to_string());
+    assert_eq!(changed["new_spender"], Key::Account(dan).to_string());
+}
+
+fn oversized_meta() -> Meta {
+    let mut meta = meta::red_dragon();
+    meta.insert("description".to_string(), "x".repeat(64));
+    meta
+}
+
+#[test]
+fn test_mint_within_max_meta_bytes() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+
+    token.set_max_meta_bytes(owner, 64);
+    token.mint_one(owner, user, TokenId::zero(), meta::red_dragon());
+    token.update_token_meta(owner, TokenId::zero(), meta::gold_dragon());
+    assert_eq!(
+        token.token_meta(TokenId::zero()).unwrap(),
+        meta::gold_dragon()
+    );
+
+    token.set_max_meta_bytes(owner, 0);
+    token.mint_one(owner, user, TokenId::one(), oversized_meta());
+}
+
+#[test]
+#[should_panic]
+fn test_mint_above_max_meta_bytes() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+
+    token.set_max_meta_bytes(owner, 64);
+    token.mint_one(owner, user, TokenId::zero(), oversized_meta());
+}
+
+#[test]
+#[should_panic]
+fn test_update_meta_above_max_meta_bytes() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+
+    token.set_max_meta_bytes(owner, 64);
+    token.mint_one(owner, user, TokenId::zero(), meta::red_dragon());
+    token.update_token_meta(owner, TokenId::zero(), oversized_meta());
+}
+
+#[test]
+fn test_mint_while_transfers_paused() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.set_transfers_paused(owner, true);
+    assert_eq!(env.last_events()[0]["event_type"], "cep47_transfers_pause");
+    token.mint_one(owner, ali, TokenId::zero(), meta::red_dragon());
+    token.burn_one(ali, ali, TokenId::zero());
+    token.mint_one(owner, ali, TokenId::one(), meta::blue_dragon());
+
+    token.set_transfers_paused(owner, false);
+    assert_eq!(
+        env.last_events()[0]["event_type"],
+        "cep47_transfers_unpause"
+    );
+    token.transfer(ali, bob, vec![TokenId::one()]);
+    assert_eq!(token.owner_of(TokenId::one()).unwrap(), Key::Account(bob));
+}
+
+#[test]
+#[should_panic]
+fn test_transfer_while_transfers_paused() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.mint_one(owner, ali, TokenId::zero(), meta::red_dragon());
+    token.set_transfers_paused(owner, true);
+    token.transfer(ali, bob, vec![TokenId::zero()]);
+}
+
+#[test]
+#[should_panic]
+fn test_mint_while_paused() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+
+    token.set_paused(owner, true);
+    assert_eq!(env.last_events()[0]["event_type"], "cep47_pause");
+    token.mint_one(owner, user, TokenId::zero(), meta::red_dragon());
+}
+
+#[test]
+#[should_panic]
+fn test_transfer_while_paused() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.mint_one(owner, ali, TokenId::zero(), meta::red_dragon());
+    token.set_paused(owner, true);
+    token.transfer(ali, bob, vec![TokenId::zero()]);
+}
+
+#[test]
+fn test_mint_from_template() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+
+    token.set_meta_template(owner, 7, meta::gold_dragon());
+    token.mint_from_template(owner, user, 7, 3);
+    assert_eq!(token.balance_of(user), U256::from(3));
+
+    let token_ids = token.all_tokens(U256::zero(), 3);
+    for token_id in &token_ids {
+        assert_eq!(token.token_template(*token_id), Some(7));
+        assert_eq!(token.token_meta(*token_id).unwrap(), meta::gold_dragon());
+    }
+
+    token.set_meta_template(owner, 7, meta::black_dragon());
+    assert_eq!(
+        token.token_meta(token_ids[0]).unwrap(),
+        meta::black_dragon()
+    );
+
+    token.update_token_meta(owner, token_ids[1], meta::red_dragon());
+    assert_eq!(token.token_meta(token_ids[1]).unwrap(), meta::red_dragon());
+    assert_eq!(
+        token.token_meta(token_ids[2]).unwrap(),
+        meta::black_dragon()
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_mint_from_unknown_template() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+    token.mint_from_template(owner, user, 7, 1);
+}
+
+#[test]
+fn test_supports_interface() {
+    let (env, token, owner) = deploy();
+    assert!(token.supports_interface("cep47"));
+    assert!(token.supports_interface("cep47-enumerable"));
+    assert!(token.supports_interface("cep47-operator-approvals"));
+    assert!(token.supports_interface("cep47-mutable-metadata"));
+    assert!(!token.supports_interface("cep47-royalties"));
+
+    let immutable = CEP47Instance::new_with_immutable_metadata(
+        &env,
+        "immutable",
+        owner,
+        NAME,
+        SYMBOL,
+        meta::contract_meta(),
+    );
+    assert!(immutable.supports_interface("cep47"));
+    assert!(!immutable.supports_interface("cep47-mutable-metadata"));
+}
+
+#[test]
+fn test_transfer_and_approve() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let market = env.next_user();
+    let token_id = TokenId::zero();
+
+    token.mint_one(owner, ali, token_id, meta::red_dragon());
+    token.approve(ali, bob, vec![token_id]);
+    token.transfer_and_approve(bob, bob, token_id, market);
+
+    assert_eq!(token.owner_of(token_id).unwrap(), Key::Account(bob));
+    assert_eq!(token.get_approved(ali, token_id), None);
+    assert_eq!(
+        token.get_approved(bob, token_id),
+        Some(Key::Account(market))
+    );
+
+    let event_types: Vec<String> = env
+        .last_events()
+        .iter()
+        .map(|event| event["event_type"].clone())
+        .collect();
+    assert!(event_types.contains(&"cep47_transfer_token".to_string()));
+    assert!(event_types.contains(&"cep47_approve_token".to_string()));
+}
+
+#[test]
+#[should_panic]
+fn test_transfer_and_approve_for_other_recipient() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let market = env.next_user();
+    let token_id = TokenId::zero();
+
+    token.mint_one(owner, ali, token_id, meta::red_dragon());
+    token.approve(ali, market, vec![token_id]);
+    token.transfer_and_approve(market, bob, token_id, market);
+}
+
+#[test]
+fn test_recent_events() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+    let token_ids: Vec<TokenId> = (0..5).map(TokenId::from).collect();
+
+    token.mint_many(
+        owner,
+        user,
+        vec![TokenId::from(100)],
+        vec![meta::red_dragon()],
+    );
+    assert!(token.recent_events(10).is_empty());
+
+    token.set_recent_events_capacity(owner, 3);
+    token.mint_many(owner, user, token_ids, vec![meta::red_dragon(); 5]);
+
+    let recent: Vec<String> = token
+        .recent_events(10)
+        .iter()
+        .map(|event| event["token_id"].clone())
+        .collect();
+    assert_eq!(recent, vec!["2", "3", "4"]);
+
+    let recent = token.recent_events(2);
+    assert_eq!(recent.len(), 2);
+    assert_eq!(recent[0]["event_type"], "cep47_mint_one");
+    assert_eq!(recent[0]["token_id"], "3");
+    assert_eq!(recent[1]["token_id"], "4");
+}
+
+#[test]
+#[should_panic]
+fn test_recent_events_capacity_above_max() {
+    let (_, token, owner) = deploy();
+    token.set_recent_events_capacity(owner, 51);
+}
+
+#[test]
+fn test_batch_transfer_matches_single_transfers() {
+    let env = TestEnv::new();
+    let owner = env.next_user();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let batched = CEP47Instance::new(&env, "batched", owner, NAME, SYMBOL, meta::contract_meta());
+    let single = CEP47Instance::new(&env, "single", owner, NAME, SYMBOL, meta::contract_meta());
+    let token_ids: Vec<TokenId> = (0..8).map(TokenId::from).collect();
+    let moved: Vec<TokenId> = vec![6, 1, 3, 7, 0].into_iter().map(TokenId::from).collect();
+
+    for token in [&batched, &single] {
+        token.mint_many(owner, ali, token_ids.clone(), vec![meta::red_dragon(); 8]);
+        token.mint_one(owner, bob, TokenId::from(100), meta::red_dragon());
+    }
+    batched.transfer(ali, bob, moved.clone());
+    let batched_gas = env.last_gas_cost();
+    let mut single_gas = U512::zero();
+    for token_id in &moved {
+        single.transfer(ali, bob, vec![*token_id]);
+        single_gas += env.last_gas_cost();
+    }
+    assert!(batched_gas < single_gas);
+
+    for account in [ali, bob] {
+        assert_eq!(batched.balance_of(account), single.balance_of(account));
+        for index in 0..9 {
+            assert_eq!(
+                batched.get_token_by_index(account, U256::from(index)),
+                single.get_token_by_index(account, U256::from(index))
+            );
+        }
+    }
+    assert_eq!(batched.balance_of(ali), U256::from(3));
+    assert_eq!(batched.balance_of(bob), U256::from(6));
+    for token_id in moved {
+        assert_eq!(batched.owner_of(token_id).unwrap(), Key::Account(bob));
+    }
+}
+
+#[test]
+fn test_creator_defaults_to_installer() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+    assert_eq!(token.creator(), Key::Account(owner));
+
+    token.mint_one(owner, user, TokenId::zero(), meta::red_dragon());
+    let events = env.last_events();
+    assert_eq!(events[0]["event_type"], "cep47_mint_one");
+    assert_eq!(events[0]["creator"], Key::Account(owner).to_string());
+}
+
+#[test]
+fn test_creator_distinct_from_minter() {
+    let env = TestEnv::new();
+    let owner = env.next_user();
+    let artist = env.next_user();
+    let user = env.next_user();
+    let token = CEP47Instance::new_with_creator(
+        &env,
+        NAME,
+        owner,
+        NAME,
+        SYMBOL,
+        meta::contract_meta(),
+        artist,
+    );
+    assert_eq!(token.creator(), Key::Account(artist));
+
+    token.mint_one(owner, user, TokenId::zero(), meta::red_dragon());
+    let events = env.last_events();
+    assert_eq!(events[0]["creator"], Key::Account(artist).to_string());
+    assert_eq!(events[0]["recipient"], Key::Account(user).to_string());
+}
+
+#[test]
+fn test_token_royalty_defaults_to_creator() {
+    let env = TestEnv::new();
+    let owner = env.next_user();
+    let artist = env.next_user();
+    let studio = env.next_user();
+    let user = env.next_user();
+    let token = CEP47Instance::new_with_creator(
+        &env,
+        NAME,
+        owner,
+        NAME,
+        SYMBOL,
+        meta::contract_meta(),
+        artist,
+    );
+
+    token.mint_one(owner, user, TokenId::zero(), meta::red_dragon());
+    assert_eq!(
+        token.token_royalty(TokenId::zero()),
+        Some((Key::Account(artist), 0))
+    );
+    assert_eq!(token.token_royalty(TokenId::one()), None);
+
+    token.set_token_royalties(owner, vec![(TokenId::zero(), Key::Account(studio), 500)]);
+    assert_eq!(
+        token.token_royalty(TokenId::zero()),
+        Some((Key::Account(studio), 500))
+    );
+}
+
+#[test]
+fn test_exist_many() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+
+    token.mint_many(
+        owner,
+        user,
+        vec![TokenId::from(1), TokenId::from(3)],
+        vec![meta::red_dragon(), meta::gold_dragon()],
+    );
+    token.burn_one(user, user, TokenId::from(3));
+
+    assert_eq!(
+        token.exist_many(vec![
+            TokenId::from(1),
+            TokenId::from(2),
+            TokenId::from(3),
+            TokenId::from(1)
+        ]),
+        vec![true, false, false, true]
+    );
+    assert!(token.exist_many(vec![]).is_empty());
+}
+
+fn sign_meta(secret_key: &SecretKey, token_id: TokenId, token_meta: &Meta, nonce: u64) -> Bytes {
+    let mut meta_hash = [0u8; 32];
+    let mut hasher = VarBlake2b::new(32).unwrap();
+    hasher.update(token_meta.to_bytes().unwrap());
+    hasher.finalize_variable(|hash| meta_hash.copy_from_slice(hash));
+    let message = (token_id, meta_hash, nonce).to_bytes().unwrap();
+    let signature = crypto::sign(message, secret_key, &PublicKey::from(secret_key));
+    Bytes::from(signature.to_bytes().unwrap())
+}
+
+fn meta_signer() -> SecretKey {
+    SecretKey::ed25519_from_bytes([7u8; 32]).unwrap()
+}
+
+#[test]
+fn test_update_token_meta_signed() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+    let relayer = env.next_user();
+    let token_id = TokenId::zero();
+    let signer = meta_signer();
+
+    token.mint_one(owner, user, token_id, meta::red_dragon());
+    token.set_meta_signer(owner, Some(PublicKey::from(&signer)));
+
+    let signature = sign_meta(&signer, token_id, &meta::gold_dragon(), 1);
+    token.update_token_meta_signed(relayer, token_id, meta::gold_dragon(), 1, signature);
+    assert_eq!(token.token_meta(token_id).unwrap(), meta::gold_dragon());
+}
+
+#[test]
+#[should_panic]
+fn test_update_token_meta_signed_replay() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+    let token_id = TokenId::zero();
+    let signer = meta_signer();
+
+    token.mint_one(owner, user, token_id, meta::red_dragon());
+    token.set_meta_signer(owner, Some(PublicKey::from(&signer)));
+
+    let signature = sign_meta(&signer, token_id, &meta::gold_dragon(), 1);
+    token.update_token_meta_signed(user, token_id, meta::gold_dragon(), 1, signature.clone());
+    token.update_token_meta_signed(user, token_id, meta::gold_dragon(), 1, signature);
+}
+
+#[test]
+#[should_panic]
+fn test_update_token_meta_signed_wrong_signature() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+    let token_id = TokenId::zero();
+    let signer = meta_signer();
+    let impostor = SecretKey::ed25519_from_bytes([8u8; 32]).unwrap();
+
+    token.mint_one(owner, user, token_id, meta::red_dragon());
+    token.set_meta_signer(owner, Some(PublicKey::from(&signer)));
+
+    let signature = sign_meta(&impostor, token_id, &meta::gold_dragon(), 1);
+    token.update_token_meta_signed(user, token_id, meta::gold_dragon(), 1, signature);
+}
+
+#[test]
+#[should_panic]
+fn test_update_token_meta_signed_for_other_meta() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+    let token_id = TokenId::zero();
+    let signer = meta_signer();
+
+    token.mint_one(owner, user, token_id, meta::red_dragon());
+    token.set_meta_signer(owner, Some(PublicKey::from(&signer)));
+
+    let signature = sign_meta(&signer, token_id, &meta::gold_dragon(), 1);
+    token.update_token_meta_signed(user, token_id, meta::red_dragon(), 1, signature);
+}
+
+#[test]
+fn test_transfer_with_fee() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let treasury = env.next_user();
+    let token_id = TokenId::zero();
+
+    token.mint_one(owner, ali, token_id, meta::red_dragon());
+    token.set_transfer_fee(owner, 250, treasury);
+    token.transfer_with_fee(ali, bob, vec![token_id], U256::from(1_000_000));
+
+    assert_eq!(token.owner_of(token_id).unwrap(), Key::Account(bob));
+    let events = env.last_events();
+    let fee_event = events
+        .iter()
+        .find(|event| event["event_type"] == "cep47_transfer_fee")
+        .unwrap();
+    assert_eq!(fee_event["amount"], "25000");
+    assert_eq!(fee_event["treasury"], Key::Account(treasury).to_string());
+    assert!(events
+        .iter()
+        .any(|event| event["event_type"] == "cep47_transfer_token"));
+}
+
+#[test]
+#[should_panic]
+fn test_set_transfer_fee_above_denominator() {
+    let (env, token, owner) = deploy();
+    let treasury = env.next_user();
+    token.set_transfer_fee(owner, 10_001, treasury);
+}
+
+#[test]
+fn test_set_token_royalties() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+    let artist = env.next_user();
+    let studio = env.next_user();
+    let token_ids = vec![TokenId::zero(), TokenId::one()];
+
+    token.mint_many(
+        owner,
+        user,
+        token_ids.clone(),
+        vec![meta::red_dragon(), meta::gold_dragon()],
+    );
+    token.set_token_royalties(
+        owner,
+        vec![
+            (token_ids[0], Key::Account(artist), 500),
+            (token_ids[1], Key::Account(studio), 10_000),
+        ],
+    );
+
+    assert_eq!(
+        token.token_royalty(token_ids[0]),
+        Some((Key::Account(artist), 500))
+    );
+    assert_eq!(
+        token.token_royalty(token_ids[1]),
+        Some((Key::Account(studio), 10_000))
+    );
+    let events = env.last_events();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0]["event_type"], "cep47_royalties_update");
+
+    token.burn_one(user, user, token_ids[0]);
+    assert_eq!(token.token_royalty(token_ids[0]), None);
+}
+
+#[test]
+#[should_panic]
+fn test_set_token_royalties_with_invalid_bps() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+    let artist = env.next_user();
+    let token_ids = vec![TokenId::zero(), TokenId::one()];
+
+    token.mint_many(
+        owner,
+        user,
+        token_ids.clone(),
+        vec![meta::red_dragon(), meta::gold_dragon()],
+    );
+    token.set_token_royalties(
+        owner,
+        vec![
+            (token_ids[0], Key::Account(artist), 500),
+            (token_ids[1], Key::Account(artist), 10_001),
+        ],
+    );
+}
+
+#[test]
+fn test_total_approvals() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let dan = env.next_user();
+    let token_ids: Vec<TokenId> = (0..4).map(TokenId::from).collect();
+
+    token.mint_many(owner, ali, token_ids.clone(), vec![meta::red_dragon(); 4]);
+    assert_eq!(token.total_approvals(), U256::zero());
+
+    token.approve(ali, bob, token_ids.clone());
+    assert_eq!(token.total_approvals(), U256::from(4));
+
+    token.approve(ali, dan, vec![token_ids[0]]);
+    token.approve(ali, dan, vec![token_ids[0]]);
+    assert_eq!(token.total_approvals(), U256::from(4));
+
+    token.transfer_from(dan, ali, dan, vec![token_ids[0]]);
+    assert_eq!(token.total_approvals(), U256::from(3));
+
+    token.revoke(ali, vec![token_ids[1]]);
+    assert_eq!(token.total_approvals(), U256::from(2));
+
+    token.transfer(ali, dan, vec![token_ids[2]]);
+    assert_eq!(token.total_approvals(), U256::from(1));
+    assert_eq!(token.get_approved(ali, token_ids[2]), None);
+
+    token.burn_one(ali, ali, token_ids[3]);
+    assert_eq!(token.total_approvals(), U256::zero());
+}
+
+#[test]
+fn test_last_mint_time() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+    assert_eq!(token.last_mint_time(), 0);
+
+    env.set_block_time(1_000);
+    token.mint_one(owner, user, TokenId::zero(), meta::red_dragon());
+    assert_eq!(token.last_mint_time(), 1_000);
+
+    env.set_block_time(5_000);
+    token.transfer(user, owner, vec![TokenId::zero()]);
+    assert_eq!(token.last_mint_time(), 1_000);
+
+    token.mint_one(owner, user, TokenId::one(), meta::blue_dragon());
+    assert_eq!(token.last_mint_time(), 5_000);
+}
+
+#[test]
+fn test_set_nonce() {
+    let env = TestEnv::new();
+    let owner = env.next_user();
+    let user = env.next_user();
+    let salt = "dragons";
+    let token = CEP47Instance::new_with_token_id_salt(
+        &env,
+        NAME,
+        owner,
+        NAME,
+        SYMBOL,
+        meta::contract_meta(),
+        Some(salt.to_string()),
+    );
+
+    token.mint_generated(owner, user, vec![meta::red_dragon()]);
+    token.set_nonce(owner, 10);
+    assert_eq!(token.nonce(), 10);
+    let events = env.last_events();
+    assert_eq!(events[0]["event_type"], "cep47_config_update");
+    assert_eq!(events[0]["new_value"], "10");
+
+    token.mint_generated(owner, user, vec![meta::blue_dragon()]);
+    assert_eq!(token.nonce(), 11);
+    let token_id = generated_token_id(salt.as_bytes(), 10);
+    assert_eq!(token.owner_of(token_id).unwrap(), Key::Account(user));
+    assert_eq!(token.owner_of(generated_token_id(salt.as_bytes(), 1)), None);
+}
+
+#[test]
+#[should_panic]
+fn test_set_nonce_by_non_admin() {
+    let (env, token, _) = deploy();
+    let user = env.next_user();
+    token.set_nonce(user, 10);
+}
+
+#[test]
+#[should_panic]
+fn test_set_nonce_backwards() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+    token.mint_generated(owner, user, vec![meta::red_dragon(), meta::blue_dragon()]);
+    token.set_nonce(owner, 1);
+}
+
+#[test]
+#[should_panic]
+fn test_set_nonce_to_max() {
+    let (_, token, owner) = deploy();
+    token.set_nonce(owner, u32::MAX);
+}
+
+#[test]
+fn test_mint_generated_up_to_nonce_limit() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+
+    token.set_nonce(owner, u32::MAX - 1);
+    token.mint_generated(owner, user, vec![meta::red_dragon()]);
+    assert_eq!(token.nonce(), u32::MAX);
+    assert_eq!(token.balance_of(Key::Account(user)), U256::one());
+}
+
+#[test]
+#[should_panic]
+fn test_mint_generated_past_nonce_limit() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+
+    token.set_nonce(owner, u32::MAX - 1);
+    token.mint_generated(owner, user, vec![meta::red_dragon(), meta::blue_dragon()]);
+}
+
+fn typed_meta(entries: &[(&str, u8, &str)]) -> TypedMeta {
+    entries
+        .iter()
+        .map(|(key, type_tag, value)| (key.to_string(), (*type_tag, value.to_string())))
+        .collect()
+}
+
+#[test]
+fn test_update_token_typed_meta() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+    let token_id = TokenId::zero();
+    let meta = typed_meta(&[
+        ("name", 0, "Red"),
+        ("level", 1, "5"),
+        ("power", 1, "-2.5"),
+        ("shiny", 2, "true"),
+    ]);
+
+    token.mint_one(owner, user, token_id, meta::red_dragon());
+    assert_eq!(token.token_typed_meta(token_id), None);
+
+    token.update_token_typed_meta(owner, token_id, meta.clone());
+    let stored = token.token_typed_meta(token_id).unwrap();
+    assert_eq!(stored, meta);
+    assert_eq!(stored["level"], (1, "5".to_string()));
+    assert_eq!(stored["shiny"], (2, "true".to_string()));
+    assert_eq!(token.token_meta(token_id).unwrap(), meta::red_dragon());
+}
+
+#[test]
+#[should_panic]
+fn test_update_token_typed_meta_with_mismatched_value() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+    let token_id = TokenId::zero();
+
+    token.mint_one(owner, user, token_id, meta::red_dragon());
+    token.update_token_typed_meta(owner, token_id, typed_meta(&[("shiny", 2, "yes")]));
+}
+
+#[test]
+fn test_can_transfer_all() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let dan = env.next_user();
+    let token_ids: Vec<TokenId> = (0..3).map(TokenId::from).collect();
+
+    token.mint_many(owner, ali, token_ids.clone(), vec![meta::red_dragon(); 3]);
+    token.mint_one(owner, dan, TokenId::from(10), meta::blue_dragon());
+    assert!(token.can_transfer_all(ali, ali, token_ids.clone()));
+    assert!(!token.can_transfer_all(bob, ali, token_ids.clone()));
+
+    token.approve(ali, bob, vec![token_ids[0], token_ids[2]]);
+    assert!(token.can_transfer_all(bob, ali, vec![token_ids[0], token_ids[2]]));
+    assert!(!token.can_transfer_all(bob, ali, token_ids.clone()));
+
+    token.set_approval_for_all(ali, dan, true);
+    assert!(token.can_transfer_all(dan, ali, token_ids.clone()));
+    assert!(!token.can_transfer_all(dan, ali, vec![token_ids[0], TokenId::from(10)]));
+    assert!(!token.can_transfer_all(dan, ali, vec![TokenId::from(99)]));
+}
+
+#[test]
+fn test_mint_with_receipt() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+
+    token.mint_one(owner, user, TokenId::from(100), meta::red_dragon());
+    env.set_block_time(7_000);
+    let receipt = token.mint_with_receipt(
+        &env,
+        owner,
+        user,
+        Some(vec![TokenId::zero(), TokenId::one()]),
+        vec![meta::blue_dragon(), meta::gold_dragon()],
+    );
+
+    assert_eq!(receipt.token_ids, vec![TokenId::zero(), TokenId::one()]);
+    assert_eq!(receipt.new_total_supply, token.total_supply());
+    assert_eq!(receipt.new_total_supply, U256::from(3));
+    assert_eq!(receipt.block_time, 7_000);
+    assert_eq!(receipt.block_time, token.last_mint_time());
+    for token_id in receipt.token_ids {
+        assert_eq!(token.owner_of(token_id).unwrap(), Key::Account(user));
+    }
+}
+
+#[test]
+fn test_mint_after_unlock_mint_id() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+    let token_id = TokenId::from(7);
+
+    token.lock_mint_id(owner, token_id);
+    assert!(token.is_mint_id_locked(token_id));
+    token.mint_one(owner, user, TokenId::from(8), meta::red_dragon());
+
+    token.unlock_mint_id(owner, token_id);
+    assert!(!token.is_mint_id_locked(token_id));
+    token.mint_one(owner, user, token_id, meta::red_dragon());
+    assert_eq!(token.owner_of(token_id).unwrap(), Key::Account(user));
+}
+
+#[test]
+#[should_panic]
+fn test_mint_locked_mint_id() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+    let token_id = TokenId::from(7);
+
+    token.lock_mint_id(owner, token_id);
+    token.mint_one(owner, user, token_id, meta::red_dragon());
+}
+
+#[test]
+#[should_panic]
+fn test_lock_mint_id_by_non_admin() {
+    let (env, token, _) = deploy();
+    let user = env.next_user();
+    token.lock_mint_id(user, TokenId::from(7));
+}
+
+#[test]
+fn test_burned_tokens() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+    let token_ids: Vec<TokenId> = (0..5).map(TokenId::from).collect();
+
+    token.mint_many(owner, user, token_ids.clone(), vec![meta::red_dragon(); 5]);
+    assert!(token.burned_tokens(U256::zero(), 10).is_empty());
+
+    token.burn_many(user, user, vec![token_ids[3], token_ids[0]]);
+    token.burn_one(user, user, token_ids[4]);
+
+    assert_eq!(
+        token.burned_tokens(U256::zero(), 10),
+        vec![token_ids[3], token_ids[0], token_ids[4]]
+    );
+    assert_eq!(
+        token.burned_tokens(U256::zero(), 2),
+        vec![token_ids[3], token_ids[0]]
+    );
+    assert_eq!(token.burned_tokens(U256::from(2), 2), vec![token_ids[4]]);
+    assert!(token.burned_tokens(U256::from(3), 2).is_empty());
+}
+
+fn serial_meta(serial: &str) -> Meta {
+    let mut meta = meta::red_dragon();
+    meta.insert("serial".to_string(), serial.to_string());
+    meta
+}
+
+#[test]
+fn test_unique_meta_value_freed_by_burn() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+
+    token.set_unique_meta_key(owner, Some("serial".to_string()));
+    token.mint_one(owner, user, TokenId::zero(), serial_meta("A-1"));
+    token.mint_one(owner, user, TokenId::one(), serial_meta("A-2"));
+
+    token.update_token_meta(owner, TokenId::one(), serial_meta("A-3"));
+    token.mint_one(owner, user, TokenId::from(2), serial_meta("A-2"));
+
+    token.burn_one(user, user, TokenId::zero());
+    token.mint_one(owner, user, TokenId::from(3), serial_meta("A-1"));
+    assert_eq!(
+        token.token_meta(TokenId::from(3)).unwrap(),
+        serial_meta("A-1")
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_mint_duplicate_unique_meta_value() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+
+    token.set_unique_meta_key(owner, Some("serial".to_string()));
+    token.mint_one(owner, user, TokenId::zero(), serial_meta("A-1"));
+    token.mint_one(owner, user, TokenId::one(), serial_meta("A-1"));
+}
+
+#[test]
+#[should_panic]
+fn test_mint_duplicate_unique_meta_value_in_batch() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+
+    token.set_unique_meta_key(owner, Some("serial".to_string()));
+    token.mint_many(
+        owner,
+        user,
+        vec![TokenId::zero(), TokenId::one()],
+        vec![serial_meta("A-1"), serial_meta("A-1")],
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_update_meta_to_duplicate_unique_meta_value() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+
+    token.set_unique_meta_key(owner, Some("serial".to_string()));
+    token.mint_one(owner, user, TokenId::zero(), serial_meta("A-1"));
+    token.mint_one(owner, user, TokenId::one(), serial_meta("A-2"));
+    token.update_token_meta(owner, TokenId::one(), serial_meta("A-1"));
+}
+
+#[test]
+fn test_pause_requires_admin_threshold() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let carol = env.next_user();
+
+    token.set_admins(
+        owner,
+        vec![Key::from(ali), Key::from(bob), Key::from(carol)],
+        2,
+    );
+    assert_eq!(token.admin_threshold(), 2);
+
+    token.set_paused(ali, true);
+    token.set_paused(ali, true);
+    assert!(!token.paused());
+
+    token.set_paused(bob, true);
+    assert!(token.paused());
+    assert_eq!(env.last_events()[0]["event_type"], "cep47_pause");
+
+    token.set_paused(carol, false);
+    assert!(token.paused());
+}
+
+#[test]
+#[should_panic]
+fn test_set_admins_removes_previous_admins() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+
+    token.set_admins(owner, vec![Key::from(ali)], 1);
+    token.set_paused(owner, true);
+}
+
+#[test]
+#[should_panic]
+fn test_set_admins_with_threshold_above_admin_count() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+
+    token.set_admins(owner, vec![Key::from(owner), Key::from(ali)], 3);
+}
+
+#[test]
+fn test_owner_token_meta_at() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+    let token_ids = vec![TokenId::from(1), TokenId::from(2)];
+    let token_metas = vec![meta::red_dragon(), meta::blue_dragon()];
+
+    token.mint_many(owner, user, token_ids, token_metas);
+    assert_eq!(
+        token.owner_token_meta_at(user, U256::one()),
+        Some((TokenId::from(2), meta::blue_dragon()))
+    );
+    assert_eq!(token.owner_token_meta_at(user, U256::from(2)), None);
+}
+
+#[test]
+fn test_mint_to_many() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    let minted = token.mint_to_many(
+        &env,
+        owner,
+        vec![
+            (Key::from(ali), meta::red_dragon()),
+            (Key::from(bob), meta::blue_dragon()),
+            (Key::from(ali), meta::gold_dragon()),
+        ],
+    );
+    assert_eq!(minted.len(), 3);
+    assert_eq!(token.total_supply(), U256::from(3));
+    for (recipient, token_id) in &minted {
+        assert_eq!(token.owner_of(*token_id).unwrap(), *recipient);
+    }
+    assert_eq!(token.token_meta(minted[1].1).unwrap(), meta::blue_dragon());
+    assert_eq!(token.balance_of(ali), U256::from(2));
+}
+
+#[test]
+fn test_approve_unfrozen_token() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.mint_one(owner, ali, TokenId::zero(), meta::red_dragon());
+    token.set_token_frozen(owner, TokenId::zero(), true);
+    assert!(token.is_frozen(TokenId::zero()));
+    token.set_token_frozen(owner, TokenId::zero(), false);
+    token.approve(ali, bob, vec![TokenId::zero()]);
+    assert_eq!(
+        token.get_approved(ali, TokenId::zero()),
+        Some(Key::from(bob))
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_approve_frozen_token() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.mint_one(owner, ali, TokenId::zero(), meta::red_dragon());
+    token.set_token_frozen(owner, TokenId::zero(), true);
+    token.approve(ali, bob, vec![TokenId::zero()]);
+}
+
+#[test]
+#[should_panic]
+fn test_transfer_frozen_token() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.mint_one(owner, ali, TokenId::zero(), meta::red_dragon());
+    token.set_token_frozen(owner, TokenId::zero(), true);
+    token.transfer(ali, bob, vec![TokenId::zero()]);
+}
+
+#[test]
+fn test_my_approvals() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let carol = env.next_user();
+    let token_ids = vec![TokenId::from(1), TokenId::from(2), TokenId::from(3)];
+    let token_metas = vec![meta::red_dragon(), meta::blue_dragon(), meta::gold_dragon()];
+
+    token.mint_many(owner, ali, token_ids, token_metas);
+    token.approve(ali, bob, vec![TokenId::from(1)]);
+    token.approve(ali, carol, vec![TokenId::from(3)]);
+
+    let mut approvals = token.my_approvals(ali, 10);
+    approvals.sort();
+    assert_eq!(
+        approvals,
+        vec![
+            (TokenId::from(1), Key::from(bob)),
+            (TokenId::from(3), Key::from(carol))
+        ]
+    );
+    assert!(token.my_approvals(bob, 10).is_empty());
+    assert!(token.my_approvals(ali, 1).len() <= 1);
+}
+
+#[test]
+fn test_validate_mint() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+
+    token.validate_mint(owner, user, None, vec![meta::red_dragon()]);
+    token.validate_mint(
+        owner,
+        user,
+        Some(vec![TokenId::zero()]),
+        vec![meta::red_dragon()],
+    );
+    assert_eq!(token.total_supply(), U256::zero());
+    assert_eq!(token.nonce(), 0);
+    assert_eq!(token.owner_of(TokenId::zero()), None);
+}
+
+#[test]
+#[should_panic]
+fn test_validate_mint_with_mismatched_lengths() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+
+    token.validate_mint(
+        owner,
+        user,
+        Some(vec![TokenId::zero(), TokenId::one()]),
+        vec![meta::red_dragon()],
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_validate_mint_with_existing_id() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+
+    token.mint_one(owner, user, TokenId::zero(), meta::red_dragon());
+    token.validate_mint(
+        owner,
+        user,
+        Some(vec![TokenId::zero()]),
+        vec![meta::blue_dragon()],
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_validate_mint_above_cap() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+
+    token.set_total_supply_cap(owner, Some(U256::one()));
+    token.validate_mint(
+        owner,
+        user,
+        None,
+        vec![meta::red_dragon(), meta::blue_dragon()],
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_revoke_during_approval_lock() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    env.set_block_time(1000);
+    token.mint_one(owner, ali, TokenId::zero(), meta::red_dragon());
+    token.approve(ali, bob, vec![TokenId::zero()]);
+    token.lock_approval(bob, TokenId::zero(), 2000);
+    token.revoke(ali, vec![TokenId::zero()]);
+}
+
+#[test]
+#[should_panic]
+fn test_overwrite_approval_during_lock() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let carol = env.next_user();
+
+    env.set_block_time(1000);
+    token.mint_one(owner, ali, TokenId::zero(), meta::red_dragon());
+    token.approve(ali, bob, vec![TokenId::zero()]);
+    token.lock_approval(bob, TokenId::zero(), 2000);
+    token.approve(ali, carol, vec![TokenId::zero()]);
+}
+
+#[test]
+#[should_panic]
+fn test_delegate_approval_during_lock() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let carol = env.next_user();
+
+    env.set_block_time(1000);
+    token.mint_one(owner, ali, TokenId::zero(), meta::red_dragon());
+    token.approve(ali, bob, vec![TokenId::zero()]);
+    token.lock_approval(bob, TokenId::zero(), 2000);
+    token.delegate_approval(bob, TokenId::zero(), carol);
+}
+
+#[test]
+fn test_revoke_after_approval_lock() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    env.set_block_time(1000);
+    token.mint_one(owner, ali, TokenId::zero(), meta::red_dragon());
+    token.approve(ali, bob, vec![TokenId::zero()]);
+    token.lock_approval(bob, TokenId::zero(), 2000);
+
+    env.set_block_time(2000);
+    token.revoke(ali, vec![TokenId::zero()]);
+    assert_eq!(token.get_approved(ali, TokenId::zero()), None);
+}
+
+#[test]
+fn test_user_operations_after_renounce_admin() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_ids = vec![TokenId::from(1), TokenId::from(2)];
+    let token_metas = vec![meta::red_dragon(), meta::blue_dragon()];
+
+    token.mint_many(owner, ali, token_ids, token_metas);
+    token.renounce_admin(owner);
+    assert!(token.admins_renounced());
+
+    token.transfer(ali, bob, vec![TokenId::from(1)]);
+    assert_eq!(token.owner_of(TokenId::from(1)).unwrap(), Key::from(bob));
+    token.burn_one(ali, ali, TokenId::from(2));
+    assert_eq!(token.total_supply(), U256::one());
+}
+
+#[test]
+#[should_panic]
+fn test_admin_operation_after_renounce_admin() {
+    let (_, token, owner) = deploy();
+
+    token.renounce_admin(owner);
+    token.set_paused(owner, true);
+}
+
+#[test]
+#[should_panic]
+fn test_mint_after_renounce_admin() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+
+    token.renounce_admin(owner);
+    token.mint_one(owner, ali, TokenId::one(), meta::red_dragon());
+}
+
+#[test]
+#[should_panic]
+fn test_update_token_meta_after_renounce_admin() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+
+    token.mint_one(owner, ali, TokenId::one(), meta::red_dragon());
+    token.renounce_admin(owner);
+    token.update_token_meta(owner, TokenId::one(), meta::blue_dragon());
+}
+
+#[test]
+fn test_burn_with_meta_events() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+    let token_id = TokenId::zero();
+
+    let mut token_meta = meta::red_dragon();
+    token_meta.insert("size".to_string(), "huge".to_string());
+    token.mint_one(owner, user, token_id, token_meta);
+    token.set_burn_meta_events(owner, true);
+    token.burn_one(user, user, token_id);
+
+    let events = env.last_events();
+    assert_eq!(events.len(), 3);
+    assert_eq!(events[0]["event_type"], "cep47_burn_one");
+    let value_of = |key: &str| {
+        events
+            .iter()
+            .find(|event| event["event_type"] == "cep47_burn_meta" && event["key"] == key)
+            .map(|event| event["value"].clone())
+    };
+    assert_eq!(value_of("color"), Some("red".to_string()));
+    assert_eq!(value_of("size"), Some("huge".to_string()));
+}
+
+#[test]
+fn test_burn_without_meta_events() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+
+    token.mint_one(owner, user, TokenId::zero(), meta::red_dragon());
+    token.burn_one(user, user, TokenId::zero());
+
+    let events = env.last_events();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0]["event_type"], "cep47_burn_one");
+}
+
+#[test]
+fn test_owners_of() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.mint_one(owner, ali, TokenId::from(1), meta::red_dragon());
+    token.mint_one(owner, bob, TokenId::from(2), meta::blue_dragon());
+    token.mint_one(owner, bob, TokenId::from(3), meta::gold_dragon());
+    token.burn_one(bob, bob, TokenId::from(3));
+
+    assert_eq!(
+        token.owners_of(vec![TokenId::from(2), TokenId::from(3), TokenId::from(1)]),
+        vec![
+            (TokenId::from(2), Some(Key::from(bob))),
+            (TokenId::from(3), None),
+            (TokenId::from(1), Some(Key::from(ali)))
+        ]
+    );
+}
+
+#[test]
+fn test_transfer_to_owner_by_spender_keeps_approval() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.mint_one(owner, ali, TokenId::one(), meta::red_dragon());
+    token.approve(ali, bob, vec![TokenId::one()]);
+    token.transfer_from(bob, ali, ali, vec![TokenId::one()]);
+
+    assert_eq!(token.owner_of(TokenId::one()).unwrap(), Key::from(ali));
+    assert_eq!(
+        token.get_approved(ali, TokenId::one()),
+        Some(Key::from(bob))
+    );
+}
+
+#[test]
+fn test_transfer_to_self_keeps_index() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let token_ids = vec![TokenId::from(1), TokenId::from(2), TokenId::from(3)];
+    let token_metas = vec![meta::red_dragon(), meta::blue_dragon(), meta::gold_dragon()];
+
+    token.mint_many(owner, ali, token_ids.clone(), token_metas);
+    token.transfer(ali, ali, vec![TokenId::from(1), TokenId::from(2)]);
+
+    assert_eq!(token.balance_of(ali), U256::from(3));
+    for (index, token_id) in token_ids.into_iter().enumerate() {
+        assert_eq!(
+            token.get_token_by_index(ali, U256::from(index)),
+            Some(token_id)
+        );
+        assert_eq!(token.owner_of(token_id).unwrap(), Key::from(ali));
+    }
+}
+
+#[test]
+fn test_feature_flags() {
+    let (_, token, owner) = deploy();
+    let events_enabled = 1 << 5;
+    assert_eq!(token.feature_flags(), events_enabled);
+
+    token.set_paused(owner, true);
+    token.set_burn_meta_events(owner, true);
+    token.set_total_supply_cap(owner, Some(U256::from(10)));
+    assert_eq!(token.feature_flags(), 1 | 1 << 4 | events_enabled | 1 << 7);
+}
+
+#[test]
+fn test_mint_random_follows_weights() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+    let templates = vec![
+        (3, meta::red_dragon()),
+        (1, meta::blue_dragon()),
+        (0, meta::gold_dragon()),
+    ];
+    let mints = 100;
+
+    for block_time in 0..mints {
+        env.set_block_time(block_time);
+        token.mint_random(owner, user, templates.clone());
+    }
+    assert_eq!(token.balance_of(user), U256::from(mints));
+
+    let metas: Vec<Meta> = token
+        .all_tokens(U256::zero(), mints as u32)
+        .into_iter()
+        .map(|token_id| token.token_meta(token_id).unwrap())
+        .collect();
+    let reds = metas.iter().filter(|m| **m == meta::red_dragon()).count();
+    assert!(!metas.contains(&meta::gold_dragon()));
+    assert!((60..=90).contains(&reds), "{} red out of {}", reds, mints);
+}
+
+#[test]
+#[should_panic]
+fn test_mint_random_with_zero_weights() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+
+    token.mint_random(owner, user, vec![(0, meta::red_dragon())]);
+}
+
+#[test]
+fn test_owner_has_attribute() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.mint_many(
+        owner,
+        ali,
+        vec![TokenId::from(1), TokenId::from(2)],
+        vec![meta::blue_dragon(), meta::red_dragon()],
+    );
+    token.mint_one(owner, bob, TokenId::from(3), meta::blue_dragon());
+
+    assert!(token.owner_has_attribute(ali, "color", "red", 10));
+    assert!(!token.owner_has_attribute(ali, "color", "red", 1));
+    assert!(!token.owner_has_attribute(bob, "color", "red", 10));
+}
+
+#[test]
+fn test_meta_resolver_version() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+    let resolver = ContractHash::new([7u8; 32]);
+    assert_eq!(token.meta_resolver_version(), 0);
+
+    token.set_dynamic_meta_contract(owner, Some(resolver));
+    assert_eq!(token.meta_resolver_version(), 1);
+    token.set_dynamic_meta_contract(owner, Some(resolver));
+    assert_eq!(token.meta_resolver_version(), 1);
+    token.set_dynamic_meta_contract(owner, None);
+    assert_eq!(token.meta_resolver_version(), 2);
+
+    token.mint_one(owner, user, TokenId::zero(), meta::red_dragon());
+    token.update_token_meta(owner, TokenId::zero(), meta::blue_dragon());
+    let events = env.last_events();
+    assert_eq!(events[0]["event_type"], "cep47_metadata_update");
+    assert_eq!(events[0]["resolver_version"], "2");
+}
+
+#[test]
+fn test_burn_if_meta() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+
+    token.mint_one(owner, user, TokenId::zero(), meta::red_dragon());
+    token.burn_if_meta(user, TokenId::zero(), "color", "red");
+    assert_eq!(token.owner_of(TokenId::zero()), None);
+    assert_eq!(token.total_supply(), U256::zero());
+}
+
+#[test]
+#[should_panic]
+fn test_burn_if_meta_mismatch() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+
+    token.mint_one(owner, user, TokenId::zero(), meta::red_dragon());
+    token.burn_if_meta(user, TokenId::zero(), "color", "blue");
+}
+
+#[test]
+fn test_process_mint_queue_in_chunks() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.enqueue_mint(owner, ali, meta::red_dragon());
+    token.enqueue_mint(owner, bob, meta::blue_dragon());
+    token.enqueue_mint(owner, ali, meta::gold_dragon());
+    assert_eq!(token.mint_queue_len(), 3);
+    assert_eq!(token.total_supply(), U256::zero());
+
+    token.process_mint_queue(owner, 2);
+    assert_eq!(token.mint_queue_len(), 1);
+    assert_eq!(token.balance_of(ali), U256::one());
+    assert_eq!(token.balance_of(bob), U256::one());
+
+    token.process_mint_queue(owner, 5);
+    assert_eq!(token.mint_queue_len(), 0);
+    assert_eq!(token.balance_of(ali), U256::from(2));
+    let last = token.get_token_by_index(ali, U256::one()).unwrap();
+    assert_eq!(token.token_meta(last).unwrap(), meta::gold_dragon());
+
+    token.process_mint_queue(owner, 1);
+    assert_eq!(token.total_supply(), U256::from(3));
+}
+
+#[test]
+fn test_holdings_diff() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.mint_many(
+        owner,
+        ali,
+        vec![TokenId::from(1), TokenId::from(2)],
+        vec![meta::red_dragon(), meta::blue_dragon()],
+    );
+    token.mint_one(owner, bob, TokenId::from(3), meta::gold_dragon());
+
+    assert_eq!(
+        token.holdings_diff(ali, bob, 10),
+        (
+            vec![TokenId::from(1), TokenId::from(2)],
+            vec![TokenId::from(3)]
+        )
+    );
+    assert_eq!(
+        token.holdings_diff(ali, bob, 1),
+        (vec![TokenId::from(1)], vec![TokenId::from(3)])
+    );
+    assert_eq!(token.holdings_diff(ali, ali, 10), (vec![], vec![]));
+}
+
+#[test]
+fn test_simulate_transfer() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.mint_one(owner, ali, TokenId::zero(), meta::red_dragon());
+    token.simulate_transfer(ali, ali, bob, vec![TokenId::zero()]);
+    token.approve(ali, bob, vec![TokenId::zero()]);
+    token.simulate_transfer(bob, ali, bob, vec![TokenId::zero()]);
+    assert_eq!(token.owner_of(TokenId::zero()).unwrap(), Key::from(ali));
+    assert_eq!(
+        token.get_approved(ali, TokenId::zero()),
+        Some(Key::from(bob))
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_simulate_transfer_without_approval() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.mint_one(owner, ali, TokenId::zero(), meta::red_dragon());
+    token.simulate_transfer(bob, ali, bob, vec![TokenId::zero()]);
+}
+
+#[test]
+#[should_panic]
+fn test_simulate_transfer_of_missing_token() {
+    let (env, token, _) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.simulate_transfer(ali, ali, bob, vec![TokenId::zero()]);
+}
+
+#[test]
+#[should_panic]
+fn test_simulate_transfer_while_paused() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.mint_one(owner, ali, TokenId::zero(), meta::red_dragon());
+    token.set_transfers_paused(owner, true);
+    token.simulate_transfer(ali, ali, bob, vec![TokenId::zero()]);
+}
+
+#[test]
+fn test_approvals_up_to_owner_limit() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let carol = env.next_user();
+    let token_ids = vec![TokenId::from(1), TokenId::from(2), TokenId::from(3)];
+    let token_metas = vec![meta::red_dragon(), meta::blue_dragon(), meta::gold_dragon()];
+
+    token.mint_many(owner, ali, token_ids, token_metas);
+    token.set_max_approvals_per_owner(owner, Some(2));
+    token.approve(ali, bob, vec![TokenId::from(1), TokenId::from(2)]);
+    assert_eq!(token.approval_count(ali), 2);
+
+    // Overwriting an existing approval doesn't count against the limit.
+    token.approve(ali, carol, vec![TokenId::from(1)]);
+    token.revoke(ali, vec![TokenId::from(2)]);
+    assert_eq!(token.approval_count(ali), 1);
+    token.approve(ali, carol, vec![TokenId::from(3)]);
+    assert_eq!(token.approval_count(ali), 2);
+}
+
+#[test]
+#[should_panic]
+fn test_approve_above_owner_limit() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_ids = vec![TokenId::from(1), TokenId::from(2), TokenId::from(3)];
+    let token_metas = vec![meta::red_dragon(), meta::blue_dragon(), meta::gold_dragon()];
+
+    token.mint_many(owner, ali, token_ids.clone(), token_metas);
+    token.set_max_approvals_per_owner(owner, Some(2));
+    token.approve(ali, bob, token_ids);
+}
+
+#[test]
+fn test_stamp_on_transfer() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.mint_one(owner, ali, TokenId::zero(), meta::red_dragon());
+    token.transfer(ali, bob, vec![TokenId::zero()]);
+    assert_eq!(
+        token.token_meta(TokenId::zero()).unwrap(),
+        meta::red_dragon()
+    );
+
+    token.set_stamp_on_transfer(owner, true);
+    env.set_block_time(1234);
+    token.transfer(bob, ali, vec![TokenId::zero()]);
+
+    let mut expected = meta::red_dragon();
+    expected.insert("last_transfer".to_string(), "1234".to_string());
+    assert_eq!(token.token_meta(TokenId::zero()).unwrap(), expected);
+}
+
+#[test]
+fn test_ownership_histogram() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let carol = env.next_user();
+    let dave = env.next_user();
+
+    token.mint_copies_multi(owner, ali, vec![(meta::red_dragon(), 3)]);
+    token.mint_copies_multi(owner, bob, vec![(meta::blue_dragon(), 1)]);
+    token.mint_copies_multi(owner, carol, vec![(meta::gold_dragon(), 6)]);
+    token.mint_copies_multi(owner, dave, vec![(meta::black_dragon(), 1)]);
+
+    let dave_token = token.get_token_by_index(dave, U256::zero()).unwrap();
+    token.transfer(dave, ali, vec![dave_token]);
+    assert_eq!(token.holder_count(), 3);
+
+    let buckets = vec![U256::one(), U256::from(2), U256::from(5)];
+    assert_eq!(
+        token.ownership_histogram(buckets.clone(), 0, 10),
+        vec![1, 1, 1]
+    );
+    assert_eq!(
+        token.ownership_histogram(buckets, 0, 2).iter().sum::<u32>(),
+        2
+    );
+}
+
+#[test]
+fn test_transferer_role_moves_token() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let cat = env.next_user();
+
+    token.mint_one(owner, ali, TokenId::zero(), meta::red_dragon());
+    token.grant_token_role(ali, TokenId::zero(), "transferer", bob);
+    token.transfer_from(bob, ali, cat, vec![TokenId::zero()]);
+    assert_eq!(token.owner_of(TokenId::zero()).unwrap(), Key::from(cat));
+}
+
+#[test]
+#[should_panic]
+fn test_transferer_role_does_not_survive_transfer() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let cat = env.next_user();
+
+    token.mint_one(owner, ali, TokenId::zero(), meta::red_dragon());
+    token.grant_token_role(ali, TokenId::zero(), "transferer", bob);
+    token.transfer(ali, cat, vec![TokenId::zero()]);
+    token.transfer_from(bob, cat, bob, vec![TokenId::zero()]);
+}
+
+#[test]
+#[should_panic]
+fn test_transferer_role_does_not_survive_round_trip() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let cat = env.next_user();
+
+    token.mint_one(owner, ali, TokenId::zero(), meta::red_dragon());
+    token.grant_token_role(ali, TokenId::zero(), "transferer", bob);
+    token.transfer(ali, cat, vec![TokenId::zero()]);
+    token.transfer(cat, ali, vec![TokenId::zero()]);
+    token.transfer_from(bob, ali, bob, vec![TokenId::zero()]);
+}
+
+#[test]
+#[should_panic]
+fn test_mint_burned_token_id() {
+    let (env, token, owner) = deploy();
+    let user = env.next_user();
+
+    token.mint_one(owner, user, TokenId::zero(), meta::red_dragon());
+    token.burn_one(user, user, TokenId::zero());
+    assert!(token.is_burned(TokenId::zero()));
+    token.mint_one(owner, user, TokenId::zero(), meta::red_dragon());
+}
+
+#[test]
+#[should_panic]
+fn test_revoked_transferer_role() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.mint_one(owner, ali, TokenId::zero(), meta::red_dragon());
+    token.grant_token_role(ali, TokenId::zero(), "transferer", bob);
+    token.revoke_token_role(ali, TokenId::zero(), "transferer", bob);
+    token.transfer_from(bob, ali, bob, vec![TokenId::zero()]);
+}
+
+#[test]
+#[should_panic]
+fn test_grant_token_role_by_non_owner() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.mint_one(owner, ali, TokenId::zero(), meta::red_dragon());
+    token.grant_token_role(bob, TokenId::zero(), "transferer", bob);
+}
+
+#[test]
+fn test_forge() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let ids = vec![TokenId::zero(), TokenId::one()];
+
+    token.mint_many(
+        owner,
+        ali,
+        ids.clone(),
+        vec![meta::red_dragon(), meta::blue_dragon()],
+    );
+    token.forge(ali, ids, meta::gold_dragon());
+    assert_eq!(token.total_supply(), U256::one());
+    assert_eq!(token.balance_of(ali), U256::one());
+    assert!(token.owner_of(TokenId::zero()).is_none());
+    assert!(token.owner_of(TokenId::one()).is_none());
+
+    let forged = token.get_token_by_index(ali, U256::zero()).unwrap();
+    assert_eq!(token.token_meta(forged).unwrap(), meta::gold_dragon());
+}
+
+#[test]
+fn test_forge_at_min_supply() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+
+    token.mint_many(
+        owner,
+        ali,
+        vec![TokenId::zero(), TokenId::one()],
+        vec![meta::red_dragon(), meta::blue_dragon()],
+    );
+    token.set_min_supply(owner, Some(U256::from(2)));
+    token.forge(ali, vec![TokenId::zero()], meta::gold_dragon());
+    assert_eq!(token.total_supply(), U256::from(2));
+}
+
+#[test]
+#[should_panic]
+fn test_forge_below_min_supply() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+
+    token.mint_many(
+        owner,
+        ali,
+        vec![TokenId::zero(), TokenId::one()],
+        vec![meta::red_dragon(), meta::blue_dragon()],
+    );
+    token.set_min_supply(owner, Some(U256::from(2)));
+    token.forge(
+        ali,
+        vec![TokenId::zero(), TokenId::one()],
+        meta::gold_dragon(),
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_forge_with_not_owned_input() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.mint_one(owner, ali, TokenId::zero(), meta::red_dragon());
+    token.mint_one(owner, bob, TokenId::one(), meta::blue_dragon());
+    token.forge(
+        ali,
+        vec![TokenId::zero(), TokenId::one()],
+        meta::gold_dragon(),
+    );
+}
+
+#[test]
+fn test_mint_idempotent_retry() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+
+    token.mint_idempotent(owner, ali, vec![meta::red_dragon()], "order-1");
+    let minted = token.get_token_by_index(ali, U256::zero()).unwrap();
+
+    env.set_block_time(1);
+    token.mint_idempotent(owner, ali, vec![meta::red_dragon()], "order-1");
+    assert_eq!(token.total_supply(), U256::one());
+    assert_eq!(token.balance_of(ali), U256::one());
+    assert_eq!(token.get_token_by_index(ali, U256::zero()), Some(minted));
+}
+
+#[test]
+#[should_panic]
+fn test_mint_idempotent_key_reused_for_other_request() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+
+    token.mint_idempotent(owner, ali, vec![meta::red_dragon()], "order-1");
+    token.mint_idempotent(owner, ali, vec![meta::blue_dragon()], "order-1");
+}
+
+#[test]
+fn test_dictionary_names() {
+    let (env, token, owner) = deploy();
+    let mut names = token.dictionary_names(&env, owner);
+    for name in &names {
+        token.assert_dictionary_exists(name);
+    }
+
+    let mut expected: Vec<String> = [
+        "owners",
+        "owned_tokens_by_index",
+        "owned_indexes_by_token",
+        "balances",
+        "metadata",
+        "allowances",
+        "approval_counts",
+        "mint_times",
+        "minters",
+        "operators",
+        "minted_count",
+        "all_tokens_by_index",
+        "all_indexes_by_token",
+        "last_transfer",
+        "expiries",
+        "burned_tokens",
+        "meta_templates",
+        "token_templates",
+        "supported_interfaces",
+        "recent_events",
+        "used_meta_nonces",
+        "token_royalties",
+        "typed_metadata",
+        "mint_locked_ids",
+        "burned_tokens_by_index",
+        "used_meta_values",
+        "frozen_tokens",
+        "approval_locks",
+        "mint_queue",
+        "holders_by_index",
+        "holder_indexes",
+        "token_acl",
+        "used_mint_keys",
+        "view_counts",
+        "transfer_allowlist",
+        "token_fees",
+        "meta_versions",
+        "trait_counts",
+        "template_usage",
+        "admins",
+        "admin_approvals",
+    ]
+    .iter()
+    .map(|name| String::from(*name))
+    .collect();
+    names.sort();
+    expected.sort();
+    assert_eq!(names, expected);
+}
+
+#[test]
+fn test_approve_and_revoke_for_all_many() {
+    let (env, token, _) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let cat = env.next_user();
+    let operators = vec![Key::from(bob), Key::from(cat)];
+
+    token.approve_for_all_many(ali, operators.clone());
+    assert!(token.is_approved_for_all(ali, bob));
+    assert!(token.is_approved_for_all(ali, cat));
+    let events = env.last_events();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0]["event_type"], "cep47_approval_for_all_many");
+    assert_eq!(
+        events[0]["operators"],
+        format!("{},{}", Key::from(bob), Key::from(cat))
+    );
+    assert_eq!(events[0]["approved"], "true");
+
+    token.revoke_for_all_many(ali, operators);
+    assert!(!token.is_approved_for_all(ali, bob));
+    assert!(!token.is_approved_for_all(ali, cat));
+    assert_eq!(env.last_events()[0]["approved"], "false");
+}
+
+#[test]
+#[should_panic]
+fn test_approve_for_all_many_including_self() {
+    let (env, token, _) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.approve_for_all_many(ali, vec![Key::from(bob), Key::from(ali)]);
+}
+
+#[test]
+fn test_record_view() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let recorder = env.next_user();
+
+    token.mint_one(owner, ali, TokenId::zero(), meta::red_dragon());
+    token.set_view_recorder(owner, Some(recorder));
+    assert_eq!(token.view_count(TokenId::zero()), 0);
+    token.record_view(recorder, TokenId::zero());
+    token.record_view(recorder, TokenId::zero());
+    assert_eq!(token.view_count(TokenId::zero()), 2);
+}
+
+#[test]
+#[should_panic]
+fn test_record_view_by_unauthorized_caller() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let recorder = env.next_user();
+
+    token.mint_one(owner, ali, TokenId::zero(), meta::red_dragon());
+    token.set_view_recorder(owner, Some(recorder));
+    token.record_view(ali, TokenId::zero());
+}
+
+#[test]
+fn test_token_meta_resolved_merges_defaults() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let mut defaults = Meta::new();
+    defaults.insert("color".to_string(), "grey".to_string());
+    defaults.insert("series".to_string(), "genesis".to_string());
+
+    token.mint_one(owner, ali, TokenId::zero(), meta::red_dragon());
+    token.set_default_meta(owner, defaults);
+    let resolved = token
+        .token_meta_resolved(&env, owner, TokenId::zero())
+        .unwrap();
+
+    let mut expected = meta::red_dragon();
+    expected.insert("series".to_string(), "genesis".to_string());
+    assert_eq!(resolved, expected);
+    assert_eq!(
+        token.token_meta(TokenId::zero()).unwrap(),
+        meta::red_dragon()
+    );
+}
+
+#[test]
+fn test_token_meta_resolved_token_key_overrides_default() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let mut defaults = Meta::new();
+    defaults.insert("color".to_string(), "grey".to_string());
+
+    token.mint_one(owner, ali, TokenId::zero(), meta::red_dragon());
+    token.set_default_meta(owner, defaults);
+    let resolved = token
+        .token_meta_resolved(&env, owner, TokenId::zero())
+        .unwrap();
+    assert_eq!(resolved["color"], meta::red_dragon()["color"]);
+    assert!(token
+        .token_meta_resolved(&env, owner, TokenId::one())
+        .is_none());
+}
+
+#[test]
+fn test_transfer_keep_operator() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let staking = env.next_user();
+
+    token.mint_one(owner, ali, TokenId::zero(), meta::red_dragon());
+    token.set_approval_for_all(bob, ali, true);
+    token.transfer_keep_operator(ali, bob, TokenId::zero(), staking);
+    assert_eq!(token.owner_of(TokenId::zero()).unwrap(), Key::from(bob));
+    assert_eq!(
+        token.get_approved(bob, TokenId::zero()),
+        Some(Key::from(staking))
+    );
+    assert_eq!(token.get_approved(ali, TokenId::zero()), None);
+
+    let event_types: Vec<String> = env
+        .last_events()
+        .iter()
+        .map(|event| event["event_type"].clone())
+        .collect();
+    assert!(event_types.contains(&String::from("cep47_transfer_token")));
+    assert!(event_types.contains(&String::from("cep47_approve_token")));
+}
+
+#[test]
+#[should_panic]
+fn test_transfer_keep_operator_without_recipient_consent() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let staking = env.next_user();
+
+    token.mint_one(owner, ali, TokenId::zero(), meta::red_dragon());
+    token.transfer_keep_operator(ali, bob, TokenId::zero(), staking);
+}
+
+#[test]
+fn test_genesis_supply() {
+    let env = TestEnv::new();
+    let owner = env.next_user();
+    let ali = env.next_user();
+    let token = CEP47Instance::new_with_genesis_mints(
+        &env,
+        NAME,
+        owner,
+        NAME,
+        SYMBOL,
+        meta::contract_meta(),
+        vec![
+            (Key::from(owner), meta::red_dragon()),
+            (Key::from(ali), meta::blue_dragon()),
+        ],
+    );
+    assert_eq!(token.genesis_supply(), U256::from(2));
+    assert_eq!(token.balance_of(ali), U256::one());
+
+    token.mint_one(owner, ali, TokenId::zero(), meta::gold_dragon());
+    assert_eq!(token.total_supply(), U256::from(3));
+    assert_eq!(token.genesis_supply(), U256::from(2));
+}
+
+#[test]
+fn test_admin_burn_across_owners() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let (t0, t1, t2) = (TokenId::zero(), TokenId::one(), TokenId::from(2));
+
+    token.mint_many(
+        owner,
+        ali,
+        vec![t0, t1],
+        vec![meta::red_dragon(), meta::blue_dragon()],
+    );
+    token.mint_one(owner, bob, t2, meta::gold_dragon());
+    token.admin_burn(owner, vec![t0, t2]);
+
+    assert_eq!(token.total_supply(), U256::one());
+    assert_eq!(token.balance_of(ali), U256::one());
+    assert_eq!(token.balance_of(bob), U256::zero());
+    assert!(token.owner_of(t0).is_none());
+    assert!(token.owner_of(t2).is_none());
+
+    let admin_burns: Vec<_> = env
+        .last_events()
+        .into_iter()
+        .filter(|event| event["event_type"] == "cep47_admin_burn")
+        .collect();
+    assert_eq!(admin_burns.len(), 2);
+}
+
+#[test]
+#[should_panic]
+fn test_admin_burn_by_non_admin() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+
+    token.mint_one(owner, ali, TokenId::zero(), meta::red_dragon());
+    token.admin_burn(ali, vec![TokenId::zero()]);
+}
+
+#[test]
+fn test_is_admin() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    assert!(token.is_admin(owner));
+    assert!(!token.is_admin(ali));
+
+    token.set_admins(owner, vec![Key::from(owner), Key::from(ali)], 1);
+    assert!(token.is_admin(ali));
+    assert!(!token.is_admin(bob));
+
+    token.renounce_admin(owner);
+    assert!(!token.is_admin(owner));
+    assert!(!token.is_admin(ali));
+}
+
+#[test]
+fn test_tokens_minted_between() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+
+    for (block_time, token_id) in [(100, 0u32), (200, 1), (300, 2)] {
+        env.set_block_time(block_time);
+        token.mint_one(owner, ali, TokenId::from(token_id), meta::red_dragon());
+    }
+    assert_eq!(
+        token.tokens_minted_between(150, 300, U256::zero(), 10),
+        vec![TokenId::one(), TokenId::from(2)]
+    );
+    assert_eq!(
+        token.tokens_minted_between(100, 100, U256::zero(), 10),
+        vec![TokenId::zero()]
+    );
+    assert!(token
+        .tokens_minted_between(301, 400, U256::zero(), 10)
+        .is_empty());
+    assert_eq!(
+        token.tokens_minted_between(0, 400, U256::zero(), 2),
+        vec![TokenId::zero(), TokenId::one()]
+    );
+    assert_eq!(
+        token.tokens_minted_between(0, 400, U256::from(2), 2),
+        vec![TokenId::from(2)]
+    );
+}
+
+#[test]
+fn test_last_error_records_failed_checks() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.mint_one(owner, ali, TokenId::zero(), meta::red_dragon());
+    token.set_debug_errors(owner, true);
+    assert_eq!(token.last_error(), None);
+
+    token.validate_mint(
+        owner,
+        ali,
+        Some(vec![TokenId::zero()]),
+        vec![meta::blue_dragon()],
+    );
+    assert_eq!(
+        token.last_error(),
+        Some(String::from("mint of token_ids 0 failed with error 3"))
+    );
+
+    token.simulate_transfer(bob, ali, bob, vec![TokenId::zero()]);
+    assert_eq!(
+        token.last_error(),
+        Some(format!(
+            "simulate_transfer of token_ids 0 from {} to {} failed with error 1",
+            Key::from(ali),
+            Key::from(bob)
+        ))
+    );
+
+    token.simulate_transfer(ali, ali, bob, vec![TokenId::zero()]);
+    assert_eq!(token.last_error(), None);
+}
+
+#[test]
+#[should_panic]
+fn test_failed_check_reverts_without_debug_errors() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.mint_one(owner, ali, TokenId::zero(), meta::red_dragon());
+    token.simulate_transfer(bob, ali, bob, vec![TokenId::zero()]);
+}
+
+#[test]
+fn test_announce_supply() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let token_ids = vec![TokenId::zero(), TokenId::one(), TokenId::from(2)];
+    let token_metas = vec![meta::red_dragon(), meta::blue_dragon(), meta::gold_dragon()];
+
+    token.mint_many(owner, ali, token_ids, token_metas);
+    token.burn_one(ali, ali, TokenId::one());
+    token.announce_supply(ali);
+
+    let events = env.last_events();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0]["event_type"], "cep47_supply_announcement");
+    assert_eq!(events[0]["total_supply"], "2");
+    assert_eq!(events[0]["total_minted"], "3");
+    assert_eq!(events[0]["total_burned"], "1");
+}
+
+#[test]
+fn test_holds_exactly() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let (t0, t1, t2) = (TokenId::zero(), TokenId::one(), TokenId::from(2));
+
+    token.mint_many(
+        owner,
+        ali,
+        vec![t0, t1],
+        vec![meta::red_dragon(), meta::blue_dragon()],
+    );
+    token.mint_one(owner, owner, t2, meta::gold_dragon());
+
+    assert!(token.holds_exactly(ali, vec![t1, t0]));
+    assert!(!token.holds_exactly(ali, vec![t0]));
+    assert!(!token.holds_exactly(ali, vec![t0, t1, t2]));
+    assert!(!token.holds_exactly(ali, vec![t0, t0]));
+}
+
+#[test]
+fn test_transfer_to_allowlisted_recipient() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.set_transfer_allowlisted(owner, vec![Key::from(ali), Key::from(bob)], true);
+    token.set_transfer_restricted(owner, true);
+    token.mint_one(owner, ali, TokenId::zero(), meta::red_dragon());
+    token.transfer(ali, bob, vec![TokenId::zero()]);
+    assert_eq!(token.owner_of(TokenId::zero()).unwrap(), Key::from(bob));
+}
+
+#[test]
+#[should_panic]
+fn test_transfer_to_recipient_off_allowlist() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.set_transfer_allowlisted(owner, vec![Key::from(ali)], true);
+    token.set_transfer_restricted(owner, true);
+    token.mint_one(owner, ali, TokenId::zero(), meta::red_dragon());
+    token.transfer(ali, bob, vec![TokenId::zero()]);
+}
+
+#[test]
+#[should_panic]
+fn test_transfer_to_removed_allowlist_entry() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.set_transfer_allowlisted(owner, vec![Key::from(ali), Key::from(bob)], true);
+    token.set_transfer_restricted(owner, true);
+    token.mint_one(owner, ali, TokenId::zero(), meta::red_dragon());
+    token.set_transfer_allowlisted(owner, vec![Key::from(bob)], false);
+    token.transfer(ali, bob, vec![TokenId::zero()]);
+}
+
+#[test]
+fn test_peek_token_ids_matches_mint() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+
+    env.set_block_time(1234);
+    let peeked = token.peek_then_mint(
+        &env,
+        owner,
+        ali,
+        vec![meta::red_dragon(), meta::blue_dragon()],
+    );
+    assert_eq!(peeked.len(), 2);
+    assert_eq!(token.get_token_by_index(ali, U256::zero()), Some(peeked[0]));
+    assert_eq!(token.get_token_by_index(ali, U256::one()), Some(peeked[1]));
+    assert_eq!(token.nonce(), 2);
+}
+
+#[test]
+fn test_transfer_with_token_fee_override() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let treasury = env.next_user();
+    let (t0, t1) = (TokenId::zero(), TokenId::one());
+    let fee_amount = |env: &TestEnv| {
+        env.last_events()
+            .into_iter()
+            .find(|event| event["event_type"] == "cep47_transfer_fee")
+            .unwrap()["amount"]
+            .clone()
+    };
+
+    token.mint_many(
+        owner,
+        ali,
+        vec![t0, t1],
+        vec![meta::red_dragon(), meta::blue_dragon()],
+    );
+    token.set_transfer_fee(owner, 250, treasury);
+    token.set_token_fee(owner, t0, Some(1000));
+
+    token.transfer_with_fee(ali, bob, vec![t0], U256::from(1_000_000));
+    assert_eq!(fee_amount(&env), "100000");
+
+    token.transfer_with_fee(ali, bob, vec![t1], U256::from(1_000_000));
+    assert_eq!(fee_amount(&env), "25000");
+}
+
+#[test]
+fn test_token_fee_removed_on_burn() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+
+    token.mint_one(owner, ali, TokenId::zero(), meta::red_dragon());
+    token.set_token_fee(owner, TokenId::zero(), Some(1000));
+    assert_eq!(token.token_fee(TokenId::zero()), Some(1000));
+
+    token.burn_one(ali, ali, TokenId::zero());
+    assert_eq!(token.token_fee(TokenId::zero()), None);
+}
+
+#[test]
+#[should_panic]
+fn test_set_token_fee_by_non_admin() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+
+    token.mint_one(owner, ali, TokenId::zero(), meta::red_dragon());
+    token.set_token_fee(ali, TokenId::zero(), Some(1000));
+}
+
+#[test]
+fn test_require_explicit_ids_allows_explicit_mint() {
+    let env = TestEnv::new();
+    let owner = env.next_user();
+    let ali = env.next_user();
+    let token = CEP47Instance::new_with_require_explicit_ids(
+        &env,
+        NAME,
+        owner,
+        NAME,
+        SYMBOL,
+        meta::contract_meta(),
+    );
+    assert!(token.require_explicit_ids());
+
+    token.mint_one(owner, ali, TokenId::from(7), meta::red_dragon());
+    assert_eq!(token.owner_of(TokenId::from(7)).unwrap(), Key::Account(ali));
+}
+
+#[test]
+#[should_panic]
+fn test_require_explicit_ids_rejects_generated_ids() {
+    let env = TestEnv::new();
+    let owner = env.next_user();
+    let token = CEP47Instance::new_with_require_explicit_ids(
+        &env,
+        NAME,
+        owner,
+        NAME,
+        SYMBOL,
+        meta::contract_meta(),
+    );
+    token.mint_for_self(owner, vec![meta::red_dragon()]);
+}
+
+#[test]
+fn test_meta_versions() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+
+    token.mint_many(
+        owner,
+        ali,
+        vec![TokenId::from(1), TokenId::from(2)],
+        vec![meta::red_dragon(), meta::blue_dragon()],
+    );
+    token.update_token_meta(owner, TokenId::from(2), meta::gold_dragon());
+    token.burn_one(ali, ali, TokenId::from(1));
+
+    assert_eq!(
+        token.meta_versions(vec![TokenId::from(1), TokenId::from(2), TokenId::from(3)]),
+        vec![None, Some(2), None]
+    );
+}
+
+#[test]
+fn test_rarity_score_favours_rare_traits() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+
+    token.mint_many(
+        owner,
+        ali,
+        vec![TokenId::from(1), TokenId::from(2), TokenId::from(3)],
+        vec![meta::red_dragon(), meta::red_dragon(), meta::blue_dragon()],
+    );
+    let common = token.rarity_score(TokenId::from(1), vec!["color"]);
+    let rare = token.rarity_score(TokenId::from(3), vec!["color"]);
+    assert!(rare > common);
+    assert_eq!(rare, U256::from(1_000_000));
+
+    token.burn_one(ali, ali, TokenId::from(2));
+    assert_eq!(
+        token.rarity_score(TokenId::from(1), vec!["color"]),
+        U256::from(1_000_000)
+    );
+    assert_eq!(
+        token.rarity_score(TokenId::from(1), vec!["missing"]),
+        U256::zero()
+    );
+}
+
+#[test]
+fn test_aggregate_balance_matches_total_supply() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.mint_many(
+        owner,
+        ali,
+        vec![TokenId::from(1), TokenId::from(2)],
+        vec![meta::red_dragon(), meta::blue_dragon()],
+    );
+    token.mint_one(owner, bob, TokenId::from(3), meta::gold_dragon());
+    token.transfer(ali, bob, vec![TokenId::from(1)]);
+
+    let owners = vec![Key::from(ali), Key::from(bob), Key::from(owner)];
+    assert_eq!(token.aggregate_balance(owners), token.total_supply());
+    assert_eq!(
+        token.aggregate_balance(vec![Key::from(bob), Key::from(bob)]),
+        U256::from(2)
+    );
+}
+
+#[test]
+fn test_notify_on_mint_to_accepting_contract() {
+    let (env, token, owner) = deploy();
+    let receiver = deploy_receiver(&env, owner, "accepting_receiver", true);
+
+    token.set_notify_on_mint(owner, true);
+    token.mint_one(owner, receiver, TokenId::zero(), meta::red_dragon());
+    assert_eq!(token.owner_of(TokenId::zero()).unwrap(), receiver);
+    assert_eq!(token.feature_flags() & (1 << 13), 1 << 13);
+}
+
+#[test]
+#[should_panic]
+fn test_notify_on_mint_to_reverting_contract() {
+    let (env, token, owner) = deploy();
+    let receiver = deploy_receiver(&env, owner, "rejecting_receiver", false);
+
+    token.set_notify_on_mint(owner, true);
+    token.mint_one(owner, receiver, TokenId::zero(), meta::red_dragon());
+}
+
+#[test]
+fn test_mint_to_reverting_contract_without_notify() {
+    let (env, token, owner) = deploy();
+    let receiver = deploy_receiver(&env, owner, "rejecting_receiver", false);
+
+    token.mint_one(owner, receiver, TokenId::zero(), meta::red_dragon());
+    assert_eq!(token.owner_of(TokenId::zero()).unwrap(), receiver);
+}
+
+#[test]
+fn test_trait_counts_follow_template_mints() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+
+    token.mint_one(owner, ali, TokenId::from(100), meta::gold_dragon());
+    token.set_meta_template(owner, 7, meta::gold_dragon());
+    token.mint_from_template(owner, ali, 7, 2);
+    let template_ids = token.all_tokens(U256::one(), 2);
+    assert_eq!(
+        token.rarity_score(TokenId::from(100), vec!["color"]),
+        U256::from(1_000_000 / 3)
+    );
+
+    token.burn_one(ali, ali, template_ids[0]);
+    assert_eq!(
+        token.rarity_score(TokenId::from(100), vec!["color"]),
+        U256::from(1_000_000 / 2)
+    );
+
+    token.set_meta_template(owner, 7, meta::black_dragon());
+    assert_eq!(
+        token.rarity_score(TokenId::from(100), vec!["color"]),
+        U256::from(1_000_000)
+    );
+    token.burn_one(ali, ali, template_ids[1]);
+    assert_eq!(
+        token.rarity_score(TokenId::from(100), vec!["color"]),
+        U256::from(1_000_000)
+    );
+}
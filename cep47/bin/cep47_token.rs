@@ -10,11 +10,13 @@ use casper_contract::{
     unwrap_or_revert::UnwrapOrRevert,
 };
 use casper_types::{
-    runtime_args, CLType, CLTyped, CLValue, ContractPackageHash, EntryPoint, EntryPointAccess,
-    EntryPointType, EntryPoints, Group, Key, Parameter, RuntimeArgs, URef, U256,
+    bytesrepr::{Bytes, ToBytes},
+    runtime_args, CLType, CLTyped, CLValue, ContractHash, ContractPackageHash, EntryPoint,
+    EntryPointAccess, EntryPointType, EntryPoints, Group, Key, Parameter, PublicKey, RuntimeArgs,
+    URef, U256,
 };
-use cep47::{Meta, TokenId, CEP47};
-use contract_utils::{ContractContext, OnChainContractStorage};
+use cep47::{Meta, MintReceipt, TokenId, TypedMeta, CEP47};
+use contract_utils::{AdminControl, ContractContext, OnChainContractStorage};
 
 #[derive(Default)]
 struct NFTToken(OnChainContractStorage);
@@ -26,9 +28,34 @@ impl ContractContext<OnChainContractStorage> for NFTToken {
 }
 
 impl CEP47<OnChainContractStorage> for NFTToken {}
+impl AdminControl<OnChainContractStorage> for NFTToken {}
 impl NFTToken {
-    fn constructor(&mut self, name: String, symbol: String, meta: Meta) {
-        CEP47::init(self, name, symbol, meta);
+    #[allow(clippy::too_many_arguments)]
+    fn constructor(
+        &mut self,
+        name: String,
+        symbol: String,
+        meta: Meta,
+        token_id_salt: Option<String>,
+        immutable_metadata: bool,
+        require_explicit_ids: bool,
+        creator: Option<Key>,
+        genesis_mints: Vec<(Key, Meta)>,
+    ) {
+        let caller = self.get_caller();
+        CEP47::init(
+            self,
+            name,
+            symbol,
+            meta,
+            token_id_salt,
+            immutable_metadata,
+            require_explicit_ids,
+            creator.unwrap_or(caller),
+            genesis_mints,
+        );
+        AdminControl::init(self);
+        self.add_admin_without_checked(caller);
     }
 }
 
@@ -37,7 +64,21 @@ fn constructor() {
     let name = runtime::get_named_arg::<String>("name");
     let symbol = runtime::get_named_arg::<String>("symbol");
     let meta = runtime::get_named_arg::<Meta>("meta");
-    NFTToken::default().constructor(name, symbol, meta);
+    let token_id_salt = runtime::get_named_arg::<Option<String>>("token_id_salt");
+    let immutable_metadata = runtime::get_named_arg::<bool>("immutable_metadata");
+    let require_explicit_ids = runtime::get_named_arg::<bool>("require_explicit_ids");
+    let creator = runtime::get_named_arg::<Option<Key>>("creator");
+    let genesis_mints = runtime::get_named_arg::<Vec<(Key, Meta)>>("genesis_mints");
+    NFTToken::default().constructor(
+        name,
+        symbol,
+        meta,
+        token_id_salt,
+        immutable_metadata,
+        require_explicit_ids,
+        creator,
+        genesis_mints,
+    );
 }
 
 #[no_mangle]
@@ -46,6 +87,18 @@ fn name() {
     runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
 }
 
+#[no_mangle]
+fn genesis_supply() {
+    let ret = NFTToken::default().genesis_supply();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn creator() {
+    let ret = NFTToken::default().creator();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
 #[no_mangle]
 fn symbol() {
     let ret = NFTToken::default().symbol();
@@ -58,12 +111,66 @@ fn meta() {
     runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
 }
 
+#[no_mangle]
+fn supports_interface() {
+    let interface_id = runtime::get_named_arg::<String>("interface_id");
+    let ret = NFTToken::default().supports_interface(interface_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn feature_flags() {
+    let ret = NFTToken::default().feature_flags();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn event_schema_version() {
+    let ret = NFTToken::default().event_schema_version();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
 #[no_mangle]
 fn total_supply() {
     let ret = NFTToken::default().total_supply();
     runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
 }
 
+#[no_mangle]
+fn total_supply_cap() {
+    let ret = NFTToken::default().total_supply_cap();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn set_total_supply_cap() {
+    let cap = runtime::get_named_arg::<Option<U256>>("cap");
+    let mut contract = NFTToken::default();
+    if contract.approve_admin_action("set_total_supply_cap", cap.to_bytes().unwrap_or_revert()) {
+        contract.set_total_supply_cap(cap).unwrap_or_revert();
+    }
+}
+
+#[no_mangle]
+fn min_supply() {
+    let ret = NFTToken::default().min_supply();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn set_min_supply() {
+    let min_supply = runtime::get_named_arg::<Option<U256>>("min_supply");
+    let mut contract = NFTToken::default();
+    contract.assert_caller_is_admin();
+    contract.set_min_supply(min_supply);
+}
+
+#[no_mangle]
+fn remaining_supply() {
+    let ret = NFTToken::default().remaining_supply();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
 #[no_mangle]
 fn balance_of() {
     let owner = runtime::get_named_arg::<Key>("owner");
@@ -71,6 +178,67 @@ fn balance_of() {
     runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
 }
 
+#[no_mangle]
+fn minted_count_of() {
+    let account = runtime::get_named_arg::<Key>("account");
+    let ret = NFTToken::default().minted_count_of(account);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn all_tokens() {
+    let start = runtime::get_named_arg::<U256>("start");
+    let limit = runtime::get_named_arg::<u32>("limit");
+    let ret = NFTToken::default().all_tokens(start, limit);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn tokens_minted_between() {
+    let from = runtime::get_named_arg::<u64>("from");
+    let to = runtime::get_named_arg::<u64>("to");
+    let start = runtime::get_named_arg::<U256>("start");
+    let limit = runtime::get_named_arg::<u32>("limit");
+    let ret = NFTToken::default().tokens_minted_between(from, to, start, limit);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn burned_tokens() {
+    let start = runtime::get_named_arg::<U256>("start");
+    let limit = runtime::get_named_arg::<u32>("limit");
+    let ret = NFTToken::default().burned_tokens(start, limit);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn tokens_with_attribute() {
+    let key = runtime::get_named_arg::<String>("key");
+    let value = runtime::get_named_arg::<String>("value");
+    let start = runtime::get_named_arg::<U256>("start");
+    let limit = runtime::get_named_arg::<u32>("limit");
+    let ret = NFTToken::default().tokens_with_attribute(key, value, start, limit);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn holds_exactly() {
+    let owner = runtime::get_named_arg::<Key>("owner");
+    let token_ids = runtime::get_named_arg::<Vec<TokenId>>("token_ids");
+    let ret = NFTToken::default().holds_exactly(owner, token_ids);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn owner_has_attribute() {
+    let owner = runtime::get_named_arg::<Key>("owner");
+    let key = runtime::get_named_arg::<String>("key");
+    let value = runtime::get_named_arg::<String>("value");
+    let cap = runtime::get_named_arg::<u32>("cap");
+    let ret = NFTToken::default().owner_has_attribute(owner, key, value, cap);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
 #[no_mangle]
 fn get_token_by_index() {
     let owner = runtime::get_named_arg::<Key>("owner");
@@ -79,6 +247,72 @@ fn get_token_by_index() {
     runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
 }
 
+#[no_mangle]
+fn lock_approval() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let until = runtime::get_named_arg::<u64>("until");
+    NFTToken::default()
+        .lock_approval(token_id, until)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn approval_locked_until() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = NFTToken::default().approval_locked_until(token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn my_approvals() {
+    let cap = runtime::get_named_arg::<u32>("cap");
+    let ret = NFTToken::default().my_approvals(cap);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn holdings_diff() {
+    let a = runtime::get_named_arg::<Key>("a");
+    let b = runtime::get_named_arg::<Key>("b");
+    let cap = runtime::get_named_arg::<u32>("cap");
+    let ret = NFTToken::default().holdings_diff(a, b, cap);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn holder_count() {
+    let ret = NFTToken::default().holder_count();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn aggregate_balance() {
+    let owners = runtime::get_named_arg::<Vec<Key>>("owners");
+    let ret = NFTToken::default()
+        .aggregate_balance(owners)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn ownership_histogram() {
+    let buckets = runtime::get_named_arg::<Vec<U256>>("buckets");
+    let start = runtime::get_named_arg::<u64>("start");
+    let limit = runtime::get_named_arg::<u32>("limit");
+    let ret = NFTToken::default()
+        .ownership_histogram(buckets, start, limit)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn owner_token_meta_at() {
+    let owner = runtime::get_named_arg::<Key>("owner");
+    let index = runtime::get_named_arg::<U256>("index");
+    let ret = NFTToken::default().owner_token_meta_at(owner, index);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
 #[no_mangle]
 fn owner_of() {
     let token_id = runtime::get_named_arg::<TokenId>("token_id");
@@ -94,285 +328,2678 @@ fn token_meta() {
 }
 
 #[no_mangle]
-fn update_token_meta() {
+fn token_meta_resolved() {
     let token_id = runtime::get_named_arg::<TokenId>("token_id");
-    let token_meta = runtime::get_named_arg::<Meta>("token_meta");
-    NFTToken::default()
-        .set_token_meta(token_id, token_meta)
-        .unwrap_or_revert();
+    let ret = NFTToken::default().token_meta_resolved(token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
 }
 
 #[no_mangle]
-fn mint() {
-    let recipient = runtime::get_named_arg::<Key>("recipient");
-    let token_ids = runtime::get_named_arg::<Vec<TokenId>>("token_ids");
-    let token_metas = runtime::get_named_arg::<Vec<Meta>>("token_metas");
-    NFTToken::default()
-        .mint(recipient, token_ids, token_metas)
-        .unwrap_or_revert();
+fn default_meta() {
+    let ret = NFTToken::default().default_meta();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
 }
 
 #[no_mangle]
-fn mint_copies() {
-    let recipient = runtime::get_named_arg::<Key>("recipient");
-    let token_ids = runtime::get_named_arg::<Vec<U256>>("token_ids");
-    let token_meta = runtime::get_named_arg::<Meta>("token_meta");
-    let count = runtime::get_named_arg::<u32>("count");
-    NFTToken::default()
-        .mint_copies(recipient, token_ids, token_meta, count)
-        .unwrap_or_revert();
+fn set_default_meta() {
+    let meta = runtime::get_named_arg::<Meta>("meta");
+    let mut contract = NFTToken::default();
+    contract.assert_caller_is_admin();
+    contract.set_default_meta(meta).unwrap_or_revert();
 }
 
 #[no_mangle]
-fn burn() {
+fn reset_owner_index() {
     let owner = runtime::get_named_arg::<Key>("owner");
+    let end = runtime::get_named_arg::<U256>("end");
+    let mut contract = NFTToken::default();
+    contract.assert_caller_is_admin();
+    let ret = contract.reset_owner_index(owner, end).unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn rebuild_indices() {
     let token_ids = runtime::get_named_arg::<Vec<TokenId>>("token_ids");
-    NFTToken::default()
-        .burn(owner, token_ids)
-        .unwrap_or_revert();
+    let mut contract = NFTToken::default();
+    contract.assert_caller_is_admin();
+    contract.rebuild_indices(token_ids).unwrap_or_revert();
 }
 
 #[no_mangle]
-fn transfer() {
-    let recipient = runtime::get_named_arg::<Key>("recipient");
+fn events_enabled() {
+    let ret = NFTToken::default().events_enabled();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn set_events_enabled() {
+    let enabled = runtime::get_named_arg::<bool>("enabled");
+    let mut contract = NFTToken::default();
+    contract.assert_caller_is_admin();
+    contract.set_events_enabled(enabled);
+}
+
+#[no_mangle]
+fn recipient_policy() {
+    let ret = NFTToken::default().recipient_policy();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn set_recipient_policy() {
+    let policy = runtime::get_named_arg::<u8>("policy");
+    let mut contract = NFTToken::default();
+    contract.assert_caller_is_admin();
+    contract.set_recipient_policy(policy).unwrap_or_revert();
+}
+
+#[no_mangle]
+fn immutable_metadata() {
+    let ret = NFTToken::default().immutable_metadata();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn require_explicit_ids() {
+    let ret = NFTToken::default().require_explicit_ids();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn metadata_hash() {
     let token_ids = runtime::get_named_arg::<Vec<TokenId>>("token_ids");
-    NFTToken::default()
-        .transfer(recipient, token_ids)
+    let ret = NFTToken::default()
+        .metadata_hash(token_ids)
         .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
 }
 
 #[no_mangle]
-fn transfer_from() {
-    let sender = runtime::get_named_arg::<Key>("sender");
-    let recipient = runtime::get_named_arg::<Key>("recipient");
+fn is_burned() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = NFTToken::default().is_burned(token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn exist_many() {
     let token_ids = runtime::get_named_arg::<Vec<TokenId>>("token_ids");
-    NFTToken::default()
-        .transfer_from(sender, recipient, token_ids)
-        .unwrap_or_revert();
+    let ret = NFTToken::default().exist_many(token_ids).unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
 }
 
 #[no_mangle]
-fn approve() {
-    let spender = runtime::get_named_arg::<Key>("spender");
+fn meta_versions() {
     let token_ids = runtime::get_named_arg::<Vec<TokenId>>("token_ids");
-    NFTToken::default()
-        .approve(spender, token_ids)
+    let ret = NFTToken::default()
+        .meta_versions(token_ids)
         .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
 }
 
 #[no_mangle]
-fn get_approved() {
-    let owner = runtime::get_named_arg::<Key>("owner");
-    let token_id = runtime::get_named_arg::<TokenId>("token_id");
-    let ret = NFTToken::default().get_approved(owner, token_id);
+fn owners_of() {
+    let token_ids = runtime::get_named_arg::<Vec<TokenId>>("token_ids");
+    let ret = NFTToken::default().owners_of(token_ids).unwrap_or_revert();
     runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
 }
 
 #[no_mangle]
-fn call() {
-    // Read arguments for the constructor call.
-    let name: String = runtime::get_named_arg("name");
-    let symbol: String = runtime::get_named_arg("symbol");
-    let meta: Meta = runtime::get_named_arg("meta");
-    let contract_name: String = runtime::get_named_arg("contract_name");
+fn was_ever_minted() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = NFTToken::default().was_ever_minted(token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
 
-    // Prepare constructor args
-    let constructor_args = runtime_args! {
-        "name" => name,
-        "symbol" => symbol,
-        "meta" => meta
-    };
+#[no_mangle]
+fn token_detail() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = NFTToken::default().token_detail(token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
 
-    let (contract_hash, _) = storage::new_contract(
-        get_entry_points(),
-        None,
-        Some(String::from("contract_package_hash")),
-        None,
-    );
+#[no_mangle]
+fn dynamic_meta_contract() {
+    let ret = NFTToken::default().dynamic_meta_contract();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
 
-    let package_hash: ContractPackageHash = ContractPackageHash::new(
-        runtime::get_key("contract_package_hash")
-            .unwrap_or_revert()
-            .into_hash()
-            .unwrap_or_revert(),
-    );
+#[no_mangle]
+fn meta_resolver_version() {
+    let ret = NFTToken::default().meta_resolver_version();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
 
-    let constructor_access: URef =
-        storage::create_contract_user_group(package_hash, "constructor", 1, Default::default())
-            .unwrap_or_revert()
-            .pop()
-            .unwrap_or_revert();
+#[no_mangle]
+fn view_recorder() {
+    let ret = NFTToken::default().view_recorder();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
 
-    let _: () = runtime::call_contract(contract_hash, "constructor", constructor_args);
+#[no_mangle]
+fn set_view_recorder() {
+    let recorder = runtime::get_named_arg::<Option<Key>>("recorder");
+    let mut contract = NFTToken::default();
+    contract.assert_caller_is_admin();
+    contract.set_view_recorder(recorder);
+}
 
-    let mut urefs = BTreeSet::new();
-    urefs.insert(constructor_access);
-    storage::remove_contract_user_group_urefs(package_hash, "constructor", urefs)
+#[no_mangle]
+fn rarity_score() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let trait_keys = runtime::get_named_arg::<Vec<String>>("trait_keys");
+    let ret = NFTToken::default()
+        .rarity_score(token_id, trait_keys)
         .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
 
-    runtime::put_key(
-        &format!("{}_contract_hash", contract_name),
-        contract_hash.into(),
-    );
-    runtime::put_key(
-        &format!("{}_contract_hash_wrapped", contract_name),
-        storage::new_uref(contract_hash).into(),
-    );
+#[no_mangle]
+fn view_count() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = NFTToken::default().view_count(token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
 }
 
-fn get_entry_points() -> EntryPoints {
-    let mut entry_points = EntryPoints::new();
+#[no_mangle]
+fn record_view() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    NFTToken::default().record_view(token_id).unwrap_or_revert();
+}
+
+#[no_mangle]
+fn set_dynamic_meta_contract() {
+    let contract = runtime::get_named_arg::<Option<ContractHash>>("contract");
+    let mut contract_context = NFTToken::default();
+    contract_context.assert_caller_is_admin();
+    contract_context.set_dynamic_meta_contract(contract);
+}
+
+#[no_mangle]
+fn announce_supply() {
+    NFTToken::default().announce_supply();
+}
+
+#[no_mangle]
+fn paused() {
+    let ret = NFTToken::default().paused();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn set_paused() {
+    let paused = runtime::get_named_arg::<bool>("paused");
+    let mut contract = NFTToken::default();
+    if contract.approve_admin_action("set_paused", paused.to_bytes().unwrap_or_revert()) {
+        contract.set_paused(paused);
+    }
+}
+
+#[no_mangle]
+fn set_admins() {
+    let admins = runtime::get_named_arg::<Vec<Key>>("admins");
+    let threshold = runtime::get_named_arg::<u8>("threshold");
+    let mut contract = NFTToken::default();
+    let args = (admins.clone(), threshold).to_bytes().unwrap_or_revert();
+    if contract.approve_admin_action("set_admins", args) {
+        contract.set_admins(admins, threshold);
+    }
+}
+
+#[no_mangle]
+fn renounce_admin() {
+    let mut contract = NFTToken::default();
+    if contract.approve_admin_action("renounce_admin", Vec::new()) {
+        contract.renounce_admin();
+    }
+}
+
+#[no_mangle]
+fn admins_renounced() {
+    let ret = NFTToken::default().admins_renounced();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn is_admin() {
+    let account = runtime::get_named_arg::<Key>("account");
+    let ret = NFTToken::default().is_admin(account);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn am_i_admin() {
+    let ret = NFTToken::default().am_i_admin();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn admin_threshold() {
+    let ret = NFTToken::default().admin_threshold();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn transfers_paused() {
+    let ret = NFTToken::default().transfers_paused();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn set_transfers_paused() {
+    let paused = runtime::get_named_arg::<bool>("paused");
+    let mut contract = NFTToken::default();
+    contract.assert_caller_is_admin();
+    contract.set_transfers_paused(paused);
+}
+
+#[no_mangle]
+fn recent_events_capacity() {
+    let ret = NFTToken::default().recent_events_capacity();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn set_recent_events_capacity() {
+    let capacity = runtime::get_named_arg::<u32>("capacity");
+    let mut contract = NFTToken::default();
+    contract.assert_caller_is_admin();
+    contract
+        .set_recent_events_capacity(capacity)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn recent_events() {
+    let count = runtime::get_named_arg::<u32>("count");
+    let ret = NFTToken::default().recent_events(count);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn max_meta_bytes() {
+    let ret = NFTToken::default().max_meta_bytes();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn set_max_meta_bytes() {
+    let limit = runtime::get_named_arg::<u32>("limit");
+    let mut contract = NFTToken::default();
+    contract.assert_caller_is_admin();
+    contract.set_max_meta_bytes(limit);
+}
+
+#[no_mangle]
+fn metadata_delta_events() {
+    let ret = NFTToken::default().metadata_delta_events();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn set_metadata_delta_events() {
+    let enabled = runtime::get_named_arg::<bool>("enabled");
+    let mut contract = NFTToken::default();
+    contract.assert_caller_is_admin();
+    contract.set_metadata_delta_events(enabled);
+}
+
+#[no_mangle]
+fn stamp_on_transfer() {
+    let ret = NFTToken::default().stamp_on_transfer();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn set_stamp_on_transfer() {
+    let enabled = runtime::get_named_arg::<bool>("enabled");
+    let mut contract = NFTToken::default();
+    contract.assert_caller_is_admin();
+    contract.set_stamp_on_transfer(enabled).unwrap_or_revert();
+}
+
+#[no_mangle]
+fn transfer_restricted() {
+    let ret = NFTToken::default().transfer_restricted();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn set_transfer_restricted() {
+    let enabled = runtime::get_named_arg::<bool>("enabled");
+    let mut contract = NFTToken::default();
+    contract.assert_caller_is_admin();
+    contract.set_transfer_restricted(enabled);
+}
+
+#[no_mangle]
+fn notify_on_mint() {
+    let ret = NFTToken::default().notify_on_mint();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn set_notify_on_mint() {
+    let enabled = runtime::get_named_arg::<bool>("enabled");
+    let mut contract = NFTToken::default();
+    contract.assert_caller_is_admin();
+    contract.set_notify_on_mint(enabled);
+}
+
+#[no_mangle]
+fn is_transfer_allowlisted() {
+    let account = runtime::get_named_arg::<Key>("account");
+    let ret = NFTToken::default().is_transfer_allowlisted(account);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn set_transfer_allowlisted() {
+    let accounts = runtime::get_named_arg::<Vec<Key>>("accounts");
+    let allowed = runtime::get_named_arg::<bool>("allowed");
+    let mut contract = NFTToken::default();
+    contract.assert_caller_is_admin();
+    contract
+        .set_transfer_allowlisted(accounts, allowed)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn burn_meta_events() {
+    let ret = NFTToken::default().burn_meta_events();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn set_debug_errors() {
+    let enabled = runtime::get_named_arg::<bool>("enabled");
+    let mut contract = NFTToken::default();
+    contract.assert_caller_is_admin();
+    contract.set_debug_errors(enabled);
+}
+
+#[no_mangle]
+fn last_error() {
+    let ret = NFTToken::default().last_error();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn set_burn_meta_events() {
+    let enabled = runtime::get_named_arg::<bool>("enabled");
+    let mut contract = NFTToken::default();
+    contract.assert_caller_is_admin();
+    contract.set_burn_meta_events(enabled);
+}
+
+#[no_mangle]
+fn unique_meta_key() {
+    let ret = NFTToken::default().unique_meta_key();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn set_unique_meta_key() {
+    let key = runtime::get_named_arg::<Option<String>>("key");
+    let mut contract = NFTToken::default();
+    contract.assert_caller_is_admin();
+    contract.set_unique_meta_key(key).unwrap_or_revert();
+}
+
+#[no_mangle]
+fn meta_signer() {
+    let ret = NFTToken::default().meta_signer();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn set_meta_signer() {
+    let signer = runtime::get_named_arg::<Option<PublicKey>>("signer");
+    let mut contract = NFTToken::default();
+    contract.assert_caller_is_admin();
+    contract.set_meta_signer(signer);
+}
+
+#[no_mangle]
+fn update_token_meta_signed() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let token_meta = runtime::get_named_arg::<Meta>("token_meta");
+    let nonce = runtime::get_named_arg::<u64>("nonce");
+    let signature = runtime::get_named_arg::<Bytes>("signature");
+    let mut contract = NFTToken::default();
+    contract.assert_not_renounced();
+    contract
+        .set_token_meta_signed(token_id, token_meta, nonce, signature)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn token_typed_meta() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = NFTToken::default().token_typed_meta(token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn update_token_typed_meta() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let token_meta = runtime::get_named_arg::<TypedMeta>("token_meta");
+    let mut contract = NFTToken::default();
+    contract.assert_not_renounced();
+    contract
+        .set_token_typed_meta(token_id, token_meta)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn update_token_meta() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let token_meta = runtime::get_named_arg::<Meta>("token_meta");
+    let mut contract = NFTToken::default();
+    contract.assert_not_renounced();
+    contract
+        .set_token_meta(token_id, token_meta)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn mint() {
+    let recipient = runtime::get_named_arg::<Key>("recipient");
+    let token_ids = runtime::get_named_arg::<Option<Vec<TokenId>>>("token_ids");
+    let token_metas = runtime::get_named_arg::<Vec<Meta>>("token_metas");
+    let mut contract = NFTToken::default();
+    contract.assert_not_renounced();
+    contract
+        .mint(recipient, token_ids, token_metas)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn mint_idempotent() {
+    let recipient = runtime::get_named_arg::<Key>("recipient");
+    let token_ids = runtime::get_named_arg::<Option<Vec<TokenId>>>("token_ids");
+    let token_metas = runtime::get_named_arg::<Vec<Meta>>("token_metas");
+    let idempotency_key = runtime::get_named_arg::<String>("idempotency_key");
+    let mut contract = NFTToken::default();
+    contract.assert_not_renounced();
+    let ret = contract
+        .mint_idempotent(recipient, token_ids, token_metas, idempotency_key)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn mint_with_receipt() {
+    let recipient = runtime::get_named_arg::<Key>("recipient");
+    let token_ids = runtime::get_named_arg::<Option<Vec<TokenId>>>("token_ids");
+    let token_metas = runtime::get_named_arg::<Vec<Meta>>("token_metas");
+    let mut contract = NFTToken::default();
+    contract.assert_not_renounced();
+    let ret = contract
+        .mint_with_receipt(recipient, token_ids, token_metas)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn validate_mint() {
+    let recipient = runtime::get_named_arg::<Key>("recipient");
+    let token_ids = runtime::get_named_arg::<Option<Vec<TokenId>>>("token_ids");
+    let token_metas = runtime::get_named_arg::<Vec<Meta>>("token_metas");
+    NFTToken::default()
+        .recorded_validate_mint(recipient, token_ids, token_metas)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn mint_copies() {
+    let recipient = runtime::get_named_arg::<Key>("recipient");
+    let token_ids = runtime::get_named_arg::<Option<Vec<TokenId>>>("token_ids");
+    let token_meta = runtime::get_named_arg::<Meta>("token_meta");
+    let count = runtime::get_named_arg::<u32>("count");
+    let mut contract = NFTToken::default();
+    contract.assert_not_renounced();
+    contract
+        .mint_copies(recipient, token_ids, token_meta, count)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn meta_template() {
+    let template_id = runtime::get_named_arg::<u32>("template_id");
+    let ret = NFTToken::default().meta_template(template_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn is_frozen() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = NFTToken::default().is_frozen(token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn set_token_frozen() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let frozen = runtime::get_named_arg::<bool>("frozen");
+    let mut contract = NFTToken::default();
+    contract.assert_caller_is_admin();
+    contract
+        .set_token_frozen(token_id, frozen)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn is_mint_id_locked() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = NFTToken::default().is_mint_id_locked(token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn lock_mint_id() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let mut contract = NFTToken::default();
+    contract.assert_caller_is_admin();
+    contract.lock_mint_id(token_id);
+}
+
+#[no_mangle]
+fn unlock_mint_id() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let mut contract = NFTToken::default();
+    contract.assert_caller_is_admin();
+    contract.unlock_mint_id(token_id);
+}
+
+#[no_mangle]
+fn set_meta_template() {
+    let template_id = runtime::get_named_arg::<u32>("template_id");
+    let meta = runtime::get_named_arg::<Meta>("meta");
+    let mut contract = NFTToken::default();
+    contract.assert_caller_is_admin();
+    contract
+        .set_meta_template(template_id, meta)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn mint_from_template() {
+    let recipient = runtime::get_named_arg::<Key>("recipient");
+    let template_id = runtime::get_named_arg::<u32>("template_id");
+    let count = runtime::get_named_arg::<u32>("count");
+    let mut contract = NFTToken::default();
+    contract.assert_not_renounced();
+    contract
+        .mint_from_template(recipient, template_id, count)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn mint_for_self() {
+    let token_metas = runtime::get_named_arg::<Vec<Meta>>("token_metas");
+    let mut contract = NFTToken::default();
+    contract.assert_not_renounced();
+    contract.mint_for_self(token_metas).unwrap_or_revert();
+}
+
+#[no_mangle]
+fn mint_random() {
+    let recipient = runtime::get_named_arg::<Key>("recipient");
+    let templates = runtime::get_named_arg::<Vec<(u32, Meta)>>("templates");
+    let mut contract = NFTToken::default();
+    contract.assert_not_renounced();
+    let ret = contract
+        .mint_random(recipient, templates)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn forge() {
+    let burn_ids = runtime::get_named_arg::<Vec<TokenId>>("burn_ids");
+    let new_meta = runtime::get_named_arg::<Meta>("new_meta");
+    let mut contract = NFTToken::default();
+    contract.assert_not_renounced();
+    let ret = contract.forge(burn_ids, new_meta).unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn approve_for_all_many() {
+    let operators = runtime::get_named_arg::<Vec<Key>>("operators");
+    NFTToken::default()
+        .approve_for_all_many(operators)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn revoke_for_all_many() {
+    let operators = runtime::get_named_arg::<Vec<Key>>("operators");
+    NFTToken::default()
+        .revoke_for_all_many(operators)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn dictionary_names() {
+    let ret = NFTToken::default().dictionary_names();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn peek_token_ids() {
+    let n = runtime::get_named_arg::<u32>("n");
+    let ret = NFTToken::default().peek_token_ids(n).unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn mint_queue_len() {
+    let ret = NFTToken::default().mint_queue_len();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn enqueue_mint() {
+    let recipient = runtime::get_named_arg::<Key>("recipient");
+    let token_meta = runtime::get_named_arg::<Meta>("token_meta");
+    let mut contract = NFTToken::default();
+    contract.assert_not_renounced();
+    contract
+        .enqueue_mint(recipient, token_meta)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn process_mint_queue() {
+    let count = runtime::get_named_arg::<u32>("count");
+    let mut contract = NFTToken::default();
+    contract.assert_not_renounced();
+    let ret = contract.process_mint_queue(count).unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn mint_to_many() {
+    let recipients_and_metas = runtime::get_named_arg::<Vec<(Key, Meta)>>("recipients_and_metas");
+    let mut contract = NFTToken::default();
+    contract.assert_not_renounced();
+    let ret = contract
+        .mint_to_many(recipients_and_metas)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn mint_copies_multi() {
+    let recipient = runtime::get_named_arg::<Key>("recipient");
+    let metas_and_counts = runtime::get_named_arg::<Vec<(Meta, u32)>>("metas_and_counts");
+    let mut contract = NFTToken::default();
+    contract.assert_not_renounced();
+    contract
+        .mint_copies_multi(recipient, metas_and_counts)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn mint_with_expiry() {
+    let recipient = runtime::get_named_arg::<Key>("recipient");
+    let token_ids = runtime::get_named_arg::<Option<Vec<TokenId>>>("token_ids");
+    let token_metas = runtime::get_named_arg::<Vec<Meta>>("token_metas");
+    let expires_at = runtime::get_named_arg::<u64>("expires_at");
+    let mut contract = NFTToken::default();
+    contract.assert_not_renounced();
+    contract
+        .mint_with_expiry(recipient, token_ids, token_metas, expires_at)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn token_expiry() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = NFTToken::default().token_expiry(token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn is_expired() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = NFTToken::default().is_expired(token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn burn() {
+    let owner = runtime::get_named_arg::<Key>("owner");
+    let token_ids = runtime::get_named_arg::<Vec<TokenId>>("token_ids");
+    NFTToken::default()
+        .burn(owner, token_ids)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn grant_token_role() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let role = runtime::get_named_arg::<String>("role");
+    let account = runtime::get_named_arg::<Key>("account");
+    NFTToken::default()
+        .grant_token_role(token_id, role, account)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn revoke_token_role() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let role = runtime::get_named_arg::<String>("role");
+    let account = runtime::get_named_arg::<Key>("account");
+    NFTToken::default()
+        .revoke_token_role(token_id, role, account)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn has_token_role() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let role = runtime::get_named_arg::<String>("role");
+    let account = runtime::get_named_arg::<Key>("account");
+    let ret = NFTToken::default().has_token_role(token_id, role, account);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn simulate_transfer() {
+    let owner = runtime::get_named_arg::<Key>("owner");
+    let recipient = runtime::get_named_arg::<Key>("recipient");
+    let token_ids = runtime::get_named_arg::<Vec<TokenId>>("token_ids");
+    NFTToken::default()
+        .recorded_simulate_transfer(owner, recipient, token_ids)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn burn_if_meta() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let key = runtime::get_named_arg::<String>("key");
+    let expected = runtime::get_named_arg::<String>("expected");
+    NFTToken::default()
+        .burn_if_meta(token_id, key, expected)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn redeem() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let shipping_ref = runtime::get_named_arg::<String>("shipping_ref");
+    NFTToken::default()
+        .redeem(token_id, shipping_ref)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn total_approvals() {
+    let ret = NFTToken::default().total_approvals();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn token_royalty() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = NFTToken::default().token_royalty(token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn set_token_royalties() {
+    let entries = runtime::get_named_arg::<Vec<(TokenId, Key, u16)>>("entries");
+    let mut contract = NFTToken::default();
+    contract.assert_caller_is_admin();
+    contract.set_token_royalties(entries).unwrap_or_revert();
+}
+
+#[no_mangle]
+fn transfer_fee_bps() {
+    let ret = NFTToken::default().transfer_fee_bps();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn fee_treasury() {
+    let ret = NFTToken::default().fee_treasury();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn set_transfer_fee() {
+    let bps = runtime::get_named_arg::<u16>("bps");
+    let treasury = runtime::get_named_arg::<Option<Key>>("treasury");
+    let mut contract = NFTToken::default();
+    contract.assert_caller_is_admin();
+    contract.set_transfer_fee(bps, treasury).unwrap_or_revert();
+}
+
+#[no_mangle]
+fn token_fee_bps() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = NFTToken::default().token_fee_bps(token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn set_token_fee() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let bps = runtime::get_named_arg::<Option<u16>>("bps");
+    let mut contract = NFTToken::default();
+    contract.assert_caller_is_admin();
+    contract.set_token_fee(token_id, bps).unwrap_or_revert();
+}
+
+#[no_mangle]
+fn transfer_with_fee() {
+    let recipient = runtime::get_named_arg::<Key>("recipient");
+    let token_ids = runtime::get_named_arg::<Vec<TokenId>>("token_ids");
+    let sale_price = runtime::get_named_arg::<U256>("sale_price");
+    NFTToken::default()
+        .transfer_with_fee(recipient, token_ids, sale_price)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn can_transfer_all() {
+    let spender = runtime::get_named_arg::<Key>("spender");
+    let owner = runtime::get_named_arg::<Key>("owner");
+    let token_ids = runtime::get_named_arg::<Vec<TokenId>>("token_ids");
+    let ret = NFTToken::default().can_transfer_all(spender, owner, token_ids);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn transfer() {
+    let recipient = runtime::get_named_arg::<Key>("recipient");
+    let token_ids = runtime::get_named_arg::<Vec<TokenId>>("token_ids");
+    NFTToken::default()
+        .transfer(recipient, token_ids)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn transfer_from() {
+    let sender = runtime::get_named_arg::<Key>("sender");
+    let recipient = runtime::get_named_arg::<Key>("recipient");
+    let token_ids = runtime::get_named_arg::<Vec<TokenId>>("token_ids");
+    NFTToken::default()
+        .transfer_from(sender, recipient, token_ids)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn transfer_and_approve() {
+    let recipient = runtime::get_named_arg::<Key>("recipient");
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let operator = runtime::get_named_arg::<Key>("operator");
+    NFTToken::default()
+        .transfer_and_approve(recipient, token_id, operator)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn transfer_keep_operator() {
+    let recipient = runtime::get_named_arg::<Key>("recipient");
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let operator = runtime::get_named_arg::<Key>("operator");
+    NFTToken::default()
+        .transfer_keep_operator(recipient, token_id, operator)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn safe_transfer_from() {
+    let sender = runtime::get_named_arg::<Key>("sender");
+    let recipient = runtime::get_named_arg::<Key>("recipient");
+    let token_ids = runtime::get_named_arg::<Vec<TokenId>>("token_ids");
+    NFTToken::default()
+        .safe_transfer_from(sender, recipient, token_ids)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn has_approval() {
+    let owner = runtime::get_named_arg::<Key>("owner");
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = NFTToken::default().has_approval(owner, token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn revoke() {
+    let token_ids = runtime::get_named_arg::<Vec<TokenId>>("token_ids");
+    NFTToken::default().revoke(token_ids).unwrap_or_revert();
+}
+
+#[no_mangle]
+fn approve() {
+    let spender = runtime::get_named_arg::<Key>("spender");
+    let token_ids = runtime::get_named_arg::<Vec<TokenId>>("token_ids");
+    NFTToken::default()
+        .approve(spender, token_ids)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn get_approved() {
+    let owner = runtime::get_named_arg::<Key>("owner");
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = NFTToken::default().get_approved(owner, token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn delegate_approval() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let new_spender = runtime::get_named_arg::<Key>("new_spender");
+    NFTToken::default()
+        .delegate_approval(token_id, new_spender)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn transfer_cooldown() {
+    let ret = NFTToken::default().transfer_cooldown();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn set_transfer_cooldown() {
+    let cooldown = runtime::get_named_arg::<u64>("cooldown");
+    let mut contract = NFTToken::default();
+    contract.assert_caller_is_admin();
+    contract.set_transfer_cooldown(cooldown);
+}
+
+#[no_mangle]
+fn mint_lock_period() {
+    let ret = NFTToken::default().mint_lock_period();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn set_mint_lock_period() {
+    let period = runtime::get_named_arg::<u64>("period");
+    let mut contract = NFTToken::default();
+    contract.assert_caller_is_admin();
+    contract.set_mint_lock_period(period);
+}
+
+#[no_mangle]
+fn last_transfer_time() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = NFTToken::default().last_transfer_time(token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn max_mints_per_block() {
+    let ret = NFTToken::default().max_mints_per_block();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn max_approvals_per_owner() {
+    let ret = NFTToken::default().max_approvals_per_owner();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn set_max_approvals_per_owner() {
+    let limit = runtime::get_named_arg::<Option<u32>>("limit");
+    let mut contract = NFTToken::default();
+    contract.assert_caller_is_admin();
+    contract.set_max_approvals_per_owner(limit);
+}
+
+#[no_mangle]
+fn approval_count() {
+    let owner = runtime::get_named_arg::<Key>("owner");
+    let ret = NFTToken::default().approval_count(owner);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn set_max_mints_per_block() {
+    let limit = runtime::get_named_arg::<Option<u32>>("limit");
+    let mut contract = NFTToken::default();
+    contract.assert_caller_is_admin();
+    contract.set_max_mints_per_block(limit);
+}
+
+#[no_mangle]
+fn mint_time() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = NFTToken::default().mint_time(token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn minter_of() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = NFTToken::default().minter_of(token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn recall_window() {
+    let ret = NFTToken::default().recall_window();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn set_recall_window() {
+    let window = runtime::get_named_arg::<u64>("window");
+    let mut contract = NFTToken::default();
+    contract.assert_caller_is_admin();
+    contract.set_recall_window(window);
+}
+
+#[no_mangle]
+fn admin_burn() {
+    let token_ids = runtime::get_named_arg::<Vec<TokenId>>("token_ids");
+    let mut contract = NFTToken::default();
+    contract.assert_caller_is_admin();
+    contract.admin_burn(token_ids).unwrap_or_revert();
+}
+
+#[no_mangle]
+fn recall() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let mut contract = NFTToken::default();
+    contract.assert_caller_is_admin();
+    contract.recall(token_id).unwrap_or_revert();
+}
+
+#[no_mangle]
+fn set_approval_for_all() {
+    let operator = runtime::get_named_arg::<Key>("operator");
+    let approved = runtime::get_named_arg::<bool>("approved");
+    NFTToken::default()
+        .set_approval_for_all(operator, approved)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+fn is_approved_for_all() {
+    let owner = runtime::get_named_arg::<Key>("owner");
+    let operator = runtime::get_named_arg::<Key>("operator");
+    let ret = NFTToken::default().is_approved_for_all(owner, operator);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn are_approved_for_all() {
+    let pairs = runtime::get_named_arg::<Vec<(Key, Key)>>("pairs");
+    let ret = NFTToken::default()
+        .are_approved_for_all(pairs)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn set_nonce() {
+    let value = runtime::get_named_arg::<u32>("value");
+    let mut contract = NFTToken::default();
+    contract.assert_caller_is_admin();
+    contract.set_nonce(value).unwrap_or_revert();
+}
+
+#[no_mangle]
+fn last_mint_time() {
+    let ret = NFTToken::default().last_mint_time();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn nonce() {
+    let ret = NFTToken::default().nonce();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn token_id_salt() {
+    let ret = NFTToken::default().token_id_salt();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn call() {
+    // Read arguments for the constructor call.
+    let name: String = runtime::get_named_arg("name");
+    let symbol: String = runtime::get_named_arg("symbol");
+    let meta: Meta = runtime::get_named_arg("meta");
+    let token_id_salt: Option<String> = runtime::get_named_arg("token_id_salt");
+    let immutable_metadata: bool = runtime::get_named_arg("immutable_metadata");
+    let require_explicit_ids: bool = runtime::get_named_arg("require_explicit_ids");
+    let creator: Option<Key> = runtime::get_named_arg("creator");
+    let genesis_mints: Vec<(Key, Meta)> = runtime::get_named_arg("genesis_mints");
+    let contract_name: String = runtime::get_named_arg("contract_name");
+
+    // Prepare constructor args
+    let constructor_args = runtime_args! {
+        "name" => name,
+        "symbol" => symbol,
+        "meta" => meta,
+        "token_id_salt" => token_id_salt,
+        "immutable_metadata" => immutable_metadata,
+        "require_explicit_ids" => require_explicit_ids,
+        "creator" => creator,
+        "genesis_mints" => genesis_mints
+    };
+
+    let (contract_hash, _) = storage::new_contract(
+        get_entry_points(),
+        None,
+        Some(String::from("contract_package_hash")),
+        None,
+    );
+
+    let package_hash: ContractPackageHash = ContractPackageHash::new(
+        runtime::get_key("contract_package_hash")
+            .unwrap_or_revert()
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+
+    let constructor_access: URef =
+        storage::create_contract_user_group(package_hash, "constructor", 1, Default::default())
+            .unwrap_or_revert()
+            .pop()
+            .unwrap_or_revert();
+
+    let _: () = runtime::call_contract(contract_hash, "constructor", constructor_args);
+
+    let mut urefs = BTreeSet::new();
+    urefs.insert(constructor_access);
+    storage::remove_contract_user_group_urefs(package_hash, "constructor", urefs)
+        .unwrap_or_revert();
+
+    runtime::put_key(
+        &format!("{}_contract_hash", contract_name),
+        contract_hash.into(),
+    );
+    runtime::put_key(
+        &format!("{}_contract_hash_wrapped", contract_name),
+        storage::new_uref(contract_hash).into(),
+    );
+}
+
+fn get_entry_points() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(EntryPoint::new(
+        "constructor",
+        vec![
+            Parameter::new("name", String::cl_type()),
+            Parameter::new("symbol", String::cl_type()),
+            Parameter::new("meta", Meta::cl_type()),
+            Parameter::new("token_id_salt", Option::<String>::cl_type()),
+            Parameter::new("immutable_metadata", CLType::Bool),
+            Parameter::new("require_explicit_ids", CLType::Bool),
+            Parameter::new("creator", Option::<Key>::cl_type()),
+            Parameter::new("genesis_mints", Vec::<(Key, Meta)>::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Groups(vec![Group::new("constructor")]),
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "genesis_supply",
+        vec![],
+        CLType::U256,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "name",
+        vec![],
+        String::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "creator",
+        vec![],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "symbol",
+        vec![],
+        String::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "meta",
+        vec![],
+        Meta::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "supports_interface",
+        vec![Parameter::new("interface_id", String::cl_type())],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "feature_flags",
+        vec![],
+        CLType::U32,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "event_schema_version",
+        vec![],
+        CLType::U32,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "total_supply",
+        vec![],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "total_supply_cap",
+        vec![],
+        Option::<U256>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_total_supply_cap",
+        vec![Parameter::new("cap", Option::<U256>::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "min_supply",
+        vec![],
+        Option::<U256>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_min_supply",
+        vec![Parameter::new("min_supply", Option::<U256>::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "remaining_supply",
+        vec![],
+        Option::<U256>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "balance_of",
+        vec![Parameter::new("owner", Key::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "minted_count_of",
+        vec![Parameter::new("account", Key::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "owner_of",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        CLType::Option(Box::new(CLType::Key)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "token_meta",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        Meta::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "token_meta_resolved",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        Option::<Meta>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "default_meta",
+        vec![],
+        Meta::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_default_meta",
+        vec![Parameter::new("meta", Meta::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "reset_owner_index",
+        vec![
+            Parameter::new("owner", Key::cl_type()),
+            Parameter::new("end", U256::cl_type()),
+        ],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "rebuild_indices",
+        vec![Parameter::new(
+            "token_ids",
+            CLType::List(Box::new(TokenId::cl_type())),
+        )],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "events_enabled",
+        vec![],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_events_enabled",
+        vec![Parameter::new("enabled", CLType::Bool)],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "recipient_policy",
+        vec![],
+        CLType::U8,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_recipient_policy",
+        vec![Parameter::new("policy", CLType::U8)],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "immutable_metadata",
+        vec![],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "require_explicit_ids",
+        vec![],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "exist_many",
+        vec![Parameter::new(
+            "token_ids",
+            CLType::List(Box::new(TokenId::cl_type())),
+        )],
+        CLType::List(Box::new(CLType::Bool)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "meta_versions",
+        vec![Parameter::new(
+            "token_ids",
+            CLType::List(Box::new(TokenId::cl_type())),
+        )],
+        CLType::List(Box::new(Option::<u64>::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "owners_of",
+        vec![Parameter::new(
+            "token_ids",
+            CLType::List(Box::new(TokenId::cl_type())),
+        )],
+        Vec::<(TokenId, Option<Key>)>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "metadata_hash",
+        vec![Parameter::new(
+            "token_ids",
+            CLType::List(Box::new(TokenId::cl_type())),
+        )],
+        <[u8; 32]>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "is_burned",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "was_ever_minted",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "token_detail",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        Option::<(Key, Meta, Meta)>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "dynamic_meta_contract",
+        vec![],
+        Option::<ContractHash>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "meta_resolver_version",
+        vec![],
+        CLType::U32,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "view_recorder",
+        vec![],
+        Option::<Key>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_view_recorder",
+        vec![Parameter::new("recorder", Option::<Key>::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "rarity_score",
+        vec![
+            Parameter::new("token_id", TokenId::cl_type()),
+            Parameter::new("trait_keys", CLType::List(Box::new(String::cl_type()))),
+        ],
+        CLType::U256,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "view_count",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "record_view",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_dynamic_meta_contract",
+        vec![Parameter::new(
+            "contract",
+            Option::<ContractHash>::cl_type(),
+        )],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "announce_supply",
+        vec![],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "paused",
+        vec![],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_paused",
+        vec![Parameter::new("paused", CLType::Bool)],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_admins",
+        vec![
+            Parameter::new("admins", CLType::List(Box::new(CLType::Key))),
+            Parameter::new("threshold", CLType::U8),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "renounce_admin",
+        vec![],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "admins_renounced",
+        vec![],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "admin_threshold",
+        vec![],
+        CLType::U8,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "is_admin",
+        vec![Parameter::new("account", Key::cl_type())],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "am_i_admin",
+        vec![],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "transfers_paused",
+        vec![],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_transfers_paused",
+        vec![Parameter::new("paused", CLType::Bool)],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "recent_events_capacity",
+        vec![],
+        CLType::U32,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_recent_events_capacity",
+        vec![Parameter::new("capacity", CLType::U32)],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "recent_events",
+        vec![Parameter::new("count", CLType::U32)],
+        CLType::List(Box::new(Bytes::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "max_meta_bytes",
+        vec![],
+        CLType::U32,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_max_meta_bytes",
+        vec![Parameter::new("limit", CLType::U32)],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "metadata_delta_events",
+        vec![],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_metadata_delta_events",
+        vec![Parameter::new("enabled", CLType::Bool)],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "stamp_on_transfer",
+        vec![],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_stamp_on_transfer",
+        vec![Parameter::new("enabled", CLType::Bool)],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "burn_meta_events",
+        vec![],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_debug_errors",
+        vec![Parameter::new("enabled", CLType::Bool)],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "last_error",
+        vec![],
+        Option::<String>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_burn_meta_events",
+        vec![Parameter::new("enabled", CLType::Bool)],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "unique_meta_key",
+        vec![],
+        Option::<String>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_unique_meta_key",
+        vec![Parameter::new("key", Option::<String>::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "meta_signer",
+        vec![],
+        Option::<PublicKey>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_meta_signer",
+        vec![Parameter::new("signer", Option::<PublicKey>::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "update_token_meta_signed",
+        vec![
+            Parameter::new("token_id", TokenId::cl_type()),
+            Parameter::new("token_meta", Meta::cl_type()),
+            Parameter::new("nonce", CLType::U64),
+            Parameter::new("signature", Bytes::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "token_typed_meta",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        Option::<TypedMeta>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "update_token_typed_meta",
+        vec![
+            Parameter::new("token_id", TokenId::cl_type()),
+            Parameter::new("token_meta", TypedMeta::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "update_token_meta",
+        vec![
+            Parameter::new("token_id", TokenId::cl_type()),
+            Parameter::new("token_meta", Meta::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "mint",
+        vec![
+            Parameter::new("recipient", Key::cl_type()),
+            Parameter::new("token_ids", Option::<Vec<TokenId>>::cl_type()),
+            Parameter::new("token_metas", CLType::List(Box::new(Meta::cl_type()))),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "mint_idempotent",
+        vec![
+            Parameter::new("recipient", Key::cl_type()),
+            Parameter::new("token_ids", Option::<Vec<TokenId>>::cl_type()),
+            Parameter::new("token_metas", CLType::List(Box::new(Meta::cl_type()))),
+            Parameter::new("idempotency_key", String::cl_type()),
+        ],
+        CLType::List(Box::new(TokenId::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "mint_with_receipt",
+        vec![
+            Parameter::new("recipient", Key::cl_type()),
+            Parameter::new("token_ids", Option::<Vec<TokenId>>::cl_type()),
+            Parameter::new("token_metas", CLType::List(Box::new(Meta::cl_type()))),
+        ],
+        MintReceipt::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "validate_mint",
+        vec![
+            Parameter::new("recipient", Key::cl_type()),
+            Parameter::new("token_ids", Option::<Vec<TokenId>>::cl_type()),
+            Parameter::new("token_metas", CLType::List(Box::new(Meta::cl_type()))),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "mint_copies",
+        vec![
+            Parameter::new("recipient", Key::cl_type()),
+            Parameter::new("token_ids", Option::<Vec<TokenId>>::cl_type()),
+            Parameter::new("token_meta", Meta::cl_type()),
+            Parameter::new("count", CLType::U32),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "meta_template",
+        vec![Parameter::new("template_id", CLType::U32)],
+        Option::<Meta>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "is_frozen",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "transfer_restricted",
+        vec![],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_transfer_restricted",
+        vec![Parameter::new("enabled", CLType::Bool)],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "notify_on_mint",
+        vec![],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_notify_on_mint",
+        vec![Parameter::new("enabled", CLType::Bool)],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "is_transfer_allowlisted",
+        vec![Parameter::new("account", Key::cl_type())],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_transfer_allowlisted",
+        vec![
+            Parameter::new("accounts", Vec::<Key>::cl_type()),
+            Parameter::new("allowed", CLType::Bool),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_token_frozen",
+        vec![
+            Parameter::new("token_id", TokenId::cl_type()),
+            Parameter::new("frozen", CLType::Bool),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "is_mint_id_locked",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "lock_mint_id",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "unlock_mint_id",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_meta_template",
+        vec![
+            Parameter::new("template_id", CLType::U32),
+            Parameter::new("meta", Meta::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "mint_from_template",
+        vec![
+            Parameter::new("recipient", Key::cl_type()),
+            Parameter::new("template_id", CLType::U32),
+            Parameter::new("count", CLType::U32),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "mint_for_self",
+        vec![Parameter::new(
+            "token_metas",
+            CLType::List(Box::new(Meta::cl_type())),
+        )],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "mint_random",
+        vec![
+            Parameter::new("recipient", Key::cl_type()),
+            Parameter::new("templates", Vec::<(u32, Meta)>::cl_type()),
+        ],
+        TokenId::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "forge",
+        vec![
+            Parameter::new("burn_ids", Vec::<TokenId>::cl_type()),
+            Parameter::new("new_meta", Meta::cl_type()),
+        ],
+        TokenId::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "approve_for_all_many",
+        vec![Parameter::new("operators", Vec::<Key>::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "revoke_for_all_many",
+        vec![Parameter::new("operators", Vec::<Key>::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "dictionary_names",
+        vec![],
+        CLType::List(Box::new(String::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "peek_token_ids",
+        vec![Parameter::new("n", CLType::U32)],
+        CLType::List(Box::new(TokenId::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "mint_queue_len",
+        vec![],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "enqueue_mint",
+        vec![
+            Parameter::new("recipient", Key::cl_type()),
+            Parameter::new("token_meta", Meta::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "process_mint_queue",
+        vec![Parameter::new("count", CLType::U32)],
+        CLType::List(Box::new(TokenId::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "mint_to_many",
+        vec![Parameter::new(
+            "recipients_and_metas",
+            Vec::<(Key, Meta)>::cl_type(),
+        )],
+        Vec::<(Key, TokenId)>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "mint_copies_multi",
+        vec![
+            Parameter::new("recipient", Key::cl_type()),
+            Parameter::new("metas_and_counts", Vec::<(Meta, u32)>::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "mint_with_expiry",
+        vec![
+            Parameter::new("recipient", Key::cl_type()),
+            Parameter::new("token_ids", Option::<Vec<TokenId>>::cl_type()),
+            Parameter::new("token_metas", CLType::List(Box::new(Meta::cl_type()))),
+            Parameter::new("expires_at", CLType::U64),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "token_expiry",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        CLType::Option(Box::new(CLType::U64)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "is_expired",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "burn",
+        vec![
+            Parameter::new("owner", Key::cl_type()),
+            Parameter::new("token_ids", CLType::List(Box::new(TokenId::cl_type()))),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "grant_token_role",
+        vec![
+            Parameter::new("token_id", TokenId::cl_type()),
+            Parameter::new("role", String::cl_type()),
+            Parameter::new("account", Key::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "revoke_token_role",
+        vec![
+            Parameter::new("token_id", TokenId::cl_type()),
+            Parameter::new("role", String::cl_type()),
+            Parameter::new("account", Key::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "has_token_role",
+        vec![
+            Parameter::new("token_id", TokenId::cl_type()),
+            Parameter::new("role", String::cl_type()),
+            Parameter::new("account", Key::cl_type()),
+        ],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "simulate_transfer",
+        vec![
+            Parameter::new("owner", Key::cl_type()),
+            Parameter::new("recipient", Key::cl_type()),
+            Parameter::new("token_ids", CLType::List(Box::new(TokenId::cl_type()))),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "burn_if_meta",
+        vec![
+            Parameter::new("token_id", TokenId::cl_type()),
+            Parameter::new("key", String::cl_type()),
+            Parameter::new("expected", String::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "redeem",
+        vec![
+            Parameter::new("token_id", TokenId::cl_type()),
+            Parameter::new("shipping_ref", String::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "total_approvals",
+        vec![],
+        CLType::U256,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "token_royalty",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        Option::<(Key, u16)>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_token_royalties",
+        vec![Parameter::new(
+            "entries",
+            Vec::<(TokenId, Key, u16)>::cl_type(),
+        )],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "transfer_fee_bps",
+        vec![],
+        CLType::U16,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "fee_treasury",
+        vec![],
+        Option::<Key>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_transfer_fee",
+        vec![
+            Parameter::new("bps", CLType::U16),
+            Parameter::new("treasury", Option::<Key>::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "token_fee_bps",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        CLType::U16,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_token_fee",
+        vec![
+            Parameter::new("token_id", TokenId::cl_type()),
+            Parameter::new("bps", Option::<u16>::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "transfer_with_fee",
+        vec![
+            Parameter::new("recipient", Key::cl_type()),
+            Parameter::new("token_ids", CLType::List(Box::new(TokenId::cl_type()))),
+            Parameter::new("sale_price", U256::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "can_transfer_all",
+        vec![
+            Parameter::new("spender", Key::cl_type()),
+            Parameter::new("owner", Key::cl_type()),
+            Parameter::new("token_ids", CLType::List(Box::new(TokenId::cl_type()))),
+        ],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "transfer",
+        vec![
+            Parameter::new("recipient", Key::cl_type()),
+            Parameter::new("token_ids", CLType::List(Box::new(TokenId::cl_type()))),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "transfer_from",
+        vec![
+            Parameter::new("sender", Key::cl_type()),
+            Parameter::new("recipient", Key::cl_type()),
+            Parameter::new("token_ids", CLType::List(Box::new(TokenId::cl_type()))),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
     entry_points.add_entry_point(EntryPoint::new(
-        "constructor",
+        "transfer_and_approve",
         vec![
-            Parameter::new("name", String::cl_type()),
-            Parameter::new("symbol", String::cl_type()),
-            Parameter::new("meta", Meta::cl_type()),
+            Parameter::new("recipient", Key::cl_type()),
+            Parameter::new("token_id", TokenId::cl_type()),
+            Parameter::new("operator", Key::cl_type()),
         ],
         <()>::cl_type(),
-        EntryPointAccess::Groups(vec![Group::new("constructor")]),
+        EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "name",
-        vec![],
-        String::cl_type(),
+        "transfer_keep_operator",
+        vec![
+            Parameter::new("recipient", Key::cl_type()),
+            Parameter::new("token_id", TokenId::cl_type()),
+            Parameter::new("operator", Key::cl_type()),
+        ],
+        <()>::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "symbol",
-        vec![],
-        String::cl_type(),
+        "safe_transfer_from",
+        vec![
+            Parameter::new("sender", Key::cl_type()),
+            Parameter::new("recipient", Key::cl_type()),
+            Parameter::new("token_ids", CLType::List(Box::new(TokenId::cl_type()))),
+        ],
+        <()>::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "meta",
-        vec![],
-        Meta::cl_type(),
+        "approve",
+        vec![
+            Parameter::new("spender", Key::cl_type()),
+            Parameter::new("token_ids", CLType::List(Box::new(TokenId::cl_type()))),
+        ],
+        <()>::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "total_supply",
-        vec![],
-        U256::cl_type(),
+        "has_approval",
+        vec![
+            Parameter::new("owner", Key::cl_type()),
+            Parameter::new("token_id", TokenId::cl_type()),
+        ],
+        CLType::Bool,
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "balance_of",
-        vec![Parameter::new("owner", Key::cl_type())],
-        U256::cl_type(),
+        "revoke",
+        vec![Parameter::new(
+            "token_ids",
+            CLType::List(Box::new(TokenId::cl_type())),
+        )],
+        <()>::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "owner_of",
-        vec![Parameter::new("token_id", TokenId::cl_type())],
+        "get_approved",
+        vec![
+            Parameter::new("owner", Key::cl_type()),
+            Parameter::new("token_id", TokenId::cl_type()),
+        ],
         CLType::Option(Box::new(CLType::Key)),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "token_meta",
-        vec![Parameter::new("token_id", TokenId::cl_type())],
-        Meta::cl_type(),
+        "all_tokens",
+        vec![
+            Parameter::new("start", U256::cl_type()),
+            Parameter::new("limit", CLType::U32),
+        ],
+        CLType::List(Box::new(TokenId::cl_type())),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "update_token_meta",
+        "tokens_minted_between",
         vec![
-            Parameter::new("token_id", TokenId::cl_type()),
-            Parameter::new("token_meta", Meta::cl_type()),
+            Parameter::new("from", CLType::U64),
+            Parameter::new("to", CLType::U64),
+            Parameter::new("start", CLType::U256),
+            Parameter::new("limit", CLType::U32),
         ],
-        <()>::cl_type(),
+        CLType::List(Box::new(TokenId::cl_type())),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "mint",
+        "burned_tokens",
         vec![
-            Parameter::new("recipient", Key::cl_type()),
-            Parameter::new("token_ids", CLType::List(Box::new(TokenId::cl_type()))),
-            Parameter::new("token_metas", CLType::List(Box::new(Meta::cl_type()))),
+            Parameter::new("start", U256::cl_type()),
+            Parameter::new("limit", CLType::U32),
         ],
-        <()>::cl_type(),
+        CLType::List(Box::new(TokenId::cl_type())),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "mint_copies",
+        "tokens_with_attribute",
         vec![
-            Parameter::new("recipient", Key::cl_type()),
-            Parameter::new("token_ids", CLType::List(Box::new(TokenId::cl_type()))),
-            Parameter::new("token_meta", Meta::cl_type()),
-            Parameter::new("count", CLType::U32),
+            Parameter::new("key", String::cl_type()),
+            Parameter::new("value", String::cl_type()),
+            Parameter::new("start", U256::cl_type()),
+            Parameter::new("limit", CLType::U32),
         ],
-        <()>::cl_type(),
+        CLType::List(Box::new(TokenId::cl_type())),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "burn",
+        "holds_exactly",
         vec![
             Parameter::new("owner", Key::cl_type()),
-            Parameter::new("token_ids", CLType::List(Box::new(TokenId::cl_type()))),
+            Parameter::new("token_ids", Vec::<TokenId>::cl_type()),
         ],
-        <()>::cl_type(),
+        CLType::Bool,
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "transfer",
+        "owner_has_attribute",
         vec![
-            Parameter::new("recipient", Key::cl_type()),
-            Parameter::new("token_ids", CLType::List(Box::new(TokenId::cl_type()))),
+            Parameter::new("owner", Key::cl_type()),
+            Parameter::new("key", String::cl_type()),
+            Parameter::new("value", String::cl_type()),
+            Parameter::new("cap", CLType::U32),
         ],
-        <()>::cl_type(),
+        CLType::Bool,
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "transfer_from",
+        "delegate_approval",
         vec![
-            Parameter::new("sender", Key::cl_type()),
-            Parameter::new("recipient", Key::cl_type()),
-            Parameter::new("token_ids", CLType::List(Box::new(TokenId::cl_type()))),
+            Parameter::new("token_id", TokenId::cl_type()),
+            Parameter::new("new_spender", Key::cl_type()),
         ],
         <()>::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "approve",
+        "get_token_by_index",
         vec![
-            Parameter::new("spender", Key::cl_type()),
-            Parameter::new("token_ids", CLType::List(Box::new(TokenId::cl_type()))),
+            Parameter::new("owner", Key::cl_type()),
+            Parameter::new("index", U256::cl_type()),
+        ],
+        CLType::Option(Box::new(TokenId::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "lock_approval",
+        vec![
+            Parameter::new("token_id", TokenId::cl_type()),
+            Parameter::new("until", CLType::U64),
         ],
         <()>::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "get_approved",
+        "approval_locked_until",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        Option::<u64>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "my_approvals",
+        vec![Parameter::new("cap", CLType::U32)],
+        Vec::<(TokenId, Key)>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "holdings_diff",
+        vec![
+            Parameter::new("a", Key::cl_type()),
+            Parameter::new("b", Key::cl_type()),
+            Parameter::new("cap", CLType::U32),
+        ],
+        <(Vec<TokenId>, Vec<TokenId>)>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "holder_count",
+        vec![],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "aggregate_balance",
+        vec![Parameter::new(
+            "owners",
+            CLType::List(Box::new(Key::cl_type())),
+        )],
+        CLType::U256,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "ownership_histogram",
+        vec![
+            Parameter::new("buckets", CLType::List(Box::new(U256::cl_type()))),
+            Parameter::new("start", CLType::U64),
+            Parameter::new("limit", CLType::U32),
+        ],
+        CLType::List(Box::new(CLType::U32)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "owner_token_meta_at",
         vec![
             Parameter::new("owner", Key::cl_type()),
-            Parameter::new("token_id", TokenId::cl_type()),
+            Parameter::new("index", U256::cl_type()),
         ],
+        Option::<(TokenId, Meta)>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "transfer_cooldown",
+        vec![],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_transfer_cooldown",
+        vec![Parameter::new("cooldown", CLType::U64)],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "mint_lock_period",
+        vec![],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_mint_lock_period",
+        vec![Parameter::new("period", CLType::U64)],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "last_transfer_time",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        CLType::Option(Box::new(CLType::U64)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "max_mints_per_block",
+        vec![],
+        CLType::Option(Box::new(CLType::U32)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "max_approvals_per_owner",
+        vec![],
+        CLType::Option(Box::new(CLType::U32)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_max_approvals_per_owner",
+        vec![Parameter::new(
+            "limit",
+            CLType::Option(Box::new(CLType::U32)),
+        )],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "approval_count",
+        vec![Parameter::new("owner", Key::cl_type())],
+        CLType::U32,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_max_mints_per_block",
+        vec![Parameter::new(
+            "limit",
+            CLType::Option(Box::new(CLType::U32)),
+        )],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "mint_time",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        CLType::Option(Box::new(CLType::U64)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "minter_of",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
         CLType::Option(Box::new(CLType::Key)),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "get_token_by_index",
+        "recall_window",
+        vec![],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_recall_window",
+        vec![Parameter::new("window", CLType::U64)],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "admin_burn",
+        vec![Parameter::new("token_ids", Vec::<TokenId>::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "recall",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_approval_for_all",
+        vec![
+            Parameter::new("operator", Key::cl_type()),
+            Parameter::new("approved", bool::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "is_approved_for_all",
         vec![
             Parameter::new("owner", Key::cl_type()),
-            Parameter::new("index", U256::cl_type()),
+            Parameter::new("operator", Key::cl_type()),
         ],
-        CLType::Option(Box::new(TokenId::cl_type())),
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "are_approved_for_all",
+        vec![Parameter::new("pairs", Vec::<(Key, Key)>::cl_type())],
+        Vec::<bool>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_nonce",
+        vec![Parameter::new("value", CLType::U32)],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "last_mint_time",
+        vec![],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "nonce",
+        vec![],
+        CLType::U32,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "token_id_salt",
+        vec![],
+        Option::<String>::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
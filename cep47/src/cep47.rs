@@ -1,19 +1,90 @@
 use crate::{
-    data::{self, Allowances, Metadata, OwnedTokens, Owners},
+    data::{
+        self, AllTokens, Allowances, ApprovalLocks, BurnedIndex, BurnedTokens, Expiries,
+        FrozenTokens, Holders, LastTransfer, MetaTemplates, MetaValueType, MetaVersions, Metadata,
+        MintLockedIds, MintQueue, MintTimes, MintedCount, Minters, Operators, OwnedTokens, Owners,
+        RecentEvents, RecipientPolicy, SupportedInterfaces, TemplateUsage, TokenACL, TokenFees,
+        TokenRoyalties, TokenTemplates, TraitCounts, TransferAllowlist, TypedMetadata,
+        UsedMetaNonces, UsedMetaValues, UsedMintKeys, ViewCounts,
+    },
     event::CEP47Event,
-    Meta, TokenId,
+    Meta, MintReceipt, TokenId, TypedMeta,
+};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::{String, ToString},
+    vec::Vec,
+};
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{
+    bytesrepr::{Bytes, FromBytes, ToBytes},
+    crypto, runtime_args, ApiError, ContractHash, ContractPackageHash, Key, PublicKey, RuntimeArgs,
+    Signature, U256,
 };
-use alloc::{string::String, vec::Vec};
-use casper_types::{ApiError, Key, U256};
 use contract_utils::{ContractContext, ContractStorage};
 use core::convert::TryInto;
 
+pub const MAX_QUERY_BATCH_SIZE: usize = 100;
+pub const TRANSFERER_ROLE: &str = "transferer";
+pub const MAX_SHIPPING_REF_LENGTH: usize = 128;
+pub const MAX_RECENT_EVENTS: u32 = 50;
+pub const BPS_DENOMINATOR: u16 = 10_000;
+pub const RARITY_SCALE: u64 = 1_000_000;
+pub const COMPUTE_META_ENTRY_POINT: &str = "compute_meta";
+pub const ON_CEP47_RECEIVED_ENTRY_POINT: &str = "on_cep47_received";
+pub const ON_CEP47_MINTED_ENTRY_POINT: &str = "on_cep47_minted";
+pub const INTERFACE_CEP47: &str = "cep47";
+pub const INTERFACE_ENUMERABLE: &str = "cep47-enumerable";
+pub const INTERFACE_OPERATOR_APPROVALS: &str = "cep47-operator-approvals";
+pub const INTERFACE_SAFE_TRANSFER: &str = "cep47-safe-transfer";
+pub const INTERFACE_METADATA_TEMPLATES: &str = "cep47-metadata-templates";
+pub const INTERFACE_MUTABLE_METADATA: &str = "cep47-mutable-metadata";
+// Bit positions of the feature_flags bitmask.
+pub const FEATURE_PAUSED: u32 = 1;
+pub const FEATURE_TRANSFERS_PAUSED: u32 = 1 << 1;
+pub const FEATURE_IMMUTABLE_METADATA: u32 = 1 << 2;
+pub const FEATURE_METADATA_DELTA_EVENTS: u32 = 1 << 3;
+pub const FEATURE_BURN_META_EVENTS: u32 = 1 << 4;
+pub const FEATURE_EVENTS_ENABLED: u32 = 1 << 5;
+pub const FEATURE_TRANSFER_FEE: u32 = 1 << 6;
+pub const FEATURE_SUPPLY_CAP: u32 = 1 << 7;
+pub const FEATURE_DYNAMIC_META: u32 = 1 << 8;
+pub const FEATURE_SIGNED_META: u32 = 1 << 9;
+pub const FEATURE_UNIQUE_META_KEY: u32 = 1 << 10;
+pub const FEATURE_DEBUG_ERRORS: u32 = 1 << 11;
+pub const FEATURE_TRANSFER_RESTRICTED: u32 = 1 << 12;
+pub const FEATURE_NOTIFY_ON_MINT: u32 = 1 << 13;
+// Bump whenever a CEP47Event variant is added or changed.
+pub const EVENT_SCHEMA_VERSION: u32 = 13;
+
 #[repr(u16)]
 pub enum Error {
     PermissionDenied = 1,
     WrongArguments = 2,
     TokenIdAlreadyExists = 3,
     TokenIdDoesntExist = 4,
+    RateLimited = 5,
+    RecallWindowExpired = 6,
+    SupplyCapExceeded = 7,
+    TransferCooldown = 8,
+    RecipientNotAllowed = 9,
+    MinSupplyViolation = 10,
+    TokenExpired = 11,
+    MintLockActive = 12,
+    RecipientRejected = 13,
+    MetadataLocked = 14,
+    MetadataTooLarge = 15,
+    Paused = 16,
+    InvalidSignature = 17,
+    NonceAlreadyUsed = 18,
+    MintIdLocked = 19,
+    // 20 is raised by AdminControl when the caller is not an admin.
+    DuplicateMetaValue = 21,
+    TokenFrozen = 22,
+    ApprovalLocked = 23,
+    ApprovalLimitExceeded = 24,
+    DuplicateRequest = 25,
+    NonceExhausted = 26,
 }
 
 impl From<Error> for ApiError {
@@ -23,21 +94,138 @@ impl From<Error> for ApiError {
 }
 
 pub trait CEP47<Storage: ContractStorage>: ContractContext<Storage> {
-    fn init(&mut self, name: String, symbol: String, meta: Meta) {
+    #[allow(clippy::too_many_arguments)]
+    fn init(
+        &mut self,
+        name: String,
+        symbol: String,
+        meta: Meta,
+        token_id_salt: Option<String>,
+        immutable_metadata: bool,
+        require_explicit_ids: bool,
+        creator: Key,
+        genesis_mints: Vec<(Key, Meta)>,
+    ) {
         data::set_name(name);
         data::set_symbol(symbol);
         data::set_meta(meta);
+        data::set_creator(creator);
+        data::set_meta_signer(None);
+        data::set_transfer_fee_bps(0);
+        data::set_fee_treasury(None);
+        data::set_total_approvals(U256::zero());
         data::set_total_supply(U256::zero());
+        data::set_total_supply_cap(None);
+        data::set_min_supply(None);
+        data::set_metadata_delta_events(false);
+        data::set_burn_meta_events(false);
+        data::set_debug_errors(false);
+        data::set_last_error(None);
+        data::set_stamp_on_transfer(false);
+        data::set_transfer_restricted(false);
+        data::set_notify_on_mint(false);
+        data::set_mint_lock_period(0);
+        data::set_immutable_metadata(immutable_metadata);
+        data::set_require_explicit_ids(false);
+        data::set_events_enabled(true);
+        data::set_max_meta_bytes(0);
+        data::set_paused(false);
+        data::set_transfers_paused(false);
+        data::set_recent_events_capacity(0);
+        data::set_recent_events_count(0);
+        data::set_token_id_salt(token_id_salt);
+        data::set_nonce(0);
+        data::set_last_mint_time(0);
+        data::set_burned_count(U256::zero());
+        data::set_unique_meta_key(None);
+        data::set_dynamic_meta_contract(None);
+        data::set_view_recorder(None);
+        data::set_default_meta(Meta::new());
+        data::set_meta_resolver_version(0);
+        data::set_mint_queue_head(0);
+        data::set_mint_queue_tail(0);
+        data::set_max_approvals_per_owner(None);
+        data::set_holder_count(0);
+        data::set_index_reset_cursor(None);
+        data::set_recipient_policy(RecipientPolicy::AllowAll);
+        data::set_event_schema_version(EVENT_SCHEMA_VERSION);
         Owners::init();
         OwnedTokens::init();
         Metadata::init();
         Allowances::init();
+        MintTimes::init();
+        Minters::init();
+        Operators::init();
+        MintedCount::init();
+        AllTokens::init();
+        LastTransfer::init();
+        Expiries::init();
+        BurnedTokens::init();
+        MetaTemplates::init();
+        TokenTemplates::init();
+        SupportedInterfaces::init();
+        RecentEvents::init();
+        UsedMetaNonces::init();
+        TokenRoyalties::init();
+        TypedMetadata::init();
+        MintLockedIds::init();
+        BurnedIndex::init();
+        UsedMetaValues::init();
+        FrozenTokens::init();
+        ApprovalLocks::init();
+        MintQueue::init();
+        Holders::init();
+        TokenACL::init();
+        UsedMintKeys::init();
+        ViewCounts::init();
+        TransferAllowlist::init();
+        TokenFees::init();
+        MetaVersions::init();
+        TraitCounts::init();
+        TemplateUsage::init();
+
+        let supported_interfaces_dict = SupportedInterfaces::instance();
+        for interface_id in [
+            INTERFACE_CEP47,
+            INTERFACE_ENUMERABLE,
+            INTERFACE_OPERATOR_APPROVALS,
+            INTERFACE_SAFE_TRANSFER,
+            INTERFACE_METADATA_TEMPLATES,
+        ] {
+            supported_interfaces_dict.set(interface_id, true);
+        }
+        if !immutable_metadata {
+            supported_interfaces_dict.set(INTERFACE_MUTABLE_METADATA, true);
+        }
+
+        for (recipient, token_meta) in genesis_mints {
+            self.mint(recipient, None, vec![token_meta])
+                .unwrap_or_revert();
+        }
+        // Written once here and never again.
+        data::set_genesis_supply(data::total_supply());
+        // Enabled only after genesis mints, which always use generated ids.
+        data::set_require_explicit_ids(require_explicit_ids);
+    }
+
+    fn supports_interface(&self, interface_id: String) -> bool {
+        SupportedInterfaces::instance()
+            .get(&interface_id)
+            .unwrap_or_default()
     }
 
     fn name(&self) -> String {
         data::name()
     }
 
+    fn genesis_supply(&self) -> U256 {
+        data::genesis_supply()
+    }
+
+    fn creator(&self) -> Key {
+        data::creator()
+    }
+
     fn symbol(&self) -> String {
         data::symbol()
     }
@@ -46,228 +234,2382 @@ pub trait CEP47<Storage: ContractStorage>: ContractContext<Storage> {
         data::meta()
     }
 
+    fn event_schema_version(&self) -> u32 {
+        data::event_schema_version()
+    }
+
+    fn feature_flags(&self) -> u32 {
+        let mut flags = 0;
+        for (enabled, flag) in [
+            (data::paused(), FEATURE_PAUSED),
+            (data::transfers_paused(), FEATURE_TRANSFERS_PAUSED),
+            (data::immutable_metadata(), FEATURE_IMMUTABLE_METADATA),
+            (data::metadata_delta_events(), FEATURE_METADATA_DELTA_EVENTS),
+            (data::burn_meta_events(), FEATURE_BURN_META_EVENTS),
+            (data::events_enabled(), FEATURE_EVENTS_ENABLED),
+            (
+                data::transfer_fee_bps() > 0 && data::fee_treasury().is_some(),
+                FEATURE_TRANSFER_FEE,
+            ),
+            (data::total_supply_cap().is_some(), FEATURE_SUPPLY_CAP),
+            (
+                data::dynamic_meta_contract().is_some(),
+                FEATURE_DYNAMIC_META,
+            ),
+            (data::meta_signer().is_some(), FEATURE_SIGNED_META),
+            (data::unique_meta_key().is_some(), FEATURE_UNIQUE_META_KEY),
+            (data::debug_errors(), FEATURE_DEBUG_ERRORS),
+            (data::transfer_restricted(), FEATURE_TRANSFER_RESTRICTED),
+            (data::notify_on_mint(), FEATURE_NOTIFY_ON_MINT),
+        ] {
+            if enabled {
+                flags |= flag;
+            }
+        }
+        flags
+    }
+
     fn total_supply(&self) -> U256 {
         data::total_supply()
     }
 
+    fn total_supply_cap(&self) -> Option<U256> {
+        data::total_supply_cap()
+    }
+
+    fn set_total_supply_cap(&mut self, cap: Option<U256>) -> Result<(), Error> {
+        if let Some(cap) = cap {
+            if cap < data::total_supply() {
+                return Err(Error::WrongArguments);
+            }
+        }
+        let old_cap = data::total_supply_cap();
+        data::set_total_supply_cap(cap);
+        self.emit(CEP47Event::ConfigUpdate {
+            field: String::from(data::TOTAL_SUPPLY_CAP),
+            old_value: option_to_string(old_cap),
+            new_value: option_to_string(cap),
+        });
+        Ok(())
+    }
+
+    fn min_supply(&self) -> Option<U256> {
+        data::min_supply()
+    }
+
+    fn set_min_supply(&mut self, min_supply: Option<U256>) {
+        let old_min_supply = data::min_supply();
+        data::set_min_supply(min_supply);
+        self.emit(CEP47Event::ConfigUpdate {
+            field: String::from(data::MIN_SUPPLY),
+            old_value: option_to_string(old_min_supply),
+            new_value: option_to_string(min_supply),
+        });
+    }
+
+    fn remaining_supply(&self) -> Option<U256> {
+        data::total_supply_cap().map(|cap| cap.saturating_sub(data::total_supply()))
+    }
+
     fn balance_of(&self, owner: Key) -> U256 {
         OwnedTokens::instance().get_balances(&owner)
     }
 
+    fn minted_count_of(&self, account: Key) -> U256 {
+        MintedCount::instance().get(&account)
+    }
+
     fn owner_of(&self, token_id: TokenId) -> Option<Key> {
         Owners::instance().get(&token_id)
     }
 
+    fn is_burned(&self, token_id: TokenId) -> bool {
+        BurnedTokens::instance().get(&token_id).unwrap_or_default()
+    }
+
+    fn exist_many(&self, token_ids: Vec<TokenId>) -> Result<Vec<bool>, Error> {
+        if token_ids.len() > MAX_QUERY_BATCH_SIZE {
+            return Err(Error::WrongArguments);
+        }
+        let owners_dict = Owners::instance();
+        Ok(token_ids
+            .iter()
+            .map(|token_id| owners_dict.get(token_id).is_some())
+            .collect())
+    }
+
+    fn meta_versions(&self, token_ids: Vec<TokenId>) -> Result<Vec<Option<u64>>, Error> {
+        if token_ids.len() > MAX_QUERY_BATCH_SIZE {
+            return Err(Error::WrongArguments);
+        }
+        let owners_dict = Owners::instance();
+        let meta_versions_dict = MetaVersions::instance();
+        Ok(token_ids
+            .iter()
+            .map(|token_id| {
+                owners_dict
+                    .get(token_id)
+                    .map(|_| meta_versions_dict.get(token_id))
+            })
+            .collect())
+    }
+
+    fn owners_of(&self, token_ids: Vec<TokenId>) -> Result<Vec<(TokenId, Option<Key>)>, Error> {
+        if token_ids.len() > MAX_QUERY_BATCH_SIZE {
+            return Err(Error::WrongArguments);
+        }
+        let owners_dict = Owners::instance();
+        Ok(token_ids
+            .into_iter()
+            .map(|token_id| (token_id, owners_dict.get(&token_id)))
+            .collect())
+    }
+
+    fn was_ever_minted(&self, token_id: TokenId) -> bool {
+        self.owner_of(token_id).is_some() || self.is_burned(token_id)
+    }
+
     fn token_meta(&self, token_id: TokenId) -> Option<Meta> {
-        Metadata::instance().get(&token_id)
+        if let Some(contract) = data::dynamic_meta_contract() {
+            if let Some(owner) = self.owner_of(token_id) {
+                return Some(runtime::call_contract(
+                    contract,
+                    COMPUTE_META_ENTRY_POINT,
+                    runtime_args! {
+                        "token_id" => token_id,
+                        "owner" => owner
+                    },
+                ));
+            }
+        }
+        self.stored_token_meta(token_id)
     }
 
-    fn set_token_meta(&mut self, token_id: TokenId, meta: Meta) -> Result<(), Error> {
-        if self.owner_of(token_id).is_none() {
-            return Err(Error::TokenIdDoesntExist);
-        };
+    fn stored_token_meta(&self, token_id: TokenId) -> Option<Meta> {
+        Metadata::instance().get(&token_id).or_else(|| {
+            TokenTemplates::instance()
+                .get(&token_id)
+                .and_then(|template_id| MetaTemplates::instance().get(&template_id))
+        })
+    }
 
-        let metadata_dict = Metadata::instance();
-        metadata_dict.set(&token_id, meta);
+    // Collection defaults are merged in at read time and never copied into
+    // per-token storage; the token's own keys win.
+    fn token_meta_resolved(&self, token_id: TokenId) -> Option<Meta> {
+        let token_meta = self.token_meta(token_id)?;
+        let mut resolved = data::default_meta();
+        resolved.extend(token_meta);
+        Some(resolved)
+    }
 
-        self.emit(CEP47Event::MetadataUpdate { token_id });
+    fn default_meta(&self) -> Meta {
+        data::default_meta()
+    }
+
+    fn set_default_meta(&mut self, meta: Meta) -> Result<(), Error> {
+        if data::immutable_metadata() && !data::default_meta().is_empty() {
+            return Err(Error::MetadataLocked);
+        }
+        self.validate_meta_size(&meta)?;
+        data::set_default_meta(meta);
         Ok(())
     }
 
-    fn get_token_by_index(&self, owner: Key, index: U256) -> Option<TokenId> {
-        OwnedTokens::instance().get_token_by_index(&owner, &index)
+    fn meta_template(&self, template_id: u32) -> Option<Meta> {
+        MetaTemplates::instance().get(&template_id)
     }
 
-    fn validate_token_ids(&self, token_ids: Vec<TokenId>) -> bool {
-        for token_id in &token_ids {
-            if self.owner_of(*token_id).is_some() {
-                return false;
+    fn set_meta_template(&mut self, template_id: u32, meta: Meta) -> Result<(), Error> {
+        let meta_templates_dict = MetaTemplates::instance();
+        if data::immutable_metadata() && meta_templates_dict.get(&template_id).is_some() {
+            return Err(Error::MetadataLocked);
+        }
+        self.validate_meta_size(&meta)?;
+        let usage = TemplateUsage::instance().get(template_id);
+        if usage > 0 {
+            let trait_counts_dict = TraitCounts::instance();
+            if let Some(old_meta) = meta_templates_dict.get(&template_id) {
+                trait_counts_dict.remove(&old_meta, usage);
             }
+            trait_counts_dict.add(&meta, usage);
         }
-        true
+        meta_templates_dict.set(&template_id, meta);
+        Ok(())
     }
 
-    fn mint(
-        &mut self,
-        recipient: Key,
-        token_ids: Vec<TokenId>,
-        token_metas: Vec<Meta>,
-    ) -> Result<Vec<TokenId>, Error> {
-        if token_ids.len() != token_metas.len() {
-            return Err(Error::WrongArguments);
-        };
-
-        for token_id in &token_ids {
-            if self.owner_of(*token_id).is_some() {
-                return Err(Error::TokenIdAlreadyExists);
+    // Drops the token's current traits from TraitCounts, whether they come
+    // from its own metadata or from the template it still resolves to.
+    fn release_traits(&self, token_id: TokenId) {
+        let trait_counts_dict = TraitCounts::instance();
+        if let Some(meta) = Metadata::instance().get(&token_id) {
+            trait_counts_dict.remove(&meta, 1);
+        } else if let Some(template_id) = TokenTemplates::instance().get(&token_id) {
+            TemplateUsage::instance().remove(template_id);
+            if let Some(meta) = MetaTemplates::instance().get(&template_id) {
+                trait_counts_dict.remove(&meta, 1);
             }
         }
+    }
 
-        let owners_dict = Owners::instance();
-        let owned_tokens_dict = OwnedTokens::instance();
-        let metadata_dict = Metadata::instance();
+    fn immutable_metadata(&self) -> bool {
+        data::immutable_metadata()
+    }
 
-        for (token_id, token_meta) in token_ids.iter().zip(&token_metas) {
-            metadata_dict.set(token_id, token_meta.clone());
-            owners_dict.set(token_id, recipient);
-            owned_tokens_dict.set_token(&recipient, token_id);
+    fn require_explicit_ids(&self) -> bool {
+        data::require_explicit_ids()
+    }
+
+    fn metadata_hash(&self, token_ids: Vec<TokenId>) -> Result<[u8; 32], Error> {
+        if token_ids.len() > MAX_QUERY_BATCH_SIZE {
+            return Err(Error::WrongArguments);
+        }
+        let mut bytes: Vec<u8> = Vec::new();
+        for token_id in &token_ids {
+            bytes.append(
+                &mut self
+                    .stored_token_meta(*token_id)
+                    .to_bytes()
+                    .unwrap_or_revert(),
+            );
         }
+        Ok(runtime::blake2b(bytes))
+    }
 
-        let minted_tokens_count: U256 = From::<u64>::from(token_ids.len().try_into().unwrap());
-        let new_total_supply = data::total_supply()
-            .checked_add(minted_tokens_count)
-            .unwrap();
-        data::set_total_supply(new_total_supply);
+    fn token_detail(&self, token_id: TokenId) -> Option<(Key, Meta, Meta)> {
+        let owner = self.owner_of(token_id)?;
+        let token_meta = self.token_meta(token_id).unwrap_or_default();
+        Some((owner, token_meta, self.meta()))
+    }
 
-        self.emit(CEP47Event::Mint {
-            recipient,
-            token_ids: token_ids.clone(),
+    fn dynamic_meta_contract(&self) -> Option<ContractHash> {
+        data::dynamic_meta_contract()
+    }
+
+    fn meta_resolver_version(&self) -> u32 {
+        data::meta_resolver_version()
+    }
+
+    fn view_recorder(&self) -> Option<Key> {
+        data::view_recorder()
+    }
+
+    fn set_view_recorder(&mut self, recorder: Option<Key>) {
+        let old_recorder = data::view_recorder();
+        data::set_view_recorder(recorder);
+        self.emit(CEP47Event::ConfigUpdate {
+            field: String::from(data::VIEW_RECORDER),
+            old_value: option_to_string(old_recorder),
+            new_value: option_to_string(recorder),
         });
-        Ok(token_ids)
     }
 
-    fn mint_copies(
-        &mut self,
-        recipient: Key,
-        token_ids: Vec<TokenId>,
-        token_meta: Meta,
-        count: u32,
-    ) -> Result<Vec<TokenId>, Error> {
-        let token_metas = vec![token_meta; count.try_into().unwrap()];
-        self.mint(recipient, token_ids, token_metas)
+    fn view_count(&self, token_id: TokenId) -> u64 {
+        ViewCounts::instance().get(&token_id)
     }
 
-    fn burn(&mut self, owner: Key, token_ids: Vec<TokenId>) -> Result<(), Error> {
-        let spender = self.get_caller();
-        if spender != owner {
-            for token_id in &token_ids {
-                if !self.is_approved(owner, *token_id, spender) {
-                    return Err(Error::PermissionDenied);
+    fn record_view(&mut self, token_id: TokenId) -> Result<(), Error> {
+        if data::view_recorder() != Some(self.get_caller()) {
+            return Err(Error::PermissionDenied);
+        }
+        if self.owner_of(token_id).is_none() {
+            return Err(Error::TokenIdDoesntExist);
+        }
+        ViewCounts::instance().increment(&token_id);
+        Ok(())
+    }
+
+    // Each listed trait adds RARITY_SCALE / holders-of-that-value, so rarer
+    // values weigh more. Keys the token doesn't carry add nothing.
+    fn rarity_score(&self, token_id: TokenId, trait_keys: Vec<String>) -> Result<U256, Error> {
+        if trait_keys.len() > MAX_QUERY_BATCH_SIZE {
+            return Err(Error::WrongArguments);
+        }
+        if self.owner_of(token_id).is_none() {
+            return Err(Error::TokenIdDoesntExist);
+        }
+        let token_meta = self.stored_token_meta(token_id).unwrap_or_default();
+        let trait_counts_dict = TraitCounts::instance();
+        let mut score = U256::zero();
+        for key in &trait_keys {
+            if let Some(value) = token_meta.get(key) {
+                let count = trait_counts_dict.get(key, value);
+                if count > 0 {
+                    score += U256::from(RARITY_SCALE) / U256::from(count);
                 }
             }
         }
-        self.burn_internal(owner, token_ids)
+        Ok(score)
     }
 
-    fn burn_internal(&mut self, owner: Key, token_ids: Vec<TokenId>) -> Result<(), Error> {
-        let owners_dict = Owners::instance();
-        let owned_tokens_dict = OwnedTokens::instance();
-        let metadata_dict = Metadata::instance();
-        let allowances_dict = Allowances::instance();
+    fn set_dynamic_meta_contract(&mut self, contract: Option<ContractHash>) {
+        let old_contract = data::dynamic_meta_contract();
+        data::set_dynamic_meta_contract(contract);
+        if old_contract != contract {
+            data::set_meta_resolver_version(data::meta_resolver_version() + 1);
+        }
+        self.emit(CEP47Event::ConfigUpdate {
+            field: String::from(data::DYNAMIC_META_CONTRACT),
+            old_value: option_to_string(old_contract),
+            new_value: option_to_string(contract),
+        });
+    }
 
-        for token_id in &token_ids {
-            match owners_dict.get(token_id) {
-                Some(owner_of_key) => {
-                    if owner_of_key != owner {
-                        return Err(Error::PermissionDenied);
+    fn set_token_meta(&mut self, token_id: TokenId, meta: Meta) -> Result<(), Error> {
+        if data::immutable_metadata() {
+            return Err(Error::MetadataLocked);
+        }
+        self.validate_meta_size(&meta)?;
+        if self.owner_of(token_id).is_none() {
+            return Err(Error::TokenIdDoesntExist);
+        };
+
+        let metadata_dict = Metadata::instance();
+        if let Some(unique_key) = data::unique_meta_key() {
+            let used_values_dict = UsedMetaValues::instance();
+            let old_value = metadata_dict
+                .get(&token_id)
+                .and_then(|old_meta| old_meta.get(&unique_key).cloned());
+            let new_value = meta.get(&unique_key);
+            if old_value.as_ref() != new_value {
+                if let Some(new_value) = new_value {
+                    if used_values_dict.get(new_value).is_some() {
+                        return Err(Error::DuplicateMetaValue);
                     }
+                    used_values_dict.set(new_value, &token_id);
                 }
-                None => {
-                    return Err(Error::TokenIdDoesntExist);
+                if let Some(old_value) = old_value {
+                    used_values_dict.remove(&old_value);
                 }
             }
         }
-
-        for token_id in &token_ids {
-            owned_tokens_dict.remove_token(&owner, token_id);
-            metadata_dict.remove(token_id);
-            owners_dict.remove(token_id);
-            allowances_dict.remove(&owner, token_id);
+        MetaVersions::instance().increment(&token_id);
+        self.release_traits(token_id);
+        TraitCounts::instance().add(&meta, 1);
+        if !data::metadata_delta_events() {
+            metadata_dict.set(&token_id, meta);
+            self.emit(CEP47Event::MetadataUpdate { token_id });
+            return Ok(());
         }
 
-        let burnt_tokens_count: U256 = From::<u64>::from(token_ids.len().try_into().unwrap());
-        let new_total_supply = data::total_supply()
-            .checked_sub(burnt_tokens_count)
-            .unwrap();
-        data::set_total_supply(new_total_supply);
+        let old_meta = self.stored_token_meta(token_id).unwrap_or_default();
+        let changed: Vec<(String, String)> = meta
+            .iter()
+            .filter(|(key, value)| old_meta.get(*key) != Some(*value))
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect();
+        let removed: Vec<String> = old_meta
+            .keys()
+            .filter(|key| !meta.contains_key(*key))
+            .cloned()
+            .collect();
+        metadata_dict.set(&token_id, meta);
 
-        self.emit(CEP47Event::Burn { owner, token_ids });
+        self.emit(CEP47Event::MetadataDelta {
+            token_id,
+            changed,
+            removed,
+        });
         Ok(())
     }
 
-    fn approve(&mut self, spender: Key, token_ids: Vec<TokenId>) -> Result<(), Error> {
-        let caller = self.get_caller();
-        for token_id in &token_ids {
-            match self.owner_of(*token_id) {
-                None => return Err(Error::WrongArguments),
-                Some(owner) if owner != caller => return Err(Error::PermissionDenied),
-                Some(_) => Allowances::instance().set(&caller, token_id, spender),
-            }
+    fn unique_meta_key(&self) -> Option<String> {
+        data::unique_meta_key()
+    }
+
+    // Only allowed before the first mint, since existing tokens were never indexed.
+    fn set_unique_meta_key(&mut self, key: Option<String>) -> Result<(), Error> {
+        if !data::total_supply().is_zero() || !data::burned_count().is_zero() {
+            return Err(Error::WrongArguments);
         }
-        self.emit(CEP47Event::Approve {
-            owner: caller,
-            spender,
-            token_ids,
+        let old_key = data::unique_meta_key();
+        data::set_unique_meta_key(key.clone());
+        self.emit(CEP47Event::ConfigUpdate {
+            field: String::from(data::UNIQUE_META_KEY),
+            old_value: old_key.unwrap_or_default(),
+            new_value: key.unwrap_or_default(),
         });
         Ok(())
     }
 
-    fn get_approved(&self, owner: Key, token_id: TokenId) -> Option<Key> {
-        Allowances::instance().get(&owner, &token_id)
+    fn meta_signer(&self) -> Option<PublicKey> {
+        data::meta_signer()
     }
 
-    fn transfer(&mut self, recipient: Key, token_ids: Vec<TokenId>) -> Result<(), Error> {
-        self.transfer_from(self.get_caller(), recipient, token_ids)
+    fn set_meta_signer(&mut self, signer: Option<PublicKey>) {
+        let old_signer = data::meta_signer();
+        data::set_meta_signer(signer.clone());
+        self.emit(CEP47Event::ConfigUpdate {
+            field: String::from(data::META_SIGNER),
+            old_value: option_to_string(old_signer),
+            new_value: option_to_string(signer),
+        });
     }
 
-    fn transfer_from(
+    // The signer authorizes an update off-chain; anyone may submit it and pay the gas.
+    fn set_token_meta_signed(
         &mut self,
-        owner: Key,
-        recipient: Key,
-        token_ids: Vec<TokenId>,
+        token_id: TokenId,
+        meta: Meta,
+        nonce: u64,
+        signature: Bytes,
     ) -> Result<(), Error> {
-        let spender = self.get_caller();
-
-        if owner != spender {
-            let allowances_dict = Allowances::instance();
-            for token_id in &token_ids {
-                if !self.is_approved(owner, *token_id, spender) {
-                    return Err(Error::PermissionDenied);
-                }
-                allowances_dict.remove(&owner, token_id);
-            }
+        let signer = data::meta_signer().ok_or(Error::PermissionDenied)?;
+        let used_nonces_dict = UsedMetaNonces::instance();
+        if used_nonces_dict.get(nonce) {
+            return Err(Error::NonceAlreadyUsed);
         }
-        self.transfer_from_internal(owner, recipient, token_ids)
+        let (signature, _) =
+            Signature::from_bytes(&signature).map_err(|_| Error::InvalidSignature)?;
+        let meta_hash = runtime::blake2b(meta.to_bytes().unwrap_or_revert());
+        let message = (token_id, meta_hash, nonce).to_bytes().unwrap_or_revert();
+        if crypto::verify(message, &signature, &signer).is_err() {
+            return Err(Error::InvalidSignature);
+        }
+        used_nonces_dict.set(nonce);
+        self.set_token_meta(token_id, meta)
     }
 
-    fn transfer_from_internal(
-        &mut self,
-        owner: Key,
-        recipient: Key,
-        token_ids: Vec<TokenId>,
-    ) -> Result<(), Error> {
-        let owners_dict = Owners::instance();
-        let owned_tokens_dict = OwnedTokens::instance();
+    fn token_typed_meta(&self, token_id: TokenId) -> Option<TypedMeta> {
+        TypedMetadata::instance().get(&token_id)
+    }
 
-        for token_id in &token_ids {
-            match owners_dict.get(token_id) {
-                Some(owner_of_key) => {
-                    if owner_of_key != owner {
-                        return Err(Error::PermissionDenied);
-                    }
-                }
-                None => {
-                    return Err(Error::TokenIdDoesntExist);
-                }
-            }
+    fn set_token_typed_meta(&mut self, token_id: TokenId, meta: TypedMeta) -> Result<(), Error> {
+        if data::immutable_metadata() {
+            return Err(Error::MetadataLocked);
         }
-
-        for token_id in &token_ids {
-            owned_tokens_dict.remove_token(&owner, token_id);
-            owned_tokens_dict.set_token(&recipient, token_id);
-            owners_dict.set(token_id, recipient);
+        self.validate_meta_size(&meta)?;
+        if self.owner_of(token_id).is_none() {
+            return Err(Error::TokenIdDoesntExist);
         }
+        for (type_tag, value) in meta.values() {
+            match MetaValueType::from_u8(*type_tag) {
+                Some(value_type) if value_type.accepts(value) => {}
+                _ => return Err(Error::WrongArguments),
+            }
+        }
+        TypedMetadata::instance().set(&token_id, meta);
+        MetaVersions::instance().increment(&token_id);
+        self.emit(CEP47Event::MetadataUpdate { token_id });
+        Ok(())
+    }
 
-        self.emit(CEP47Event::Transfer {
-            sender: owner,
-            recipient,
-            token_ids,
+    // Lets an indexer that starts mid-stream snapshot supply without replaying history.
+    fn announce_supply(&mut self) {
+        let total_supply = data::total_supply();
+        let total_burned = data::burned_count();
+        self.emit(CEP47Event::SupplyAnnouncement {
+            total_supply,
+            total_minted: total_supply + total_burned,
+            total_burned,
         });
-        Ok(())
     }
 
-    fn is_approved(&self, owner: Key, token_id: TokenId, spender: Key) -> bool {
-        let allowances_dict = Allowances::instance();
+    fn paused(&self) -> bool {
+        data::paused()
+    }
+
+    fn set_paused(&mut self, paused: bool) {
+        data::set_paused(paused);
+        self.emit(CEP47Event::Pause { paused });
+    }
+
+    fn transfers_paused(&self) -> bool {
+        data::transfers_paused()
+    }
+
+    fn set_transfers_paused(&mut self, paused: bool) {
+        data::set_transfers_paused(paused);
+        self.emit(CEP47Event::TransfersPause { paused });
+    }
+
+    fn max_meta_bytes(&self) -> u32 {
+        data::max_meta_bytes()
+    }
+
+    fn set_max_meta_bytes(&mut self, limit: u32) {
+        let old_limit = data::max_meta_bytes();
+        data::set_max_meta_bytes(limit);
+        self.emit(CEP47Event::ConfigUpdate {
+            field: String::from(data::MAX_META_BYTES),
+            old_value: old_limit.to_string(),
+            new_value: limit.to_string(),
+        });
+    }
+
+    fn recent_events_capacity(&self) -> u32 {
+        data::recent_events_capacity()
+    }
+
+    // Changing the capacity invalidates the slot layout, so the buffer starts over.
+    fn set_recent_events_capacity(&mut self, capacity: u32) -> Result<(), Error> {
+        if capacity > MAX_RECENT_EVENTS {
+            return Err(Error::WrongArguments);
+        }
+        let old_capacity = data::recent_events_capacity();
+        data::set_recent_events_capacity(capacity);
+        data::set_recent_events_count(0);
+        self.emit(CEP47Event::ConfigUpdate {
+            field: String::from(data::RECENT_EVENTS_CAPACITY),
+            old_value: old_capacity.to_string(),
+            new_value: capacity.to_string(),
+        });
+        Ok(())
+    }
+
+    fn recent_events(&self, count: u32) -> Vec<Bytes> {
+        let capacity = data::recent_events_capacity() as u64;
+        let total = data::recent_events_count();
+        let count = total.min(capacity).min(count as u64);
+        let recent_events = RecentEvents::instance();
+        (total - count..total)
+            .filter_map(|index| recent_events.get(index % capacity))
+            .collect()
+    }
+
+    fn validate_meta_size<T: ToBytes>(&self, meta: &T) -> Result<(), Error> {
+        let limit = data::max_meta_bytes();
+        if limit > 0 && meta.serialized_length() > limit as usize {
+            return Err(Error::MetadataTooLarge);
+        }
+        Ok(())
+    }
+
+    fn metadata_delta_events(&self) -> bool {
+        data::metadata_delta_events()
+    }
+
+    fn set_metadata_delta_events(&mut self, enabled: bool) {
+        let old_enabled = data::metadata_delta_events();
+        data::set_metadata_delta_events(enabled);
+        self.emit(CEP47Event::ConfigUpdate {
+            field: String::from(data::METADATA_DELTA_EVENTS),
+            old_value: old_enabled.to_string(),
+            new_value: enabled.to_string(),
+        });
+    }
+
+    fn stamp_on_transfer(&self) -> bool {
+        data::stamp_on_transfer()
+    }
+
+    fn set_stamp_on_transfer(&mut self, enabled: bool) -> Result<(), Error> {
+        if enabled && data::immutable_metadata() {
+            return Err(Error::MetadataLocked);
+        }
+        let old_enabled = data::stamp_on_transfer();
+        data::set_stamp_on_transfer(enabled);
+        self.emit(CEP47Event::ConfigUpdate {
+            field: String::from(data::STAMP_ON_TRANSFER),
+            old_value: old_enabled.to_string(),
+            new_value: enabled.to_string(),
+        });
+        Ok(())
+    }
+
+    fn transfer_restricted(&self) -> bool {
+        data::transfer_restricted()
+    }
+
+    fn set_transfer_restricted(&mut self, enabled: bool) {
+        let old_enabled = data::transfer_restricted();
+        data::set_transfer_restricted(enabled);
+        self.emit(CEP47Event::ConfigUpdate {
+            field: String::from(data::TRANSFER_RESTRICTED),
+            old_value: old_enabled.to_string(),
+            new_value: enabled.to_string(),
+        });
+    }
+
+    fn notify_on_mint(&self) -> bool {
+        data::notify_on_mint()
+    }
+
+    fn set_notify_on_mint(&mut self, enabled: bool) {
+        let old_enabled = data::notify_on_mint();
+        data::set_notify_on_mint(enabled);
+        self.emit(CEP47Event::ConfigUpdate {
+            field: String::from(data::NOTIFY_ON_MINT),
+            old_value: old_enabled.to_string(),
+            new_value: enabled.to_string(),
+        });
+    }
+
+    fn is_transfer_allowlisted(&self, account: Key) -> bool {
+        TransferAllowlist::instance().get(&account)
+    }
+
+    fn set_transfer_allowlisted(&mut self, accounts: Vec<Key>, allowed: bool) -> Result<(), Error> {
+        if accounts.len() > MAX_QUERY_BATCH_SIZE {
+            return Err(Error::WrongArguments);
+        }
+        let allowlist = TransferAllowlist::instance();
+        for account in &accounts {
+            if allowed {
+                allowlist.set(account);
+            } else {
+                allowlist.remove(account);
+            }
+        }
+        Ok(())
+    }
+
+    fn is_recipient_allowed(&self, recipient: &Key) -> bool {
+        data::recipient_policy().allows(recipient)
+            && (!data::transfer_restricted() || TransferAllowlist::instance().get(recipient))
+    }
+
+    fn burn_meta_events(&self) -> bool {
+        data::burn_meta_events()
+    }
+
+    fn set_burn_meta_events(&mut self, enabled: bool) {
+        let old_enabled = data::burn_meta_events();
+        data::set_burn_meta_events(enabled);
+        self.emit(CEP47Event::ConfigUpdate {
+            field: String::from(data::BURN_META_EVENTS),
+            old_value: old_enabled.to_string(),
+            new_value: enabled.to_string(),
+        });
+    }
+
+    fn debug_errors(&self) -> bool {
+        data::debug_errors()
+    }
+
+    // Not for production: see record_check.
+    fn set_debug_errors(&mut self, enabled: bool) {
+        let old_enabled = data::debug_errors();
+        data::set_debug_errors(enabled);
+        self.emit(CEP47Event::ConfigUpdate {
+            field: String::from(data::DEBUG_ERRORS),
+            old_value: old_enabled.to_string(),
+            new_value: enabled.to_string(),
+        });
+    }
+
+    fn last_error(&self) -> Option<String> {
+        data::last_error()
+    }
+
+    // A reverted deploy discards its writes, so only side-effect-free checks
+    // can leave an error behind. With debug_errors on they store the failure
+    // in last_error and return normally instead of reverting.
+    fn record_check(&mut self, context: String, result: Result<(), Error>) -> Result<(), Error> {
+        if !data::debug_errors() {
+            return result;
+        }
+        data::set_last_error(
+            result
+                .err()
+                .map(|error| format!("{} failed with error {}", context, error as u16)),
+        );
+        Ok(())
+    }
+
+    fn max_mints_per_block(&self) -> Option<u32> {
+        data::max_mints_per_block()
+    }
+
+    fn set_max_mints_per_block(&mut self, limit: Option<u32>) {
+        let old_limit = data::max_mints_per_block();
+        data::set_max_mints_per_block(limit);
+        self.emit(CEP47Event::ConfigUpdate {
+            field: String::from(data::MAX_MINTS_PER_BLOCK),
+            old_value: option_to_string(old_limit),
+            new_value: option_to_string(limit),
+        });
+    }
+
+    fn max_approvals_per_owner(&self) -> Option<u32> {
+        data::max_approvals_per_owner()
+    }
+
+    fn set_max_approvals_per_owner(&mut self, limit: Option<u32>) {
+        let old_limit = data::max_approvals_per_owner();
+        data::set_max_approvals_per_owner(limit);
+        self.emit(CEP47Event::ConfigUpdate {
+            field: String::from(data::MAX_APPROVALS_PER_OWNER),
+            old_value: option_to_string(old_limit),
+            new_value: option_to_string(limit),
+        });
+    }
+
+    fn approval_count(&self, owner: Key) -> u32 {
+        Allowances::instance().count(&owner)
+    }
+
+    fn mint_time(&self, token_id: TokenId) -> Option<u64> {
+        MintTimes::instance().get(&token_id)
+    }
+
+    fn minter_of(&self, token_id: TokenId) -> Option<Key> {
+        Minters::instance().get(&token_id)
+    }
+
+    fn recall_window(&self) -> u64 {
+        data::recall_window()
+    }
+
+    fn set_recall_window(&mut self, window: u64) {
+        let old_window = data::recall_window();
+        data::set_recall_window(window);
+        self.emit(CEP47Event::ConfigUpdate {
+            field: String::from(data::RECALL_WINDOW),
+            old_value: old_window.to_string(),
+            new_value: window.to_string(),
+        });
+    }
+
+    fn transfer_cooldown(&self) -> u64 {
+        data::transfer_cooldown()
+    }
+
+    fn set_transfer_cooldown(&mut self, cooldown: u64) {
+        let old_cooldown = data::transfer_cooldown();
+        data::set_transfer_cooldown(cooldown);
+        self.emit(CEP47Event::ConfigUpdate {
+            field: String::from(data::TRANSFER_COOLDOWN),
+            old_value: old_cooldown.to_string(),
+            new_value: cooldown.to_string(),
+        });
+    }
+
+    fn transfer_fee_bps(&self) -> u16 {
+        data::transfer_fee_bps()
+    }
+
+    fn fee_treasury(&self) -> Option<Key> {
+        data::fee_treasury()
+    }
+
+    fn set_transfer_fee(&mut self, bps: u16, treasury: Option<Key>) -> Result<(), Error> {
+        if bps > BPS_DENOMINATOR {
+            return Err(Error::WrongArguments);
+        }
+        let old_bps = data::transfer_fee_bps();
+        let old_treasury = data::fee_treasury();
+        data::set_transfer_fee_bps(bps);
+        data::set_fee_treasury(treasury);
+        self.emit(CEP47Event::ConfigUpdate {
+            field: String::from(data::TRANSFER_FEE_BPS),
+            old_value: old_bps.to_string(),
+            new_value: bps.to_string(),
+        });
+        self.emit(CEP47Event::ConfigUpdate {
+            field: String::from(data::FEE_TREASURY),
+            old_value: option_to_string(old_treasury),
+            new_value: option_to_string(treasury),
+        });
+        Ok(())
+    }
+
+    fn token_fee_bps(&self, token_id: TokenId) -> u16 {
+        TokenFees::instance()
+            .get(&token_id)
+            .unwrap_or_else(data::transfer_fee_bps)
+    }
+
+    fn set_token_fee(&mut self, token_id: TokenId, bps: Option<u16>) -> Result<(), Error> {
+        if self.owner_of(token_id).is_none() {
+            return Err(Error::TokenIdDoesntExist);
+        }
+        match bps {
+            Some(bps) if bps > BPS_DENOMINATOR => return Err(Error::WrongArguments),
+            Some(bps) => TokenFees::instance().set(&token_id, bps),
+            None => TokenFees::instance().remove(&token_id),
+        }
+        Ok(())
+    }
+
+    // Fees are only recorded as events; settlement happens off-chain. The
+    // sale price covers the whole batch, so the highest applicable rate wins.
+    fn transfer_with_fee(
+        &mut self,
+        recipient: Key,
+        token_ids: Vec<TokenId>,
+        sale_price: U256,
+    ) -> Result<(), Error> {
+        let bps = token_ids
+            .iter()
+            .map(|token_id| self.token_fee_bps(*token_id))
+            .max()
+            .unwrap_or_else(data::transfer_fee_bps);
+        let amount = sale_price
+            .checked_mul(U256::from(bps))
+            .ok_or(Error::WrongArguments)?
+            / U256::from(BPS_DENOMINATOR);
+        self.transfer(recipient, token_ids)?;
+        if let Some(treasury) = data::fee_treasury() {
+            if !amount.is_zero() {
+                self.emit(CEP47Event::TransferFee { amount, treasury });
+            }
+        }
+        Ok(())
+    }
+
+    // Tokens without their own entry attribute the collection creator at zero bps.
+    fn token_royalty(&self, token_id: TokenId) -> Option<(Key, u16)> {
+        TokenRoyalties::instance()
+            .get(&token_id)
+            .or_else(|| self.owner_of(token_id).map(|_| (data::creator(), 0)))
+    }
+
+    fn set_token_royalties(&mut self, entries: Vec<(TokenId, Key, u16)>) -> Result<(), Error> {
+        if entries.len() > MAX_QUERY_BATCH_SIZE {
+            return Err(Error::WrongArguments);
+        }
+        let owners_dict = Owners::instance();
+        for (token_id, _, bps) in &entries {
+            if *bps > BPS_DENOMINATOR {
+                return Err(Error::WrongArguments);
+            }
+            if owners_dict.get(token_id).is_none() {
+                return Err(Error::TokenIdDoesntExist);
+            }
+        }
+
+        let token_royalties_dict = TokenRoyalties::instance();
+        for (token_id, receiver, bps) in &entries {
+            token_royalties_dict.set(token_id, (*receiver, *bps));
+        }
+        self.emit(CEP47Event::RoyaltiesUpdate {
+            token_ids: entries.iter().map(|(token_id, _, _)| *token_id).collect(),
+        });
+        Ok(())
+    }
+
+    fn recipient_policy(&self) -> u8 {
+        data::recipient_policy() as u8
+    }
+
+    fn set_recipient_policy(&mut self, policy: u8) -> Result<(), Error> {
+        let new_policy = RecipientPolicy::from_u8(policy).ok_or(Error::WrongArguments)?;
+        let old_policy = data::recipient_policy();
+        data::set_recipient_policy(new_policy);
+        self.emit(CEP47Event::ConfigUpdate {
+            field: String::from(data::RECIPIENT_POLICY),
+            old_value: (old_policy as u8).to_string(),
+            new_value: policy.to_string(),
+        });
+        Ok(())
+    }
+
+    fn mint_lock_period(&self) -> u64 {
+        data::mint_lock_period()
+    }
+
+    fn set_mint_lock_period(&mut self, period: u64) {
+        let old_period = data::mint_lock_period();
+        data::set_mint_lock_period(period);
+        self.emit(CEP47Event::ConfigUpdate {
+            field: String::from(data::MINT_LOCK_PERIOD),
+            old_value: old_period.to_string(),
+            new_value: period.to_string(),
+        });
+    }
+
+    fn last_transfer_time(&self, token_id: TokenId) -> Option<u64> {
+        LastTransfer::instance().get(&token_id)
+    }
+
+    fn token_expiry(&self, token_id: TokenId) -> Option<u64> {
+        Expiries::instance().get(&token_id)
+    }
+
+    fn is_expired(&self, token_id: TokenId) -> bool {
+        match self.token_expiry(token_id) {
+            Some(expires_at) => {
+                let block_time: u64 = runtime::get_blocktime().into();
+                block_time >= expires_at
+            }
+            None => false,
+        }
+    }
+
+    fn nonce(&self) -> u32 {
+        data::nonce()
+    }
+
+    // Only moves forward, so ids generated from earlier nonces are never produced again.
+    // u32::MAX is rejected because it leaves no nonce for another generated id.
+    fn set_nonce(&mut self, value: u32) -> Result<(), Error> {
+        let old_nonce = data::nonce();
+        if value < old_nonce || value == u32::MAX {
+            return Err(Error::WrongArguments);
+        }
+        data::set_nonce(value);
+        self.emit(CEP47Event::ConfigUpdate {
+            field: String::from(data::NONCE),
+            old_value: old_nonce.to_string(),
+            new_value: value.to_string(),
+        });
+        Ok(())
+    }
+
+    fn last_mint_time(&self) -> u64 {
+        data::last_mint_time()
+    }
+
+    fn token_id_salt(&self) -> Option<String> {
+        data::token_id_salt()
+    }
+
+    fn get_token_by_index(&self, owner: Key, index: U256) -> Option<TokenId> {
+        OwnedTokens::instance().get_token_by_index(&owner, &index)
+    }
+
+    fn my_approvals(&self, cap: u32) -> Vec<(TokenId, Key)> {
+        let caller = self.get_caller();
+        let owned_tokens_dict = OwnedTokens::instance();
+        let allowances_dict = Allowances::instance();
+        let balance = owned_tokens_dict.get_balances(&caller);
+        let cap = core::cmp::min(cap as usize, MAX_QUERY_BATCH_SIZE);
+        let mut approvals = Vec::new();
+        let mut index = U256::zero();
+        while index < balance && index < U256::from(cap) {
+            if let Some(token_id) = owned_tokens_dict.get_token_by_index(&caller, &index) {
+                if let Some(spender) = allowances_dict.get(&caller, &token_id) {
+                    approvals.push((token_id, spender));
+                }
+            }
+            index = index + 1;
+        }
+        approvals
+    }
+
+    // Each token has a single owner, so the sides only overlap when a == b.
+    fn holdings_diff(&self, a: Key, b: Key, cap: u32) -> (Vec<TokenId>, Vec<TokenId>) {
+        if a == b {
+            return (Vec::new(), Vec::new());
+        }
+        (self.owner_tokens(a, cap), self.owner_tokens(b, cap))
+    }
+
+    fn owner_tokens(&self, owner: Key, cap: u32) -> Vec<TokenId> {
+        let owned_tokens_dict = OwnedTokens::instance();
+        let balance = owned_tokens_dict.get_balances(&owner);
+        let cap = core::cmp::min(cap as usize, MAX_QUERY_BATCH_SIZE);
+        let mut token_ids = Vec::new();
+        let mut index = U256::zero();
+        while index < balance && token_ids.len() < cap {
+            if let Some(token_id) = owned_tokens_dict.get_token_by_index(&owner, &index) {
+                token_ids.push(token_id);
+            }
+            index = index + 1;
+        }
+        token_ids
+    }
+
+    fn holder_count(&self) -> u64 {
+        data::holder_count()
+    }
+
+    // Duplicate owners are counted once so a padded list can't inflate the sum.
+    fn aggregate_balance(&self, owners: Vec<Key>) -> Result<U256, Error> {
+        if owners.len() > MAX_QUERY_BATCH_SIZE {
+            return Err(Error::WrongArguments);
+        }
+        let owners: BTreeSet<Key> = owners.into_iter().collect();
+        let owned_tokens_dict = OwnedTokens::instance();
+        Ok(owners.iter().fold(U256::zero(), |sum, owner| {
+            sum + owned_tokens_dict.get_balances(owner)
+        }))
+    }
+
+    // Counts holders per balance bucket, where bucket i covers
+    // buckets[i] <= balance < buckets[i + 1]. Paged over the holder index, so
+    // callers sum the pages for the whole collection.
+    fn ownership_histogram(
+        &self,
+        buckets: Vec<U256>,
+        start: u64,
+        limit: u32,
+    ) -> Result<Vec<u32>, Error> {
+        if buckets.len() > MAX_QUERY_BATCH_SIZE || buckets.windows(2).any(|w| w[0] >= w[1]) {
+            return Err(Error::WrongArguments);
+        }
+        let holders = Holders::instance();
+        let owned_tokens_dict = OwnedTokens::instance();
+        let limit = core::cmp::min(limit as usize, MAX_QUERY_BATCH_SIZE) as u64;
+        let end = core::cmp::min(start.saturating_add(limit), data::holder_count());
+        let mut counts = vec![0u32; buckets.len()];
+        for index in start..end {
+            let holder = holders.get(index).unwrap_or_revert();
+            let balance = owned_tokens_dict.get_balances(&holder);
+            if let Some(bucket) = buckets.iter().rposition(|threshold| *threshold <= balance) {
+                counts[bucket] += 1;
+            }
+        }
+        Ok(counts)
+    }
+
+    fn owner_token_meta_at(&self, owner: Key, index: U256) -> Option<(TokenId, Meta)> {
+        let token_id = self.get_token_by_index(owner, index)?;
+        Some((token_id, self.token_meta(token_id).unwrap_or_default()))
+    }
+
+    fn all_tokens(&self, start: U256, limit: u32) -> Vec<TokenId> {
+        let all_tokens_dict = AllTokens::instance();
+        let total_supply = data::total_supply();
+        let limit = core::cmp::min(limit as usize, MAX_QUERY_BATCH_SIZE);
+        let mut token_ids = Vec::new();
+        let mut index = start;
+        while index < total_supply && token_ids.len() < limit {
+            token_ids.push(
+                all_tokens_dict
+                    .get_token_by_index(&index)
+                    .unwrap_or_revert(),
+            );
+            index = index + 1;
+        }
+        token_ids
+    }
+
+    // Filters the all_tokens window [start, start + limit), so callers page
+    // through the global index until start reaches total_supply. `from` and
+    // `to` are inclusive.
+    fn tokens_minted_between(&self, from: u64, to: u64, start: U256, limit: u32) -> Vec<TokenId> {
+        let mint_times_dict = MintTimes::instance();
+        self.all_tokens(start, limit)
+            .into_iter()
+            .filter(|token_id| {
+                mint_times_dict
+                    .get(token_id)
+                    .map_or(false, |mint_time| from <= mint_time && mint_time <= to)
+            })
+            .collect()
+    }
+
+    fn burned_tokens(&self, start: U256, limit: u32) -> Vec<TokenId> {
+        let burned_index = BurnedIndex::instance();
+        let burned_count = data::burned_count();
+        let limit = core::cmp::min(limit as usize, MAX_QUERY_BATCH_SIZE);
+        let mut token_ids = Vec::new();
+        let mut index = start;
+        while index < burned_count && token_ids.len() < limit {
+            token_ids.push(burned_index.get(&index).unwrap_or_revert());
+            index = index + 1;
+        }
+        token_ids
+    }
+
+    fn tokens_with_attribute(
+        &self,
+        key: String,
+        value: String,
+        start: U256,
+        limit: u32,
+    ) -> Vec<TokenId> {
+        self.all_tokens(start, limit)
+            .into_iter()
+            .filter(|token_id| {
+                self.stored_token_meta(*token_id)
+                    .map_or(false, |meta| meta.get(&key) == Some(&value))
+            })
+            .collect()
+    }
+
+    fn holds_exactly(&self, owner: Key, token_ids: Vec<TokenId>) -> bool {
+        let token_ids: BTreeSet<TokenId> = token_ids.into_iter().collect();
+        self.balance_of(owner) == U256::from(token_ids.len())
+            && token_ids
+                .iter()
+                .all(|token_id| self.owner_of(*token_id) == Some(owner))
+    }
+
+    fn owner_has_attribute(&self, owner: Key, key: String, value: String, cap: u32) -> bool {
+        let owned_tokens_dict = OwnedTokens::instance();
+        let balance = owned_tokens_dict.get_balances(&owner);
+        let cap = core::cmp::min(cap as usize, MAX_QUERY_BATCH_SIZE);
+        let mut index = U256::zero();
+        while index < balance && index < U256::from(cap) {
+            if let Some(token_id) = owned_tokens_dict.get_token_by_index(&owner, &index) {
+                if self
+                    .stored_token_meta(token_id)
+                    .map_or(false, |meta| meta.get(&key) == Some(&value))
+                {
+                    return true;
+                }
+            }
+            index = index + 1;
+        }
+        false
+    }
+
+    // Burned ids stay retired so `is_burned` and `burned_tokens` never list a live token.
+    fn validate_token_ids(&self, token_ids: Vec<TokenId>) -> bool {
+        for token_id in &token_ids {
+            if self.was_ever_minted(*token_id) {
+                return false;
+            }
+        }
+        true
+    }
+
+    // Only valid for the current block: without a salt the ids are seeded by block time.
+    fn peek_token_ids(&self, n: u32) -> Result<Vec<TokenId>, Error> {
+        let n = core::cmp::min(n as usize, MAX_QUERY_BATCH_SIZE) as u32;
+        self.preview_token_ids(n)
+    }
+
+    fn generate_token_ids(&mut self, n: u32) -> Result<Vec<TokenId>, Error> {
+        let token_ids = self.preview_token_ids(n)?;
+        let next_nonce = data::nonce().checked_add(n).ok_or(Error::NonceExhausted)?;
+        data::set_nonce(next_nonce);
+        Ok(token_ids)
+    }
+
+    // A wrapped nonce would hand out ids that were already generated.
+    fn preview_token_ids(&self, n: u32) -> Result<Vec<TokenId>, Error> {
+        let nonce = data::nonce();
+        let end = nonce.checked_add(n).ok_or(Error::NonceExhausted)?;
+        let seed: Vec<u8> = match data::token_id_salt() {
+            Some(salt) => salt.into_bytes(),
+            None => {
+                let block_time: u64 = runtime::get_blocktime().into();
+                block_time.to_bytes().unwrap_or_revert()
+            }
+        };
+        let mut token_ids = Vec::new();
+        for i in nonce..end {
+            let mut bytes = seed.clone();
+            bytes.append(&mut i.to_bytes().unwrap_or_revert());
+            let hash = runtime::blake2b(bytes);
+            token_ids.push(TokenId::from_big_endian(&hash));
+        }
+        Ok(token_ids)
+    }
+
+    fn mint(
+        &mut self,
+        recipient: Key,
+        token_ids: Option<Vec<TokenId>>,
+        token_metas: Vec<Meta>,
+    ) -> Result<Vec<TokenId>, Error> {
+        self.mint_internal(recipient, token_ids, token_metas, None)
+    }
+
+    fn is_frozen(&self, token_id: TokenId) -> bool {
+        FrozenTokens::instance().get(&token_id)
+    }
+
+    fn set_token_frozen(&mut self, token_id: TokenId, frozen: bool) -> Result<(), Error> {
+        if self.owner_of(token_id).is_none() {
+            return Err(Error::TokenIdDoesntExist);
+        }
+        if frozen {
+            FrozenTokens::instance().set(&token_id);
+        } else {
+            FrozenTokens::instance().remove(&token_id);
+        }
+        Ok(())
+    }
+
+    fn is_mint_id_locked(&self, token_id: TokenId) -> bool {
+        MintLockedIds::instance().get(&token_id)
+    }
+
+    fn lock_mint_id(&mut self, token_id: TokenId) {
+        MintLockedIds::instance().set(&token_id);
+    }
+
+    fn unlock_mint_id(&mut self, token_id: TokenId) {
+        MintLockedIds::instance().remove(&token_id);
+    }
+
+    // A retry carrying the same key and arguments gets the original ids back;
+    // reusing a key for a different request is rejected.
+    fn mint_idempotent(
+        &mut self,
+        recipient: Key,
+        token_ids: Option<Vec<TokenId>>,
+        token_metas: Vec<Meta>,
+        idempotency_key: String,
+    ) -> Result<Vec<TokenId>, Error> {
+        let request = (recipient, token_ids.clone(), token_metas.clone())
+            .to_bytes()
+            .unwrap_or_revert();
+        let request_hash = hex::encode(runtime::blake2b(request));
+        let used_keys = UsedMintKeys::instance();
+        if let Some((used_hash, minted)) = used_keys.get(&idempotency_key) {
+            if used_hash != request_hash {
+                return Err(Error::DuplicateRequest);
+            }
+            return Ok(minted);
+        }
+        let minted = self.mint(recipient, token_ids, token_metas)?;
+        used_keys.set(&idempotency_key, request_hash, minted.clone());
+        Ok(minted)
+    }
+
+    fn mint_with_receipt(
+        &mut self,
+        recipient: Key,
+        token_ids: Option<Vec<TokenId>>,
+        token_metas: Vec<Meta>,
+    ) -> Result<MintReceipt, Error> {
+        let token_ids = self.mint(recipient, token_ids, token_metas)?;
+        Ok(MintReceipt {
+            token_ids,
+            new_total_supply: data::total_supply(),
+            block_time: runtime::get_blocktime().into(),
+        })
+    }
+
+    fn mint_from_template(
+        &mut self,
+        recipient: Key,
+        template_id: u32,
+        count: u32,
+    ) -> Result<Vec<TokenId>, Error> {
+        if self.meta_template(template_id).is_none() {
+            return Err(Error::WrongArguments);
+        }
+        let token_metas = vec![Meta::new(); count.try_into().unwrap()];
+        self.mint_internal(recipient, None, token_metas, Some(template_id))
+    }
+
+    fn mint_internal(
+        &mut self,
+        recipient: Key,
+        token_ids: Option<Vec<TokenId>>,
+        token_metas: Vec<Meta>,
+        template_id: Option<u32>,
+    ) -> Result<Vec<TokenId>, Error> {
+        if data::paused() {
+            return Err(Error::Paused);
+        }
+
+        let token_ids = match token_ids {
+            Some(token_ids) => {
+                self.check_mint_ids(&token_ids, &token_metas)?;
+                token_ids
+            }
+            None if data::require_explicit_ids() => return Err(Error::WrongArguments),
+            None => self.generate_token_ids(token_metas.len().try_into().unwrap())?,
+        };
+        self.check_mint(recipient, &token_ids, &token_metas)?;
+
+        let unique_key = data::unique_meta_key();
+        let used_values_dict = UsedMetaValues::instance();
+        let minted_tokens_count: U256 = From::<u64>::from(token_ids.len().try_into().unwrap());
+        let new_total_supply = data::total_supply()
+            .checked_add(minted_tokens_count)
+            .unwrap();
+
+        self.record_mints_in_block(token_ids.len().try_into().unwrap())?;
+
+        let owners_dict = Owners::instance();
+        let owned_tokens_dict = OwnedTokens::instance();
+        let metadata_dict = Metadata::instance();
+        let token_templates_dict = TokenTemplates::instance();
+        let mint_times_dict = MintTimes::instance();
+        let minters_dict = Minters::instance();
+        let all_tokens_dict = AllTokens::instance();
+        let meta_versions_dict = MetaVersions::instance();
+        let trait_counts_dict = TraitCounts::instance();
+        let template_meta = template_id.and_then(|id| MetaTemplates::instance().get(&id));
+        let minter = self.get_caller();
+        let block_time: u64 = runtime::get_blocktime().into();
+        let mut length = data::total_supply();
+
+        for (token_id, token_meta) in token_ids.iter().zip(&token_metas) {
+            match template_id {
+                Some(template_id) => token_templates_dict.set(token_id, template_id),
+                None => metadata_dict.set(token_id, token_meta.clone()),
+            }
+            meta_versions_dict.increment(token_id);
+            trait_counts_dict.add(template_meta.as_ref().unwrap_or(token_meta), 1);
+            if let Some(value) = unique_key.as_ref().and_then(|key| token_meta.get(key)) {
+                used_values_dict.set(value, token_id);
+            }
+            owners_dict.set(token_id, recipient);
+            owned_tokens_dict.set_token(&recipient, token_id);
+            mint_times_dict.set(token_id, block_time);
+            minters_dict.set(token_id, minter);
+            all_tokens_dict.add_token(&length, token_id);
+            length = length + 1;
+        }
+        if let Some(template_id) = template_id {
+            TemplateUsage::instance().add(template_id, token_ids.len().try_into().unwrap());
+        }
+        MintedCount::instance().add(&recipient, minted_tokens_count);
+
+        data::set_total_supply(new_total_supply);
+        data::set_last_mint_time(block_time);
+
+        self.emit(CEP47Event::Mint {
+            recipient,
+            token_ids: token_ids.clone(),
+        });
+
+        // A revert inside the callout aborts the whole mint.
+        if let Key::Hash(package_hash) = recipient {
+            if data::notify_on_mint() {
+                let _: () = runtime::call_versioned_contract(
+                    ContractPackageHash::new(package_hash),
+                    None,
+                    ON_CEP47_MINTED_ENTRY_POINT,
+                    runtime_args! {
+                        "recipient" => recipient,
+                        "token_ids" => token_ids.clone()
+                    },
+                );
+            }
+        }
+        Ok(token_ids)
+    }
+
+    fn validate_mint(
+        &self,
+        recipient: Key,
+        token_ids: Option<Vec<TokenId>>,
+        token_metas: Vec<Meta>,
+    ) -> Result<(), Error> {
+        if data::paused() {
+            return Err(Error::Paused);
+        }
+        let token_ids = match token_ids {
+            Some(token_ids) => {
+                self.check_mint_ids(&token_ids, &token_metas)?;
+                token_ids
+            }
+            None if data::require_explicit_ids() => return Err(Error::WrongArguments),
+            None => self.preview_token_ids(token_metas.len().try_into().unwrap())?,
+        };
+        self.check_mint(recipient, &token_ids, &token_metas)?;
+        self.mints_in_block_after(token_ids.len().try_into().unwrap())?;
+        Ok(())
+    }
+
+    fn check_mint_ids(&self, token_ids: &[TokenId], token_metas: &[Meta]) -> Result<(), Error> {
+        if token_ids.len() != token_metas.len() {
+            return Err(Error::WrongArguments);
+        };
+        let mint_locked_ids_dict = MintLockedIds::instance();
+        if token_ids
+            .iter()
+            .any(|token_id| mint_locked_ids_dict.get(token_id))
+        {
+            return Err(Error::MintIdLocked);
+        }
+        Ok(())
+    }
+
+    fn check_mint(
+        &self,
+        recipient: Key,
+        token_ids: &[TokenId],
+        token_metas: &[Meta],
+    ) -> Result<(), Error> {
+        if !self.is_recipient_allowed(&recipient) {
+            return Err(Error::RecipientNotAllowed);
+        }
+
+        if !self.validate_token_ids(token_ids.to_vec()) {
+            return Err(Error::TokenIdAlreadyExists);
+        }
+
+        for token_meta in token_metas {
+            self.validate_meta_size(token_meta)?;
+        }
+
+        if let Some(unique_key) = data::unique_meta_key() {
+            let used_values_dict = UsedMetaValues::instance();
+            let mut seen = BTreeSet::new();
+            for value in token_metas.iter().filter_map(|meta| meta.get(&unique_key)) {
+                if !seen.insert(value) || used_values_dict.get(value).is_some() {
+                    return Err(Error::DuplicateMetaValue);
+                }
+            }
+        }
+
+        let minted_tokens_count: U256 = From::<u64>::from(token_ids.len().try_into().unwrap());
+        let new_total_supply = data::total_supply()
+            .checked_add(minted_tokens_count)
+            .unwrap();
+        if let Some(cap) = data::total_supply_cap() {
+            if new_total_supply > cap {
+                return Err(Error::SupplyCapExceeded);
+            }
+        }
+        Ok(())
+    }
+
+    fn mints_in_block_after(&self, count: u32) -> Result<Option<u32>, Error> {
+        let limit = match data::max_mints_per_block() {
+            Some(limit) => limit,
+            None => return Ok(None),
+        };
+        let block_time: u64 = runtime::get_blocktime().into();
+        let minted = if data::last_mint_block_time() == block_time {
+            data::mints_in_block()
+        } else {
+            0
+        };
+        let new_minted = minted.checked_add(count).ok_or(Error::RateLimited)?;
+        if new_minted > limit {
+            return Err(Error::RateLimited);
+        }
+        Ok(Some(new_minted))
+    }
+
+    fn record_mints_in_block(&mut self, count: u32) -> Result<(), Error> {
+        if let Some(new_minted) = self.mints_in_block_after(count)? {
+            data::set_last_mint_block_time(runtime::get_blocktime().into());
+            data::set_mints_in_block(new_minted);
+        }
+        Ok(())
+    }
+
+    fn mint_copies(
+        &mut self,
+        recipient: Key,
+        token_ids: Option<Vec<TokenId>>,
+        token_meta: Meta,
+        count: u32,
+    ) -> Result<Vec<TokenId>, Error> {
+        let token_metas = vec![token_meta; count.try_into().unwrap()];
+        self.mint(recipient, token_ids, token_metas)
+    }
+
+    fn mint_to_many(
+        &mut self,
+        recipients_and_metas: Vec<(Key, Meta)>,
+    ) -> Result<Vec<(Key, TokenId)>, Error> {
+        let mut minted = Vec::new();
+        for (recipient, token_meta) in recipients_and_metas {
+            let token_ids = self.mint(recipient, None, vec![token_meta])?;
+            minted.push((recipient, token_ids[0]));
+        }
+        Ok(minted)
+    }
+
+    // The draw is derived from block time and the id nonce, both known to the
+    // deployer ahead of time, so it must not guard anything of real value.
+    fn mint_random(
+        &mut self,
+        recipient: Key,
+        templates: Vec<(u32, Meta)>,
+    ) -> Result<TokenId, Error> {
+        let total_weight: u64 = templates.iter().map(|(weight, _)| *weight as u64).sum();
+        if total_weight == 0 {
+            return Err(Error::WrongArguments);
+        }
+        let block_time: u64 = runtime::get_blocktime().into();
+        let seed = (block_time, data::nonce()).to_bytes().unwrap_or_revert();
+        let hash = runtime::blake2b(seed);
+        let mut draw = u64::from_le_bytes(hash[..8].try_into().unwrap()) % total_weight;
+        let mut picked = Meta::new();
+        for (weight, token_meta) in templates {
+            if draw < weight as u64 {
+                picked = token_meta;
+                break;
+            }
+            draw -= weight as u64;
+        }
+        let token_ids = self.mint(recipient, None, vec![picked])?;
+        Ok(token_ids[0])
+    }
+
+    // Burns the caller's inputs and mints one replacement to the caller; any
+    // failure reverts the whole deploy, so the two halves never land apart.
+    fn forge(&mut self, burn_ids: Vec<TokenId>, new_meta: Meta) -> Result<TokenId, Error> {
+        if burn_ids.is_empty() {
+            return Err(Error::WrongArguments);
+        }
+        // The floor applies to the supply after the replacement mint, not in between.
+        if let Some(min_supply) = data::min_supply() {
+            let net_supply = data::total_supply()
+                .checked_sub(U256::from(burn_ids.len()))
+                .ok_or(Error::WrongArguments)?
+                + U256::one();
+            if net_supply < min_supply {
+                return Err(Error::MinSupplyViolation);
+            }
+        }
+        let caller = self.get_caller();
+        self.burn_tokens(caller, burn_ids, false)?;
+        let token_ids = self.mint(caller, None, vec![new_meta])?;
+        Ok(token_ids[0])
+    }
+
+    fn dictionary_names(&self) -> Vec<String> {
+        data::dictionary_names()
+    }
+
+    fn mint_queue_len(&self) -> u64 {
+        data::mint_queue_tail() - data::mint_queue_head()
+    }
+
+    fn enqueue_mint(&mut self, recipient: Key, token_meta: Meta) -> Result<(), Error> {
+        self.validate_meta_size(&token_meta)?;
+        let tail = data::mint_queue_tail();
+        MintQueue::instance().set(tail, recipient, token_meta);
+        data::set_mint_queue_tail(tail + 1);
+        Ok(())
+    }
+
+    // Mints up to `count` queued entries in FIFO order, each with a generated id.
+    fn process_mint_queue(&mut self, count: u32) -> Result<Vec<TokenId>, Error> {
+        let mint_queue = MintQueue::instance();
+        let head = data::mint_queue_head();
+        let end = core::cmp::min(head + count as u64, data::mint_queue_tail());
+        let mut token_ids = Vec::new();
+        for position in head..end {
+            let (recipient, token_meta) = mint_queue.get(position).unwrap_or_revert();
+            token_ids.append(&mut self.mint(recipient, None, vec![token_meta])?);
+            mint_queue.remove(position);
+        }
+        data::set_mint_queue_head(end);
+        Ok(token_ids)
+    }
+
+    fn mint_for_self(&mut self, token_metas: Vec<Meta>) -> Result<Vec<TokenId>, Error> {
+        let caller = self.get_caller();
+        self.mint(caller, None, token_metas)
+    }
+
+    fn mint_copies_multi(
+        &mut self,
+        recipient: Key,
+        metas_and_counts: Vec<(Meta, u32)>,
+    ) -> Result<Vec<TokenId>, Error> {
+        let mut token_metas = Vec::new();
+        for (token_meta, count) in metas_and_counts {
+            for _ in 0..count {
+                token_metas.push(token_meta.clone());
+            }
+        }
+        self.mint(recipient, None, token_metas)
+    }
+
+    fn mint_with_expiry(
+        &mut self,
+        recipient: Key,
+        token_ids: Option<Vec<TokenId>>,
+        token_metas: Vec<Meta>,
+        expires_at: u64,
+    ) -> Result<Vec<TokenId>, Error> {
+        let block_time: u64 = runtime::get_blocktime().into();
+        if expires_at <= block_time {
+            return Err(Error::WrongArguments);
+        }
+        let token_ids = self.mint(recipient, token_ids, token_metas)?;
+        let expiries_dict = Expiries::instance();
+        for token_id in &token_ids {
+            expiries_dict.set(token_id, expires_at);
+        }
+        Ok(token_ids)
+    }
+
+    fn burn(&mut self, owner: Key, token_ids: Vec<TokenId>) -> Result<(), Error> {
+        let spender = self.get_caller();
+        if spender != owner {
+            for token_id in &token_ids {
+                if !self.is_approved(owner, *token_id, spender) {
+                    return Err(Error::PermissionDenied);
+                }
+            }
+        }
+        self.burn_internal(owner, token_ids)
+    }
+
+    fn burn_internal(&mut self, owner: Key, token_ids: Vec<TokenId>) -> Result<(), Error> {
+        self.burn_tokens(owner, token_ids, true)
+    }
+
+    fn burn_tokens(
+        &mut self,
+        owner: Key,
+        token_ids: Vec<TokenId>,
+        check_min_supply: bool,
+    ) -> Result<(), Error> {
+        if data::paused() {
+            return Err(Error::Paused);
+        }
+
+        let owners_dict = Owners::instance();
+        let owned_tokens_dict = OwnedTokens::instance();
+        let metadata_dict = Metadata::instance();
+        let allowances_dict = Allowances::instance();
+        let token_acl = TokenACL::instance();
+        let mint_times_dict = MintTimes::instance();
+        let minters_dict = Minters::instance();
+        let all_tokens_dict = AllTokens::instance();
+        let last_transfer_dict = LastTransfer::instance();
+        let expiries_dict = Expiries::instance();
+        let burned_tokens_dict = BurnedTokens::instance();
+        let token_templates_dict = TokenTemplates::instance();
+        let token_royalties_dict = TokenRoyalties::instance();
+        let token_fees_dict = TokenFees::instance();
+        let typed_metadata_dict = TypedMetadata::instance();
+        let frozen_tokens_dict = FrozenTokens::instance();
+        let approval_locks_dict = ApprovalLocks::instance();
+        let burned_index = BurnedIndex::instance();
+        let used_values_dict = UsedMetaValues::instance();
+        let unique_key = data::unique_meta_key();
+
+        for token_id in &token_ids {
+            match owners_dict.get(token_id) {
+                Some(owner_of_key) => {
+                    if owner_of_key != owner {
+                        return Err(Error::PermissionDenied);
+                    }
+                }
+                None => {
+                    return Err(Error::TokenIdDoesntExist);
+                }
+            }
+        }
+
+        let burnt_tokens_count: U256 = From::<u64>::from(token_ids.len().try_into().unwrap());
+        let new_total_supply = data::total_supply()
+            .checked_sub(burnt_tokens_count)
+            .unwrap();
+        if let Some(min_supply) = data::min_supply().filter(|_| check_min_supply) {
+            if new_total_supply < min_supply {
+                return Err(Error::MinSupplyViolation);
+            }
+        }
+
+        let burn_meta_events = data::burn_meta_events();
+        let mut token_metas = Vec::new();
+        let mut length = data::total_supply();
+        for token_id in &token_ids {
+            if burn_meta_events {
+                token_metas.push(self.stored_token_meta(*token_id).unwrap_or_default());
+            }
+            self.release_traits(*token_id);
+            owned_tokens_dict.remove_token(&owner, token_id);
+            if let Some(unique_key) = &unique_key {
+                if let Some(value) = metadata_dict
+                    .get(token_id)
+                    .and_then(|meta| meta.get(unique_key).cloned())
+                {
+                    used_values_dict.remove(&value);
+                }
+            }
+            metadata_dict.remove(token_id);
+            owners_dict.remove(token_id);
+            allowances_dict.remove(&owner, token_id);
+            approval_locks_dict.remove(token_id);
+            token_acl.clear(token_id);
+            mint_times_dict.remove(token_id);
+            minters_dict.remove(token_id);
+            all_tokens_dict.remove_token(&length, token_id);
+            last_transfer_dict.remove(token_id);
+            expiries_dict.remove(token_id);
+            if burned_tokens_dict.get(token_id) != Some(true) {
+                burned_tokens_dict.set(token_id, true);
+                burned_index.push(token_id);
+            }
+            token_templates_dict.remove(token_id);
+            token_royalties_dict.remove(token_id);
+            token_fees_dict.remove(token_id);
+            typed_metadata_dict.remove(token_id);
+            frozen_tokens_dict.remove(token_id);
+            length = length - 1;
+        }
+        data::set_total_supply(new_total_supply);
+
+        if burn_meta_events {
+            self.emit(CEP47Event::BurnWithMeta {
+                owner,
+                token_ids,
+                token_metas,
+            });
+        } else {
+            self.emit(CEP47Event::Burn { owner, token_ids });
+        }
+        Ok(())
+    }
+
+    fn burn_if_meta(
+        &mut self,
+        token_id: TokenId,
+        key: String,
+        expected: String,
+    ) -> Result<(), Error> {
+        let owner = self.owner_of(token_id).ok_or(Error::TokenIdDoesntExist)?;
+        let meta = self.stored_token_meta(token_id).unwrap_or_default();
+        if meta.get(&key) != Some(&expected) {
+            return Err(Error::WrongArguments);
+        }
+        self.burn(owner, vec![token_id])
+    }
+
+    fn redeem(&mut self, token_id: TokenId, shipping_ref: String) -> Result<(), Error> {
+        if shipping_ref.len() > MAX_SHIPPING_REF_LENGTH {
+            return Err(Error::WrongArguments);
+        }
+        let owner = self.get_caller();
+        match self.owner_of(token_id) {
+            None => return Err(Error::TokenIdDoesntExist),
+            Some(owner_of_key) if owner_of_key != owner => return Err(Error::PermissionDenied),
+            Some(_) => {}
+        }
+        self.burn_internal(owner, vec![token_id])?;
+        self.emit(CEP47Event::Redeemed {
+            token_id,
+            owner,
+            shipping_ref,
+        });
+        Ok(())
+    }
+
+    // Takedown path: burns regardless of owner or approvals.
+    fn admin_burn(&mut self, token_ids: Vec<TokenId>) -> Result<(), Error> {
+        let owners_dict = Owners::instance();
+        let mut by_owner: BTreeMap<Key, Vec<TokenId>> = BTreeMap::new();
+        for token_id in token_ids {
+            let owner = owners_dict
+                .get(&token_id)
+                .ok_or(Error::TokenIdDoesntExist)?;
+            by_owner.entry(owner).or_default().push(token_id);
+        }
+        for (owner, token_ids) in by_owner {
+            self.burn_internal(owner, token_ids.clone())?;
+            self.emit(CEP47Event::AdminBurn { owner, token_ids });
+        }
+        Ok(())
+    }
+
+    fn recall(&mut self, token_id: TokenId) -> Result<(), Error> {
+        let owner = self.owner_of(token_id).ok_or(Error::TokenIdDoesntExist)?;
+        let minter = self.minter_of(token_id).ok_or(Error::TokenIdDoesntExist)?;
+        let mint_time = self.mint_time(token_id).ok_or(Error::TokenIdDoesntExist)?;
+
+        let block_time: u64 = runtime::get_blocktime().into();
+        if block_time.saturating_sub(mint_time) > data::recall_window() {
+            return Err(Error::RecallWindowExpired);
+        }
+
+        if data::paused() {
+            return Err(Error::Paused);
+        }
+        // An admin recall bypasses the policies that gate holder transfers
+        // (mint lock, cooldown, expiry, freezes and recipient rules).
+        if owner != minter {
+            self.move_tokens(owner, minter, vec![token_id]);
+        }
+
+        self.emit(CEP47Event::Recalled {
+            owner,
+            minter,
+            token_id,
+        });
+        Ok(())
+    }
+
+    fn approve(&mut self, spender: Key, token_ids: Vec<TokenId>) -> Result<(), Error> {
+        let caller = self.get_caller();
+        self.approve_as(caller, spender, token_ids)
+    }
+
+    fn approve_as(
+        &mut self,
+        caller: Key,
+        spender: Key,
+        token_ids: Vec<TokenId>,
+    ) -> Result<(), Error> {
+        if data::paused() {
+            return Err(Error::Paused);
+        }
+        let allowances_dict = Allowances::instance();
+        let max_approvals = data::max_approvals_per_owner();
+        let mut changes = Vec::new();
+        for token_id in &token_ids {
+            match self.owner_of(*token_id) {
+                None => return Err(Error::WrongArguments),
+                Some(owner) if owner != caller => return Err(Error::PermissionDenied),
+                Some(_) => {}
+            }
+            self.check_approvable(caller, spender, *token_id)?;
+            match allowances_dict.get(&caller, token_id) {
+                // Re-approving the current spender skips the write, but still emits the event.
+                Some(old_spender) if old_spender == spender => {}
+                Some(old_spender) => {
+                    allowances_dict.set(&caller, token_id, spender);
+                    changes.push((*token_id, old_spender));
+                }
+                None => {
+                    if let Some(max_approvals) = max_approvals {
+                        if allowances_dict.count(&caller) >= max_approvals {
+                            return Err(Error::ApprovalLimitExceeded);
+                        }
+                    }
+                    allowances_dict.set(&caller, token_id, spender)
+                }
+            }
+        }
+        self.emit(CEP47Event::Approve {
+            owner: caller,
+            spender,
+            token_ids,
+        });
+        for (token_id, old_spender) in changes {
+            self.emit(CEP47Event::ApprovalChanged {
+                owner: caller,
+                token_id,
+                old_spender,
+                new_spender: spender,
+            });
+        }
+        Ok(())
+    }
+
+    fn revoke(&mut self, token_ids: Vec<TokenId>) -> Result<(), Error> {
+        let caller = self.get_caller();
+        for token_id in &token_ids {
+            match self.owner_of(*token_id) {
+                None => return Err(Error::TokenIdDoesntExist),
+                Some(owner) if owner != caller => return Err(Error::PermissionDenied),
+                Some(_) => {}
+            }
+            if self.approval_lock_holder(caller, *token_id).is_some() {
+                return Err(Error::ApprovalLocked);
+            }
+        }
+        let allowances_dict = Allowances::instance();
+        for token_id in &token_ids {
+            allowances_dict.remove(&caller, token_id);
+        }
+        self.emit(CEP47Event::Revoke {
+            owner: caller,
+            token_ids,
+        });
+        Ok(())
+    }
+
+    fn lock_approval(&mut self, token_id: TokenId, until: u64) -> Result<(), Error> {
+        let caller = self.get_caller();
+        let owner = self.owner_of(token_id).ok_or(Error::TokenIdDoesntExist)?;
+        if self.get_approved(owner, token_id) != Some(caller) {
+            return Err(Error::PermissionDenied);
+        }
+        let block_time: u64 = runtime::get_blocktime().into();
+        if until <= block_time {
+            return Err(Error::WrongArguments);
+        }
+        ApprovalLocks::instance().set(&token_id, caller, until);
+        Ok(())
+    }
+
+    fn approval_locked_until(&self, token_id: TokenId) -> Option<u64> {
+        let owner = self.owner_of(token_id)?;
+        self.approval_lock_holder(owner, token_id)?;
+        ApprovalLocks::instance()
+            .get(&token_id)
+            .map(|(_, until)| until)
+    }
+
+    // Returns the spender holding an unexpired lock on the owner's current approval.
+    fn approval_lock_holder(&self, owner: Key, token_id: TokenId) -> Option<Key> {
+        let (spender, until) = ApprovalLocks::instance().get(&token_id)?;
+        let block_time: u64 = runtime::get_blocktime().into();
+        if block_time >= until || self.get_approved(owner, token_id) != Some(spender) {
+            return None;
+        }
+        Some(spender)
+    }
+
+    fn get_approved(&self, owner: Key, token_id: TokenId) -> Option<Key> {
+        Allowances::instance().get(&owner, &token_id)
+    }
+
+    fn has_approval(&self, owner: Key, token_id: TokenId) -> bool {
+        self.get_approved(owner, token_id).is_some()
+    }
+
+    fn total_approvals(&self) -> U256 {
+        data::total_approvals()
+    }
+
+    // Shared by approve_as and delegate_approval so a delegate can't bypass a lock.
+    fn check_approvable(&self, owner: Key, spender: Key, token_id: TokenId) -> Result<(), Error> {
+        // A frozen token can't be moved, so an approval for it would be meaningless.
+        if FrozenTokens::instance().get(&token_id) {
+            return Err(Error::WrongArguments);
+        }
+        if let Some(locked_spender) = self.approval_lock_holder(owner, token_id) {
+            if locked_spender != spender {
+                return Err(Error::ApprovalLocked);
+            }
+        }
+        Ok(())
+    }
+
+    fn delegate_approval(&mut self, token_id: TokenId, new_spender: Key) -> Result<(), Error> {
+        if data::paused() {
+            return Err(Error::Paused);
+        }
+        let caller = self.get_caller();
+        let owner = self.owner_of(token_id).ok_or(Error::TokenIdDoesntExist)?;
+        let allowances_dict = Allowances::instance();
+        if allowances_dict.get(&owner, &token_id) != Some(caller) {
+            return Err(Error::PermissionDenied);
+        }
+        self.check_approvable(owner, new_spender, token_id)?;
+        allowances_dict.set(&owner, &token_id, new_spender);
+        self.emit(CEP47Event::DelegateApproval {
+            owner,
+            spender: caller,
+            new_spender,
+            token_id,
+        });
+        Ok(())
+    }
+
+    fn set_approval_for_all(&mut self, operator: Key, approved: bool) -> Result<(), Error> {
+        if data::paused() {
+            return Err(Error::Paused);
+        }
+        let caller = self.get_caller();
+        if caller == operator {
+            return Err(Error::WrongArguments);
+        }
+        let operators_dict = Operators::instance();
+        if approved {
+            operators_dict.set(&caller, &operator);
+        } else {
+            operators_dict.remove(&caller, &operator);
+        }
+        self.emit(CEP47Event::ApprovalForAll {
+            owner: caller,
+            operator,
+            approved,
+        });
+        Ok(())
+    }
+
+    fn approve_for_all_many(&mut self, operators: Vec<Key>) -> Result<(), Error> {
+        self.set_approval_for_all_many(operators, true)
+    }
+
+    fn revoke_for_all_many(&mut self, operators: Vec<Key>) -> Result<(), Error> {
+        self.set_approval_for_all_many(operators, false)
+    }
+
+    fn set_approval_for_all_many(
+        &mut self,
+        operators: Vec<Key>,
+        approved: bool,
+    ) -> Result<(), Error> {
+        if data::paused() {
+            return Err(Error::Paused);
+        }
+        if operators.len() > MAX_QUERY_BATCH_SIZE {
+            return Err(Error::WrongArguments);
+        }
+        let caller = self.get_caller();
+        if operators.contains(&caller) {
+            return Err(Error::WrongArguments);
+        }
+        let operators_dict = Operators::instance();
+        for operator in &operators {
+            if approved {
+                operators_dict.set(&caller, operator);
+            } else {
+                operators_dict.remove(&caller, operator);
+            }
+        }
+        self.emit(CEP47Event::ApprovalForAllMany {
+            owner: caller,
+            operators,
+            approved,
+        });
+        Ok(())
+    }
+
+    fn is_approved_for_all(&self, owner: Key, operator: Key) -> bool {
+        Operators::instance().get(&owner, &operator)
+    }
+
+    fn are_approved_for_all(&self, pairs: Vec<(Key, Key)>) -> Result<Vec<bool>, Error> {
+        if pairs.len() > MAX_QUERY_BATCH_SIZE {
+            return Err(Error::WrongArguments);
+        }
+        let operators_dict = Operators::instance();
+        Ok(pairs
+            .iter()
+            .map(|(owner, operator)| operators_dict.get(owner, operator))
+            .collect())
+    }
+
+    // Clears an owner's index in bounded steps so rebuild_indices can append
+    // from scratch. Every slot below `end` (or the stored balance, if higher)
+    // is cleared, since a drifted index can hold stale entries past a gap.
+    // Returns true once the owner's balance is back at zero; until then only
+    // the same owner can be reset.
+    fn reset_owner_index(&mut self, owner: Key, end: U256) -> Result<bool, Error> {
+        let mut index = match data::index_reset_cursor() {
+            Some((pending, index)) if pending == owner => index,
+            Some(_) => return Err(Error::WrongArguments),
+            None => U256::zero(),
+        };
+        let owned_tokens_dict = OwnedTokens::instance();
+        let end = core::cmp::max(end, owned_tokens_dict.get_balances(&owner));
+        for _ in 0..MAX_QUERY_BATCH_SIZE {
+            if index >= end {
+                owned_tokens_dict.set_balances(&owner, U256::zero());
+                Holders::instance().remove(&owner);
+                data::set_index_reset_cursor(None);
+                return Ok(true);
+            }
+            owned_tokens_dict.clear_index(&owner, &index);
+            index += U256::one();
+        }
+        data::set_index_reset_cursor(Some((owner, index)));
+        Ok(false)
+    }
+
+    // Appends each token to its owner's index from Owners ground truth; ids
+    // already indexed are skipped, so batches can be split and replayed.
+    fn rebuild_indices(&mut self, token_ids: Vec<TokenId>) -> Result<(), Error> {
+        if token_ids.len() > MAX_QUERY_BATCH_SIZE {
+            return Err(Error::WrongArguments);
+        }
+        if data::index_reset_cursor().is_some() {
+            return Err(Error::WrongArguments);
+        }
+        let owners_dict = Owners::instance();
+        let owned_tokens_dict = OwnedTokens::instance();
+        for token_id in &token_ids {
+            let owner = owners_dict.get(token_id).ok_or(Error::TokenIdDoesntExist)?;
+            if !owned_tokens_dict.is_indexed(&owner, token_id) {
+                owned_tokens_dict.set_token(&owner, token_id);
+            }
+        }
+        Ok(())
+    }
+
+    fn transfer(&mut self, recipient: Key, token_ids: Vec<TokenId>) -> Result<(), Error> {
+        self.transfer_from(self.get_caller(), recipient, token_ids)
+    }
+
+    fn transfer_from(
+        &mut self,
+        owner: Key,
+        recipient: Key,
+        token_ids: Vec<TokenId>,
+    ) -> Result<(), Error> {
+        if data::transfers_paused() {
+            return Err(Error::Paused);
+        }
+
+        let spender = self.get_caller();
+
+        if owner != spender {
+            let allowances_dict = Allowances::instance();
+            for token_id in &token_ids {
+                if !self.can_move(owner, *token_id, spender) {
+                    return Err(Error::PermissionDenied);
+                }
+                // A transfer back to the owner is a no-op and keeps the approval.
+                if owner != recipient {
+                    allowances_dict.remove(&owner, token_id);
+                }
+            }
+        }
+        self.transfer_from_internal(owner, recipient, token_ids)
+    }
+
+    fn transfer_and_approve(
+        &mut self,
+        recipient: Key,
+        token_id: TokenId,
+        operator: Key,
+    ) -> Result<(), Error> {
+        // The approval is granted on behalf of the new owner, so only the recipient may call this.
+        if self.get_caller() != recipient {
+            return Err(Error::PermissionDenied);
+        }
+        let owner = self.owner_of(token_id).ok_or(Error::TokenIdDoesntExist)?;
+        self.transfer_from(owner, recipient, vec![token_id])?;
+        self.approve(operator, vec![token_id])
+    }
+
+    // Writes the recipient's approval on their behalf, so the caller must
+    // already be one of the recipient's operators as well as able to move
+    // the token.
+    fn transfer_keep_operator(
+        &mut self,
+        recipient: Key,
+        token_id: TokenId,
+        operator: Key,
+    ) -> Result<(), Error> {
+        if !self.is_approved_for_all(recipient, self.get_caller()) {
+            return Err(Error::PermissionDenied);
+        }
+        let owner = self.owner_of(token_id).ok_or(Error::TokenIdDoesntExist)?;
+        self.transfer_from(owner, recipient, vec![token_id])?;
+        self.approve_as(recipient, operator, vec![token_id])
+    }
+
+    fn safe_transfer_from(
+        &mut self,
+        owner: Key,
+        recipient: Key,
+        token_ids: Vec<TokenId>,
+    ) -> Result<(), Error> {
+        let operator = self.get_caller();
+        self.transfer_from(owner, recipient, token_ids.clone())?;
+
+        if let Key::Hash(package_hash) = recipient {
+            let ack: String = runtime::call_versioned_contract(
+                ContractPackageHash::new(package_hash),
+                None,
+                ON_CEP47_RECEIVED_ENTRY_POINT,
+                runtime_args! {
+                    "operator" => operator,
+                    "from" => owner,
+                    "token_ids" => token_ids
+                },
+            );
+            if ack != ON_CEP47_RECEIVED_ENTRY_POINT {
+                return Err(Error::RecipientRejected);
+            }
+        }
+        Ok(())
+    }
+
+    fn transfer_from_internal(
+        &mut self,
+        owner: Key,
+        recipient: Key,
+        token_ids: Vec<TokenId>,
+    ) -> Result<(), Error> {
+        if data::paused() {
+            return Err(Error::Paused);
+        }
+        if !self.is_recipient_allowed(&recipient) {
+            return Err(Error::RecipientNotAllowed);
+        }
+
+        self.validate_transferable(owner, &token_ids)?;
+
+        // Re-adding a token to its own owner would reorder the owner's index.
+        if owner == recipient {
+            return Ok(());
+        }
+        self.move_tokens(owner, recipient, token_ids);
+        Ok(())
+    }
+
+    fn move_tokens(&mut self, owner: Key, recipient: Key, token_ids: Vec<TokenId>) {
+        let owners_dict = Owners::instance();
+        let owned_tokens_dict = OwnedTokens::instance();
+        let allowances_dict = Allowances::instance();
+        let token_acl = TokenACL::instance();
+        let approval_locks_dict = ApprovalLocks::instance();
+        let last_transfer_dict = LastTransfer::instance();
+        let cooldown = data::transfer_cooldown();
+        let block_time: u64 = runtime::get_blocktime().into();
+
+        owned_tokens_dict.remove_tokens(&owner, &token_ids);
+        owned_tokens_dict.set_tokens(&recipient, &token_ids);
+        let stamp_on_transfer = data::stamp_on_transfer();
+        let metadata_dict = Metadata::instance();
+        let meta_versions_dict = MetaVersions::instance();
+        let trait_counts_dict = TraitCounts::instance();
+        for token_id in &token_ids {
+            if stamp_on_transfer {
+                let mut meta = self.stored_token_meta(*token_id).unwrap_or_default();
+                meta.insert(String::from("last_transfer"), block_time.to_string());
+                self.release_traits(*token_id);
+                trait_counts_dict.add(&meta, 1);
+                metadata_dict.set(token_id, meta);
+                meta_versions_dict.increment(token_id);
+            }
+            owners_dict.set(token_id, recipient);
+            allowances_dict.remove(&owner, token_id);
+            approval_locks_dict.remove(token_id);
+            token_acl.clear(token_id);
+            if cooldown > 0 {
+                last_transfer_dict.set(token_id, block_time);
+            }
+        }
+
+        self.emit(CEP47Event::Transfer {
+            sender: owner,
+            recipient,
+            token_ids,
+        });
+    }
+
+    fn validate_transferable(&self, owner: Key, token_ids: &[TokenId]) -> Result<(), Error> {
+        let owners_dict = Owners::instance();
+        let last_transfer_dict = LastTransfer::instance();
+        let mint_times_dict = MintTimes::instance();
+        let frozen_tokens_dict = FrozenTokens::instance();
+        let cooldown = data::transfer_cooldown();
+        let mint_lock_period = data::mint_lock_period();
+        let block_time: u64 = runtime::get_blocktime().into();
+
+        for token_id in token_ids {
+            match owners_dict.get(token_id) {
+                Some(owner_of_key) => {
+                    if owner_of_key != owner {
+                        return Err(Error::PermissionDenied);
+                    }
+                }
+                None => {
+                    return Err(Error::TokenIdDoesntExist);
+                }
+            }
+            if self.is_expired(*token_id) {
+                return Err(Error::TokenExpired);
+            }
+            if frozen_tokens_dict.get(token_id) {
+                return Err(Error::TokenFrozen);
+            }
+            if mint_lock_period > 0 {
+                if let Some(mint_time) = mint_times_dict.get(token_id) {
+                    if block_time.saturating_sub(mint_time) < mint_lock_period {
+                        return Err(Error::MintLockActive);
+                    }
+                }
+            }
+            if cooldown > 0 {
+                if let Some(last_transfer) = last_transfer_dict.get(token_id) {
+                    if block_time.saturating_sub(last_transfer) < cooldown {
+                        return Err(Error::TransferCooldown);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn can_transfer_all(&self, spender: Key, owner: Key, token_ids: Vec<TokenId>) -> bool {
+        if token_ids.len() > MAX_QUERY_BATCH_SIZE || data::paused() || data::transfers_paused() {
+            return false;
+        }
+        if spender != owner
+            && !token_ids
+                .iter()
+                .all(|token_id| self.can_move(owner, *token_id, spender))
+        {
+            return false;
+        }
+        self.validate_transferable(owner, &token_ids).is_ok()
+    }
+
+    // Mirrors the checks of transfer_from, in the same order.
+    fn simulate_transfer(
+        &self,
+        owner: Key,
+        recipient: Key,
+        token_ids: Vec<TokenId>,
+    ) -> Result<(), Error> {
+        if data::transfers_paused() {
+            return Err(Error::Paused);
+        }
+        let spender = self.get_caller();
+        if owner != spender {
+            for token_id in &token_ids {
+                if !self.can_move(owner, *token_id, spender) {
+                    return Err(Error::PermissionDenied);
+                }
+            }
+        }
+        if data::paused() {
+            return Err(Error::Paused);
+        }
+        if !self.is_recipient_allowed(&recipient) {
+            return Err(Error::RecipientNotAllowed);
+        }
+        self.validate_transferable(owner, &token_ids)
+    }
+
+    fn recorded_simulate_transfer(
+        &mut self,
+        owner: Key,
+        recipient: Key,
+        token_ids: Vec<TokenId>,
+    ) -> Result<(), Error> {
+        let context = format!(
+            "simulate_transfer of token_ids {} from {} to {}",
+            join_token_ids(&token_ids),
+            owner,
+            recipient
+        );
+        let result = self.simulate_transfer(owner, recipient, token_ids);
+        self.record_check(context, result)
+    }
+
+    fn recorded_validate_mint(
+        &mut self,
+        recipient: Key,
+        token_ids: Option<Vec<TokenId>>,
+        token_metas: Vec<Meta>,
+    ) -> Result<(), Error> {
+        let context = match &token_ids {
+            Some(token_ids) => format!("mint of token_ids {}", join_token_ids(token_ids)),
+            None => format!("mint of {} generated token_ids", token_metas.len()),
+        };
+        let result = self.validate_mint(recipient, token_ids, token_metas);
+        self.record_check(context, result)
+    }
+
+    fn can_move(&self, owner: Key, token_id: TokenId, spender: Key) -> bool {
+        self.is_approved(owner, token_id, spender)
+            || self.has_token_role(token_id, String::from(TRANSFERER_ROLE), spender)
+    }
+
+    fn has_token_role(&self, token_id: TokenId, role: String, account: Key) -> bool {
+        match self.owner_of(token_id) {
+            Some(_) => TokenACL::instance()
+                .get(&token_id, &role)
+                .contains(&account),
+            None => false,
+        }
+    }
+
+    fn grant_token_role(
+        &mut self,
+        token_id: TokenId,
+        role: String,
+        account: Key,
+    ) -> Result<(), Error> {
+        self.assert_caller_owns(token_id)?;
+        let acl = TokenACL::instance();
+        let mut members = acl.get(&token_id, &role);
+        if !members.contains(&account) {
+            members.push(account);
+            acl.set(&token_id, &role, members);
+        }
+        Ok(())
+    }
+
+    fn revoke_token_role(
+        &mut self,
+        token_id: TokenId,
+        role: String,
+        account: Key,
+    ) -> Result<(), Error> {
+        self.assert_caller_owns(token_id)?;
+        let acl = TokenACL::instance();
+        let mut members = acl.get(&token_id, &role);
+        members.retain(|member| member != &account);
+        acl.set(&token_id, &role, members);
+        Ok(())
+    }
+
+    fn assert_caller_owns(&self, token_id: TokenId) -> Result<Key, Error> {
+        let owner = self.owner_of(token_id).ok_or(Error::TokenIdDoesntExist)?;
+        if owner != self.get_caller() {
+            return Err(Error::PermissionDenied);
+        }
+        Ok(owner)
+    }
+
+    fn is_approved(&self, owner: Key, token_id: TokenId, spender: Key) -> bool {
+        if self.is_approved_for_all(owner, spender) {
+            return true;
+        }
+        let allowances_dict = Allowances::instance();
         if let Some(spender_of) = allowances_dict.get(&owner, &token_id) {
             if spender_of == spender {
                 return true;
@@ -276,7 +2618,35 @@ pub trait CEP47<Storage: ContractStorage>: ContractContext<Storage> {
         false
     }
 
+    fn events_enabled(&self) -> bool {
+        data::events_enabled()
+    }
+
+    fn set_events_enabled(&mut self, enabled: bool) {
+        let old_enabled = data::events_enabled();
+        data::set_events_enabled(enabled);
+        // Bypasses `emit` so indexers also see the update that turns events off.
+        if old_enabled || enabled {
+            data::emit(&CEP47Event::ConfigUpdate {
+                field: String::from(data::EVENTS_ENABLED),
+                old_value: old_enabled.to_string(),
+                new_value: enabled.to_string(),
+            });
+        }
+    }
+
     fn emit(&mut self, event: CEP47Event) {
-        data::emit(&event);
+        if data::events_enabled() {
+            data::emit(&event);
+        }
     }
 }
+
+fn option_to_string<T: ToString>(value: Option<T>) -> String {
+    value.map(|value| value.to_string()).unwrap_or_default()
+}
+
+fn join_token_ids(token_ids: &[TokenId]) -> String {
+    let token_ids: Vec<String> = token_ids.iter().map(|id| id.to_string()).collect();
+    token_ids.join(",")
+}
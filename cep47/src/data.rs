@@ -4,13 +4,22 @@ use alloc::{
     vec::Vec,
 };
 use casper_contract::{
-    contract_api::{runtime::get_call_stack, storage},
+    contract_api::{
+        runtime::{self, get_call_stack},
+        storage,
+    },
     unwrap_or_revert::UnwrapOrRevert,
 };
-use casper_types::{system::CallStackElement, ContractPackageHash, Key, URef, U256};
-use contract_utils::{get_key, key_and_value_to_str, key_to_str, set_key, Dict};
+use casper_types::{
+    bytesrepr::{Bytes, ToBytes},
+    system::CallStackElement,
+    ContractHash, ContractPackageHash, Key, PublicKey, URef, U256,
+};
+use contract_utils::{
+    get_key, key_and_value_to_str, key_to_str, set_key, Dict, ADMIN_DICTIONARY_NAMES,
+};
 
-use crate::{event::CEP47Event, Meta, TokenId};
+use crate::{event::CEP47Event, Meta, TokenId, TypedMeta};
 
 const BALANCES_DICT: &str = "balances";
 pub const ALLOWANCES_DICT: &str = "allowances";
@@ -18,12 +27,146 @@ const METADATA_DICT: &str = "metadata";
 const OWNERS_DICT: &str = "owners";
 const OWNED_TOKENS_BY_INDEX_DICT: &str = "owned_tokens_by_index";
 const OWNED_INDEXES_BY_TOKEN_DICT: &str = "owned_indexes_by_token";
+const MINT_TIMES_DICT: &str = "mint_times";
+const MINTERS_DICT: &str = "minters";
+pub const OPERATORS_DICT: &str = "operators";
+const MINTED_COUNT_DICT: &str = "minted_count";
+const ALL_TOKENS_BY_INDEX_DICT: &str = "all_tokens_by_index";
+const ALL_INDEXES_BY_TOKEN_DICT: &str = "all_indexes_by_token";
+const LAST_TRANSFER_DICT: &str = "last_transfer";
+const EXPIRIES_DICT: &str = "expiries";
+const BURNED_TOKENS_DICT: &str = "burned_tokens";
+const META_TEMPLATES_DICT: &str = "meta_templates";
+const TOKEN_TEMPLATES_DICT: &str = "token_templates";
+const SUPPORTED_INTERFACES_DICT: &str = "supported_interfaces";
+const RECENT_EVENTS_DICT: &str = "recent_events";
+const USED_META_NONCES_DICT: &str = "used_meta_nonces";
+const TOKEN_ROYALTIES_DICT: &str = "token_royalties";
+const TYPED_METADATA_DICT: &str = "typed_metadata";
+const MINT_LOCKED_IDS_DICT: &str = "mint_locked_ids";
+const BURNED_TOKENS_BY_INDEX_DICT: &str = "burned_tokens_by_index";
+const USED_META_VALUES_DICT: &str = "used_meta_values";
+const FROZEN_TOKENS_DICT: &str = "frozen_tokens";
+const APPROVAL_LOCKS_DICT: &str = "approval_locks";
+const MINT_QUEUE_DICT: &str = "mint_queue";
+const APPROVAL_COUNTS_DICT: &str = "approval_counts";
+const HOLDERS_BY_INDEX_DICT: &str = "holders_by_index";
+const HOLDER_INDEXES_DICT: &str = "holder_indexes";
+const TOKEN_ACL_DICT: &str = "token_acl";
+const USED_MINT_KEYS_DICT: &str = "used_mint_keys";
+const VIEW_COUNTS_DICT: &str = "view_counts";
+const TRANSFER_ALLOWLIST_DICT: &str = "transfer_allowlist";
+const TOKEN_FEES_DICT: &str = "token_fees";
+const META_VERSIONS_DICT: &str = "meta_versions";
+const TRAIT_COUNTS_DICT: &str = "trait_counts";
+const TEMPLATE_USAGE_DICT: &str = "template_usage";
 const CONTRACT_PACKAGE_HASH: &str = "contract_package_hash";
 
 pub const NAME: &str = "name";
 pub const META: &str = "meta";
 pub const SYMBOL: &str = "symbol";
 pub const TOTAL_SUPPLY: &str = "total_supply";
+pub const TOTAL_SUPPLY_CAP: &str = "total_supply_cap";
+pub const GENESIS_SUPPLY: &str = "genesis_supply";
+pub const MAX_MINTS_PER_BLOCK: &str = "max_mints_per_block";
+pub const MAX_APPROVALS_PER_OWNER: &str = "max_approvals_per_owner";
+pub const MINTS_IN_BLOCK: &str = "mints_in_block";
+pub const LAST_MINT_BLOCK_TIME: &str = "last_mint_block_time";
+pub const RECALL_WINDOW: &str = "recall_window";
+pub const NONCE: &str = "nonce";
+pub const TOKEN_ID_SALT: &str = "token_id_salt";
+pub const TRANSFER_COOLDOWN: &str = "transfer_cooldown";
+pub const DYNAMIC_META_CONTRACT: &str = "dynamic_meta_contract";
+pub const RECIPIENT_POLICY: &str = "recipient_policy";
+pub const EVENT_SCHEMA_VERSION: &str = "event_schema_version";
+pub const MIN_SUPPLY: &str = "min_supply";
+pub const METADATA_DELTA_EVENTS: &str = "metadata_delta_events";
+pub const BURN_META_EVENTS: &str = "burn_meta_events";
+pub const DEBUG_ERRORS: &str = "debug_errors";
+pub const LAST_ERROR: &str = "last_error";
+pub const STAMP_ON_TRANSFER: &str = "stamp_on_transfer";
+pub const TRANSFER_RESTRICTED: &str = "transfer_restricted";
+pub const NOTIFY_ON_MINT: &str = "notify_on_mint";
+pub const MINT_LOCK_PERIOD: &str = "mint_lock_period";
+pub const IMMUTABLE_METADATA: &str = "immutable_metadata";
+pub const REQUIRE_EXPLICIT_IDS: &str = "require_explicit_ids";
+pub const EVENTS_ENABLED: &str = "events_enabled";
+pub const MAX_META_BYTES: &str = "max_meta_bytes";
+pub const PAUSED: &str = "paused";
+pub const TRANSFERS_PAUSED: &str = "transfers_paused";
+pub const RECENT_EVENTS_CAPACITY: &str = "recent_events_capacity";
+pub const RECENT_EVENTS_COUNT: &str = "recent_events_count";
+pub const CREATOR: &str = "creator";
+pub const META_RESOLVER_VERSION: &str = "meta_resolver_version";
+pub const MINT_QUEUE_HEAD: &str = "mint_queue_head";
+pub const MINT_QUEUE_TAIL: &str = "mint_queue_tail";
+pub const META_SIGNER: &str = "meta_signer";
+pub const TRANSFER_FEE_BPS: &str = "transfer_fee_bps";
+pub const FEE_TREASURY: &str = "fee_treasury";
+pub const TOTAL_APPROVALS: &str = "total_approvals";
+pub const LAST_MINT_TIME: &str = "last_mint_time";
+pub const BURNED_COUNT: &str = "burned_count";
+pub const HOLDER_COUNT: &str = "holder_count";
+pub const INDEX_RESET_CURSOR: &str = "index_reset_cursor";
+pub const UNIQUE_META_KEY: &str = "unique_meta_key";
+pub const VIEW_RECORDER: &str = "view_recorder";
+pub const DEFAULT_META: &str = "default_meta";
+
+#[repr(u8)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum RecipientPolicy {
+    AllowAll = 0,
+    AccountsOnly = 1,
+    ContractsOnly = 2,
+}
+
+impl RecipientPolicy {
+    pub fn from_u8(value: u8) -> Option<RecipientPolicy> {
+        match value {
+            0 => Some(RecipientPolicy::AllowAll),
+            1 => Some(RecipientPolicy::AccountsOnly),
+            2 => Some(RecipientPolicy::ContractsOnly),
+            _ => None,
+        }
+    }
+
+    pub fn allows(&self, recipient: &Key) -> bool {
+        match self {
+            RecipientPolicy::AllowAll => true,
+            RecipientPolicy::AccountsOnly => matches!(recipient, Key::Account(_)),
+            RecipientPolicy::ContractsOnly => matches!(recipient, Key::Hash(_)),
+        }
+    }
+}
+
+#[repr(u8)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum MetaValueType {
+    String = 0,
+    Number = 1,
+    Bool = 2,
+}
+
+impl MetaValueType {
+    pub fn from_u8(value: u8) -> Option<MetaValueType> {
+        match value {
+            0 => Some(MetaValueType::String),
+            1 => Some(MetaValueType::Number),
+            2 => Some(MetaValueType::Bool),
+            _ => None,
+        }
+    }
+
+    pub fn accepts(&self, value: &str) -> bool {
+        match self {
+            MetaValueType::String => true,
+            MetaValueType::Number => value
+                .parse::<f64>()
+                .map_or(false, |number| number.is_finite()),
+            MetaValueType::Bool => value == "true" || value == "false",
+        }
+    }
+}
 
 pub struct Owners {
     dict: Dict,
@@ -81,207 +224,1655 @@ impl Metadata {
     }
 }
 
-pub struct OwnedTokens {
-    tokens_dict: Dict,
-    indexes_dict: Dict,
-    balances_dict: Dict,
+// Role members are keyed by (token_id, role). Each token also keeps the list
+// of roles it has granted, so a transfer or burn can clear them all.
+pub struct TokenACL {
+    dict: Dict,
 }
 
-impl OwnedTokens {
-    pub fn instance() -> OwnedTokens {
-        OwnedTokens {
-            tokens_dict: Dict::instance(OWNED_TOKENS_BY_INDEX_DICT),
-            indexes_dict: Dict::instance(OWNED_INDEXES_BY_TOKEN_DICT),
-            balances_dict: Dict::instance(BALANCES_DICT),
+impl TokenACL {
+    pub fn instance() -> TokenACL {
+        TokenACL {
+            dict: Dict::instance(TOKEN_ACL_DICT),
         }
     }
 
     pub fn init() {
-        Dict::init(OWNED_TOKENS_BY_INDEX_DICT);
-        Dict::init(OWNED_INDEXES_BY_TOKEN_DICT);
-        Dict::init(BALANCES_DICT);
+        Dict::init(TOKEN_ACL_DICT)
     }
 
-    pub fn get_token_by_index(&self, owner: &Key, index: &U256) -> Option<TokenId> {
-        self.tokens_dict.get(&key_and_value_to_str(owner, index))
+    fn acl_key(token_id: &TokenId, role: &str) -> String {
+        let bytes = (*token_id, String::from(role))
+            .to_bytes()
+            .unwrap_or_revert();
+        hex::encode(runtime::blake2b(bytes))
     }
 
-    pub fn get_index_by_token(&self, owner: &Key, value: &TokenId) -> Option<U256> {
-        self.indexes_dict.get(&key_and_value_to_str(owner, value))
+    fn roles(&self, token_id: &TokenId) -> Vec<String> {
+        self.dict.get(&token_id.to_string()).unwrap_or_default()
     }
 
-    pub fn get_balances(&self, owner: &Key) -> U256 {
-        self.balances_dict
-            .get(&key_to_str(owner))
+    pub fn get(&self, token_id: &TokenId, role: &str) -> Vec<Key> {
+        self.dict
+            .get(&Self::acl_key(token_id, role))
             .unwrap_or_default()
     }
 
-    pub fn set_balances(&self, owner: &Key, value: U256) {
-        self.balances_dict.set(&key_to_str(owner), value);
+    pub fn set(&self, token_id: &TokenId, role: &str, members: Vec<Key>) {
+        let mut roles = self.roles(token_id);
+        if !roles.iter().any(|granted| granted == role) {
+            roles.push(String::from(role));
+            self.dict.set(&token_id.to_string(), roles);
+        }
+        self.dict.set(&Self::acl_key(token_id, role), members);
     }
 
-    pub fn set_token(&self, owner: &Key, value: &TokenId) {
-        let length = self.get_balances(owner);
-        self.indexes_dict
-            .set(&key_and_value_to_str(owner, value), length);
-        self.tokens_dict
-            .set(&key_and_value_to_str(owner, &length), *value);
-        self.set_balances(owner, length + 1);
+    pub fn clear(&self, token_id: &TokenId) {
+        let roles = self.roles(token_id);
+        if roles.is_empty() {
+            return;
+        }
+        for role in &roles {
+            self.dict.remove::<Vec<Key>>(&Self::acl_key(token_id, role));
+        }
+        self.dict.remove::<Vec<String>>(&token_id.to_string());
+    }
+}
+
+pub struct UsedMetaValues {
+    dict: Dict,
+}
+
+impl UsedMetaValues {
+    pub fn instance() -> UsedMetaValues {
+        UsedMetaValues {
+            dict: Dict::instance(USED_META_VALUES_DICT),
+        }
     }
 
-    pub fn remove_token(&self, owner: &Key, value: &TokenId) {
-        let length = self.get_balances(owner);
-        let index = self.get_index_by_token(owner, value).unwrap_or_revert();
-        match length.cmp(&(index + 1)) {
-            core::cmp::Ordering::Equal => {
-                self.tokens_dict
-                    .remove::<TokenId>(&key_and_value_to_str(owner, &(length - 1)));
-                self.set_balances(owner, length - 1);
-            }
-            core::cmp::Ordering::Greater => {
-                let last = self.get_token_by_index(owner, &(length - 1));
-                self.indexes_dict.set(
-                    &key_and_value_to_str(owner, &last.unwrap_or_revert()),
-                    index,
-                );
-                self.tokens_dict.set(
-                    &key_and_value_to_str(owner, &index),
-                    last.unwrap_or_revert(),
-                );
-                self.tokens_dict
-                    .remove::<TokenId>(&key_and_value_to_str(owner, &(length - 1)));
-                self.set_balances(owner, length - 1);
-            }
-            core::cmp::Ordering::Less => {}
+    pub fn init() {
+        Dict::init(USED_META_VALUES_DICT)
+    }
+
+    // Values can be longer than a dictionary key allows, so they are stored by hash.
+    fn value_key(value: &str) -> String {
+        hex::encode(runtime::blake2b(value.to_bytes().unwrap_or_revert()))
+    }
+
+    pub fn get(&self, value: &str) -> Option<TokenId> {
+        self.dict.get(&Self::value_key(value))
+    }
+
+    pub fn set(&self, value: &str, token_id: &TokenId) {
+        self.dict.set(&Self::value_key(value), *token_id);
+    }
+
+    pub fn remove(&self, value: &str) {
+        self.dict.remove::<TokenId>(&Self::value_key(value));
+    }
+}
+
+pub struct BurnedIndex {
+    dict: Dict,
+}
+
+impl BurnedIndex {
+    pub fn instance() -> BurnedIndex {
+        BurnedIndex {
+            dict: Dict::instance(BURNED_TOKENS_BY_INDEX_DICT),
         }
-        self.indexes_dict
-            .remove::<U256>(&key_and_value_to_str(owner, value));
+    }
+
+    pub fn init() {
+        Dict::init(BURNED_TOKENS_BY_INDEX_DICT)
+    }
+
+    pub fn get(&self, index: &U256) -> Option<TokenId> {
+        self.dict.get(&index.to_string())
+    }
+
+    pub fn push(&self, token_id: &TokenId) {
+        let count = burned_count();
+        self.dict.set(&count.to_string(), *token_id);
+        set_burned_count(count + 1);
     }
 }
 
-pub struct Allowances {
+pub struct MintLockedIds {
     dict: Dict,
 }
 
-impl Allowances {
-    pub fn instance() -> Allowances {
-        Allowances {
-            dict: Dict::instance(ALLOWANCES_DICT),
+impl MintLockedIds {
+    pub fn instance() -> MintLockedIds {
+        MintLockedIds {
+            dict: Dict::instance(MINT_LOCKED_IDS_DICT),
         }
     }
 
     pub fn init() {
-        Dict::init(ALLOWANCES_DICT)
+        Dict::init(MINT_LOCKED_IDS_DICT)
     }
 
-    pub fn get(&self, owner: &Key, token_id: &TokenId) -> Option<Key> {
-        self.dict.get(&key_and_value_to_str::<String>(
-            owner,
-            &token_id.to_string(),
-        ))
+    pub fn get(&self, key: &TokenId) -> bool {
+        self.dict.get(&key.to_string()).unwrap_or_default()
     }
 
-    pub fn set(&self, owner: &Key, token_id: &TokenId, value: Key) {
-        self.dict.set(
-            &key_and_value_to_str::<String>(owner, &token_id.to_string()),
-            value,
-        );
+    pub fn set(&self, key: &TokenId) {
+        self.dict.set(&key.to_string(), true);
     }
 
-    pub fn remove(&self, owner: &Key, token_id: &TokenId) {
-        self.dict.remove::<Key>(&key_and_value_to_str::<String>(
-            owner,
-            &token_id.to_string(),
-        ));
+    pub fn remove(&self, key: &TokenId) {
+        self.dict.remove::<bool>(&key.to_string());
     }
 }
 
-pub fn name() -> String {
-    get_key(NAME).unwrap_or_revert()
+pub struct FrozenTokens {
+    dict: Dict,
 }
 
-pub fn set_name(name: String) {
-    set_key(NAME, name);
+impl FrozenTokens {
+    pub fn instance() -> FrozenTokens {
+        FrozenTokens {
+            dict: Dict::instance(FROZEN_TOKENS_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(FROZEN_TOKENS_DICT)
+    }
+
+    pub fn get(&self, key: &TokenId) -> bool {
+        self.dict.get(&key.to_string()).unwrap_or_default()
+    }
+
+    pub fn set(&self, key: &TokenId) {
+        self.dict.set(&key.to_string(), true);
+    }
+
+    pub fn remove(&self, key: &TokenId) {
+        self.dict.remove::<bool>(&key.to_string());
+    }
 }
 
-pub fn symbol() -> String {
-    get_key(SYMBOL).unwrap_or_revert()
+pub struct TokenFees {
+    dict: Dict,
 }
 
-pub fn set_symbol(symbol: String) {
-    set_key(SYMBOL, symbol);
+impl TokenFees {
+    pub fn instance() -> TokenFees {
+        TokenFees {
+            dict: Dict::instance(TOKEN_FEES_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(TOKEN_FEES_DICT)
+    }
+
+    pub fn get(&self, token_id: &TokenId) -> Option<u16> {
+        self.dict.get(&token_id.to_string())
+    }
+
+    pub fn set(&self, token_id: &TokenId, bps: u16) {
+        self.dict.set(&token_id.to_string(), bps);
+    }
+
+    pub fn remove(&self, token_id: &TokenId) {
+        self.dict.remove::<u16>(&token_id.to_string());
+    }
 }
 
-pub fn meta() -> Meta {
-    get_key(META).unwrap_or_revert()
+pub struct TransferAllowlist {
+    dict: Dict,
 }
 
-pub fn set_meta(meta: Meta) {
-    set_key(META, meta);
+impl TransferAllowlist {
+    pub fn instance() -> TransferAllowlist {
+        TransferAllowlist {
+            dict: Dict::instance(TRANSFER_ALLOWLIST_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(TRANSFER_ALLOWLIST_DICT)
+    }
+
+    pub fn get(&self, account: &Key) -> bool {
+        self.dict.get(&key_to_str(account)).unwrap_or_default()
+    }
+
+    pub fn set(&self, account: &Key) {
+        self.dict.set(&key_to_str(account), true);
+    }
+
+    pub fn remove(&self, account: &Key) {
+        self.dict.remove::<bool>(&key_to_str(account));
+    }
 }
 
-pub fn total_supply() -> U256 {
-    get_key(TOTAL_SUPPLY).unwrap_or_default()
+pub struct ApprovalLocks {
+    dict: Dict,
 }
 
-pub fn set_total_supply(total_supply: U256) {
-    set_key(TOTAL_SUPPLY, total_supply);
+impl ApprovalLocks {
+    pub fn instance() -> ApprovalLocks {
+        ApprovalLocks {
+            dict: Dict::instance(APPROVAL_LOCKS_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(APPROVAL_LOCKS_DICT)
+    }
+
+    pub fn get(&self, key: &TokenId) -> Option<(Key, u64)> {
+        self.dict.get(&key.to_string())
+    }
+
+    pub fn set(&self, key: &TokenId, spender: Key, until: u64) {
+        self.dict.set(&key.to_string(), (spender, until));
+    }
+
+    pub fn remove(&self, key: &TokenId) {
+        self.dict.remove::<(Key, u64)>(&key.to_string());
+    }
 }
 
-pub fn contract_package_hash() -> ContractPackageHash {
-    let call_stacks = get_call_stack();
-    let last_entry = call_stacks.last().unwrap_or_revert();
-    let package_hash: Option<ContractPackageHash> = match last_entry {
-        CallStackElement::StoredContract {
-            contract_package_hash,
-            contract_hash: _,
-        } => Some(*contract_package_hash),
-        _ => None,
-    };
-    package_hash.unwrap_or_revert()
+pub struct TypedMetadata {
+    dict: Dict,
 }
 
-pub fn emit(event: &CEP47Event) {
-    let mut events = Vec::new();
-    let package = contract_package_hash();
-    match event {
-        CEP47Event::Mint {
-            recipient,
-            token_ids,
-        } => {
-            for token_id in token_ids {
-                let mut param = BTreeMap::new();
-                param.insert(CONTRACT_PACKAGE_HASH, package.to_string());
-                param.insert("event_type", "cep47_mint_one".to_string());
-                param.insert("recipient", recipient.to_string());
-                param.insert("token_id", token_id.to_string());
-                events.push(param);
-            }
+impl TypedMetadata {
+    pub fn instance() -> TypedMetadata {
+        TypedMetadata {
+            dict: Dict::instance(TYPED_METADATA_DICT),
         }
-        CEP47Event::Burn { owner, token_ids } => {
-            for token_id in token_ids {
-                let mut param = BTreeMap::new();
-                param.insert(CONTRACT_PACKAGE_HASH, package.to_string());
-                param.insert("event_type", "cep47_burn_one".to_string());
-                param.insert("owner", owner.to_string());
-                param.insert("token_id", token_id.to_string());
-                events.push(param);
-            }
+    }
+
+    pub fn init() {
+        Dict::init(TYPED_METADATA_DICT)
+    }
+
+    pub fn get(&self, key: &TokenId) -> Option<TypedMeta> {
+        self.dict.get(&key.to_string())
+    }
+
+    pub fn set(&self, key: &TokenId, value: TypedMeta) {
+        self.dict.set(&key.to_string(), value);
+    }
+
+    pub fn remove(&self, key: &TokenId) {
+        self.dict.remove::<TypedMeta>(&key.to_string());
+    }
+}
+
+pub struct MintTimes {
+    dict: Dict,
+}
+
+impl MintTimes {
+    pub fn instance() -> MintTimes {
+        MintTimes {
+            dict: Dict::instance(MINT_TIMES_DICT),
         }
-        CEP47Event::Approve {
-            owner,
-            spender,
-            token_ids,
-        } => {
-            for token_id in token_ids {
-                let mut param = BTreeMap::new();
-                param.insert(CONTRACT_PACKAGE_HASH, package.to_string());
-                param.insert("event_type", "cep47_approve_token".to_string());
-                param.insert("owner", owner.to_string());
-                param.insert("spender", spender.to_string());
-                param.insert("token_id", token_id.to_string());
-                events.push(param);
-            }
+    }
+
+    pub fn init() {
+        Dict::init(MINT_TIMES_DICT)
+    }
+
+    pub fn get(&self, key: &TokenId) -> Option<u64> {
+        self.dict.get(&key.to_string())
+    }
+
+    pub fn set(&self, key: &TokenId, value: u64) {
+        self.dict.set(&key.to_string(), value);
+    }
+
+    pub fn remove(&self, key: &TokenId) {
+        self.dict.remove::<u64>(&key.to_string());
+    }
+}
+
+pub struct Minters {
+    dict: Dict,
+}
+
+impl Minters {
+    pub fn instance() -> Minters {
+        Minters {
+            dict: Dict::instance(MINTERS_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(MINTERS_DICT)
+    }
+
+    pub fn get(&self, key: &TokenId) -> Option<Key> {
+        self.dict.get(&key.to_string())
+    }
+
+    pub fn set(&self, key: &TokenId, value: Key) {
+        self.dict.set(&key.to_string(), value);
+    }
+
+    pub fn remove(&self, key: &TokenId) {
+        self.dict.remove::<Key>(&key.to_string());
+    }
+}
+
+pub struct MetaTemplates {
+    dict: Dict,
+}
+
+impl MetaTemplates {
+    pub fn instance() -> MetaTemplates {
+        MetaTemplates {
+            dict: Dict::instance(META_TEMPLATES_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(META_TEMPLATES_DICT)
+    }
+
+    pub fn get(&self, key: &u32) -> Option<Meta> {
+        self.dict.get(&key.to_string())
+    }
+
+    pub fn set(&self, key: &u32, value: Meta) {
+        self.dict.set(&key.to_string(), value);
+    }
+
+    pub fn remove(&self, key: &u32) {
+        self.dict.remove::<Meta>(&key.to_string());
+    }
+}
+
+pub struct TokenTemplates {
+    dict: Dict,
+}
+
+impl TokenTemplates {
+    pub fn instance() -> TokenTemplates {
+        TokenTemplates {
+            dict: Dict::instance(TOKEN_TEMPLATES_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(TOKEN_TEMPLATES_DICT)
+    }
+
+    pub fn get(&self, key: &TokenId) -> Option<u32> {
+        self.dict.get(&key.to_string())
+    }
+
+    pub fn set(&self, key: &TokenId, value: u32) {
+        self.dict.set(&key.to_string(), value);
+    }
+
+    pub fn remove(&self, key: &TokenId) {
+        self.dict.remove::<u32>(&key.to_string());
+    }
+}
+
+// Live tokens whose metadata still resolves to each template.
+pub struct TemplateUsage {
+    dict: Dict,
+}
+
+impl TemplateUsage {
+    pub fn instance() -> TemplateUsage {
+        TemplateUsage {
+            dict: Dict::instance(TEMPLATE_USAGE_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(TEMPLATE_USAGE_DICT)
+    }
+
+    pub fn get(&self, template_id: u32) -> u64 {
+        self.dict.get(&template_id.to_string()).unwrap_or_default()
+    }
+
+    pub fn add(&self, template_id: u32, count: u64) {
+        let usage = self.get(template_id).checked_add(count).unwrap_or_revert();
+        self.dict.set(&template_id.to_string(), usage);
+    }
+
+    pub fn remove(&self, template_id: u32) {
+        let usage = self.get(template_id).checked_sub(1).unwrap_or_revert();
+        self.dict.set(&template_id.to_string(), usage);
+    }
+}
+
+pub struct TokenRoyalties {
+    dict: Dict,
+}
+
+impl TokenRoyalties {
+    pub fn instance() -> TokenRoyalties {
+        TokenRoyalties {
+            dict: Dict::instance(TOKEN_ROYALTIES_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(TOKEN_ROYALTIES_DICT)
+    }
+
+    pub fn get(&self, key: &TokenId) -> Option<(Key, u16)> {
+        self.dict.get(&key.to_string())
+    }
+
+    pub fn set(&self, key: &TokenId, value: (Key, u16)) {
+        self.dict.set(&key.to_string(), value);
+    }
+
+    pub fn remove(&self, key: &TokenId) {
+        self.dict.remove::<(Key, u16)>(&key.to_string());
+    }
+}
+
+pub struct LastTransfer {
+    dict: Dict,
+}
+
+impl LastTransfer {
+    pub fn instance() -> LastTransfer {
+        LastTransfer {
+            dict: Dict::instance(LAST_TRANSFER_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(LAST_TRANSFER_DICT)
+    }
+
+    pub fn get(&self, key: &TokenId) -> Option<u64> {
+        self.dict.get(&key.to_string())
+    }
+
+    pub fn set(&self, key: &TokenId, value: u64) {
+        self.dict.set(&key.to_string(), value);
+    }
+
+    pub fn remove(&self, key: &TokenId) {
+        self.dict.remove::<u64>(&key.to_string());
+    }
+}
+
+pub struct BurnedTokens {
+    dict: Dict,
+}
+
+impl BurnedTokens {
+    pub fn instance() -> BurnedTokens {
+        BurnedTokens {
+            dict: Dict::instance(BURNED_TOKENS_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(BURNED_TOKENS_DICT)
+    }
+
+    pub fn get(&self, key: &TokenId) -> Option<bool> {
+        self.dict.get(&key.to_string())
+    }
+
+    pub fn set(&self, key: &TokenId, value: bool) {
+        self.dict.set(&key.to_string(), value);
+    }
+
+    pub fn remove(&self, key: &TokenId) {
+        self.dict.remove::<bool>(&key.to_string());
+    }
+}
+
+pub struct SupportedInterfaces {
+    dict: Dict,
+}
+
+impl SupportedInterfaces {
+    pub fn instance() -> SupportedInterfaces {
+        SupportedInterfaces {
+            dict: Dict::instance(SUPPORTED_INTERFACES_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(SUPPORTED_INTERFACES_DICT)
+    }
+
+    pub fn get(&self, key: &str) -> Option<bool> {
+        self.dict.get(key)
+    }
+
+    pub fn set(&self, key: &str, value: bool) {
+        self.dict.set(key, value);
+    }
+
+    pub fn remove(&self, key: &str) {
+        self.dict.remove::<bool>(key);
+    }
+}
+
+pub struct RecentEvents {
+    dict: Dict,
+}
+
+impl RecentEvents {
+    pub fn instance() -> RecentEvents {
+        RecentEvents {
+            dict: Dict::instance(RECENT_EVENTS_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(RECENT_EVENTS_DICT)
+    }
+
+    pub fn get(&self, slot: u64) -> Option<Bytes> {
+        self.dict.get(&slot.to_string())
+    }
+
+    pub fn set(&self, slot: u64, value: Bytes) {
+        self.dict.set(&slot.to_string(), value);
+    }
+}
+
+pub struct MintQueue {
+    dict: Dict,
+}
+
+impl MintQueue {
+    pub fn instance() -> MintQueue {
+        MintQueue {
+            dict: Dict::instance(MINT_QUEUE_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(MINT_QUEUE_DICT)
+    }
+
+    pub fn get(&self, position: u64) -> Option<(Key, Meta)> {
+        self.dict.get(&position.to_string())
+    }
+
+    pub fn set(&self, position: u64, recipient: Key, meta: Meta) {
+        self.dict.set(&position.to_string(), (recipient, meta));
+    }
+
+    pub fn remove(&self, position: u64) {
+        self.dict.remove::<(Key, Meta)>(&position.to_string());
+    }
+}
+
+pub struct UsedMetaNonces {
+    dict: Dict,
+}
+
+impl UsedMetaNonces {
+    pub fn instance() -> UsedMetaNonces {
+        UsedMetaNonces {
+            dict: Dict::instance(USED_META_NONCES_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(USED_META_NONCES_DICT)
+    }
+
+    pub fn get(&self, nonce: u64) -> bool {
+        self.dict.get(&nonce.to_string()).unwrap_or_default()
+    }
+
+    pub fn set(&self, nonce: u64) {
+        self.dict.set(&nonce.to_string(), true);
+    }
+}
+
+pub struct UsedMintKeys {
+    dict: Dict,
+}
+
+impl UsedMintKeys {
+    pub fn instance() -> UsedMintKeys {
+        UsedMintKeys {
+            dict: Dict::instance(USED_MINT_KEYS_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(USED_MINT_KEYS_DICT)
+    }
+
+    fn dict_key(idempotency_key: &str) -> String {
+        let bytes = String::from(idempotency_key).to_bytes().unwrap_or_revert();
+        hex::encode(runtime::blake2b(bytes))
+    }
+
+    pub fn get(&self, idempotency_key: &str) -> Option<(String, Vec<TokenId>)> {
+        self.dict.get(&Self::dict_key(idempotency_key))
+    }
+
+    pub fn set(&self, idempotency_key: &str, request_hash: String, token_ids: Vec<TokenId>) {
+        self.dict
+            .set(&Self::dict_key(idempotency_key), (request_hash, token_ids));
+    }
+}
+
+pub struct Expiries {
+    dict: Dict,
+}
+
+impl Expiries {
+    pub fn instance() -> Expiries {
+        Expiries {
+            dict: Dict::instance(EXPIRIES_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(EXPIRIES_DICT)
+    }
+
+    pub fn get(&self, key: &TokenId) -> Option<u64> {
+        self.dict.get(&key.to_string())
+    }
+
+    pub fn set(&self, key: &TokenId, value: u64) {
+        self.dict.set(&key.to_string(), value);
+    }
+
+    pub fn remove(&self, key: &TokenId) {
+        self.dict.remove::<u64>(&key.to_string());
+    }
+}
+
+pub struct OwnedTokens {
+    tokens_dict: Dict,
+    indexes_dict: Dict,
+    balances_dict: Dict,
+}
+
+impl OwnedTokens {
+    pub fn instance() -> OwnedTokens {
+        OwnedTokens {
+            tokens_dict: Dict::instance(OWNED_TOKENS_BY_INDEX_DICT),
+            indexes_dict: Dict::instance(OWNED_INDEXES_BY_TOKEN_DICT),
+            balances_dict: Dict::instance(BALANCES_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(OWNED_TOKENS_BY_INDEX_DICT);
+        Dict::init(OWNED_INDEXES_BY_TOKEN_DICT);
+        Dict::init(BALANCES_DICT);
+    }
+
+    pub fn get_token_by_index(&self, owner: &Key, index: &U256) -> Option<TokenId> {
+        self.tokens_dict.get(&key_and_value_to_str(owner, index))
+    }
+
+    pub fn get_index_by_token(&self, owner: &Key, value: &TokenId) -> Option<U256> {
+        self.indexes_dict.get(&key_and_value_to_str(owner, value))
+    }
+
+    pub fn get_balances(&self, owner: &Key) -> U256 {
+        self.balances_dict
+            .get(&key_to_str(owner))
+            .unwrap_or_default()
+    }
+
+    pub fn set_balances(&self, owner: &Key, value: U256) {
+        self.balances_dict.set(&key_to_str(owner), value);
+    }
+
+    pub fn set_token(&self, owner: &Key, value: &TokenId) {
+        self.set_tokens(owner, core::slice::from_ref(value));
+    }
+
+    pub fn is_indexed(&self, owner: &Key, value: &TokenId) -> bool {
+        self.get_index_by_token(owner, value)
+            .and_then(|index| self.get_token_by_index(owner, &index))
+            == Some(*value)
+    }
+
+    // Drops the entry at `index` and its reverse lookup; returns whether one was there.
+    pub fn clear_index(&self, owner: &Key, index: &U256) -> bool {
+        match self.get_token_by_index(owner, index) {
+            Some(value) => {
+                self.tokens_dict
+                    .remove::<TokenId>(&key_and_value_to_str(owner, index));
+                self.indexes_dict
+                    .remove::<U256>(&key_and_value_to_str(owner, &value));
+                true
+            }
+            None => false,
+        }
+    }
+
+    // Balance is read and written once per batch instead of once per token.
+    pub fn set_tokens(&self, owner: &Key, values: &[TokenId]) {
+        let mut length = self.get_balances(owner);
+        if length.is_zero() && !values.is_empty() {
+            Holders::instance().add(owner);
+        }
+        for value in values {
+            self.indexes_dict
+                .set(&key_and_value_to_str(owner, value), length);
+            self.tokens_dict
+                .set(&key_and_value_to_str(owner, &length), *value);
+            length += U256::one();
+        }
+        self.set_balances(owner, length);
+    }
+
+    pub fn remove_token(&self, owner: &Key, value: &TokenId) {
+        self.remove_tokens(owner, core::slice::from_ref(value));
+    }
+
+    pub fn remove_tokens(&self, owner: &Key, values: &[TokenId]) {
+        let initial_length = self.get_balances(owner);
+        let mut length = initial_length;
+        for value in values {
+            let index = self.get_index_by_token(owner, value).unwrap_or_revert();
+            match length.cmp(&(index + 1)) {
+                core::cmp::Ordering::Equal => {
+                    self.tokens_dict
+                        .remove::<TokenId>(&key_and_value_to_str(owner, &(length - 1)));
+                    length -= U256::one();
+                }
+                core::cmp::Ordering::Greater => {
+                    let last = self.get_token_by_index(owner, &(length - 1));
+                    self.indexes_dict.set(
+                        &key_and_value_to_str(owner, &last.unwrap_or_revert()),
+                        index,
+                    );
+                    self.tokens_dict.set(
+                        &key_and_value_to_str(owner, &index),
+                        last.unwrap_or_revert(),
+                    );
+                    self.tokens_dict
+                        .remove::<TokenId>(&key_and_value_to_str(owner, &(length - 1)));
+                    length -= U256::one();
+                }
+                core::cmp::Ordering::Less => {}
+            }
+            self.indexes_dict
+                .remove::<U256>(&key_and_value_to_str(owner, value));
+        }
+        if length != initial_length {
+            self.set_balances(owner, length);
+            if length.is_zero() {
+                Holders::instance().remove(owner);
+            }
+        }
+    }
+}
+
+// Enumerable set of accounts with a non-zero balance.
+pub struct Holders {
+    holders_dict: Dict,
+    indexes_dict: Dict,
+}
+
+impl Holders {
+    pub fn instance() -> Holders {
+        Holders {
+            holders_dict: Dict::instance(HOLDERS_BY_INDEX_DICT),
+            indexes_dict: Dict::instance(HOLDER_INDEXES_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(HOLDERS_BY_INDEX_DICT);
+        Dict::init(HOLDER_INDEXES_DICT);
+    }
+
+    pub fn get(&self, index: u64) -> Option<Key> {
+        self.holders_dict.get(&index.to_string())
+    }
+
+    pub fn add(&self, owner: &Key) {
+        if self.indexes_dict.get_by_key::<u64>(owner).is_some() {
+            return;
+        }
+        let count = holder_count();
+        self.holders_dict.set(&count.to_string(), *owner);
+        self.indexes_dict.set_by_key(owner, count);
+        set_holder_count(count + 1);
+    }
+
+    pub fn remove(&self, owner: &Key) {
+        let index = match self.indexes_dict.get_by_key::<u64>(owner) {
+            Some(index) => index,
+            None => return,
+        };
+        let last = holder_count() - 1;
+        if index != last {
+            let last_holder: Key = self.get(last).unwrap_or_revert();
+            self.holders_dict.set(&index.to_string(), last_holder);
+            self.indexes_dict.set_by_key(&last_holder, index);
+        }
+        self.holders_dict.remove::<Key>(&last.to_string());
+        self.indexes_dict.remove_by_key::<u64>(owner);
+        set_holder_count(last);
+    }
+}
+
+pub struct AllTokens {
+    tokens_dict: Dict,
+    indexes_dict: Dict,
+}
+
+impl AllTokens {
+    pub fn instance() -> AllTokens {
+        AllTokens {
+            tokens_dict: Dict::instance(ALL_TOKENS_BY_INDEX_DICT),
+            indexes_dict: Dict::instance(ALL_INDEXES_BY_TOKEN_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(ALL_TOKENS_BY_INDEX_DICT);
+        Dict::init(ALL_INDEXES_BY_TOKEN_DICT);
+    }
+
+    pub fn get_token_by_index(&self, index: &U256) -> Option<TokenId> {
+        self.tokens_dict.get(&index.to_string())
+    }
+
+    pub fn get_index_by_token(&self, value: &TokenId) -> Option<U256> {
+        self.indexes_dict.get(&value.to_string())
+    }
+
+    pub fn add_token(&self, length: &U256, value: &TokenId) {
+        self.indexes_dict.set(&value.to_string(), *length);
+        self.tokens_dict.set(&length.to_string(), *value);
+    }
+
+    pub fn remove_token(&self, length: &U256, value: &TokenId) {
+        let index = self.get_index_by_token(value).unwrap_or_revert();
+        let last_index = *length - 1;
+        if index < last_index {
+            let last = self.get_token_by_index(&last_index).unwrap_or_revert();
+            self.indexes_dict.set(&last.to_string(), index);
+            self.tokens_dict.set(&index.to_string(), last);
+        }
+        self.tokens_dict.remove::<TokenId>(&last_index.to_string());
+        self.indexes_dict.remove::<U256>(&value.to_string());
+    }
+}
+
+pub struct Allowances {
+    dict: Dict,
+    counts_dict: Dict,
+}
+
+impl Allowances {
+    pub fn instance() -> Allowances {
+        Allowances {
+            dict: Dict::instance(ALLOWANCES_DICT),
+            counts_dict: Dict::instance(APPROVAL_COUNTS_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(ALLOWANCES_DICT);
+        Dict::init(APPROVAL_COUNTS_DICT);
+    }
+
+    pub fn count(&self, owner: &Key) -> u32 {
+        self.counts_dict.get_by_key(owner).unwrap_or_default()
+    }
+
+    pub fn get(&self, owner: &Key, token_id: &TokenId) -> Option<Key> {
+        self.dict.get(&key_and_value_to_str::<String>(
+            owner,
+            &token_id.to_string(),
+        ))
+    }
+
+    pub fn set(&self, owner: &Key, token_id: &TokenId, value: Key) {
+        if self.get(owner, token_id).is_none() {
+            set_total_approvals(total_approvals() + 1);
+            self.counts_dict.set_by_key(owner, self.count(owner) + 1);
+        }
+        self.dict.set(
+            &key_and_value_to_str::<String>(owner, &token_id.to_string()),
+            value,
+        );
+    }
+
+    pub fn remove(&self, owner: &Key, token_id: &TokenId) {
+        if self.get(owner, token_id).is_none() {
+            return;
+        }
+        set_total_approvals(total_approvals() - 1);
+        self.counts_dict
+            .set_by_key(owner, self.count(owner).saturating_sub(1));
+        self.dict.remove::<Key>(&key_and_value_to_str::<String>(
+            owner,
+            &token_id.to_string(),
+        ));
+    }
+}
+
+pub struct MintedCount {
+    dict: Dict,
+}
+
+impl MintedCount {
+    pub fn instance() -> MintedCount {
+        MintedCount {
+            dict: Dict::instance(MINTED_COUNT_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(MINTED_COUNT_DICT)
+    }
+
+    pub fn get(&self, owner: &Key) -> U256 {
+        self.dict.get(&key_to_str(owner)).unwrap_or_default()
+    }
+
+    pub fn add(&self, owner: &Key, count: U256) {
+        let minted = self.get(owner).checked_add(count).unwrap_or_revert();
+        self.dict.set(&key_to_str(owner), minted);
+    }
+}
+
+pub struct ViewCounts {
+    dict: Dict,
+}
+
+impl ViewCounts {
+    pub fn instance() -> ViewCounts {
+        ViewCounts {
+            dict: Dict::instance(VIEW_COUNTS_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(VIEW_COUNTS_DICT)
+    }
+
+    pub fn get(&self, token_id: &TokenId) -> u64 {
+        self.dict.get(&token_id.to_string()).unwrap_or_default()
+    }
+
+    pub fn increment(&self, token_id: &TokenId) {
+        let views = self.get(token_id).checked_add(1).unwrap_or_revert();
+        self.dict.set(&token_id.to_string(), views);
+    }
+}
+
+// Not cleared on burn: meta_versions only reports live tokens and a burned id
+// can't be minted again, so a leftover version is never read.
+pub struct MetaVersions {
+    dict: Dict,
+}
+
+impl MetaVersions {
+    pub fn instance() -> MetaVersions {
+        MetaVersions {
+            dict: Dict::instance(META_VERSIONS_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(META_VERSIONS_DICT)
+    }
+
+    pub fn get(&self, token_id: &TokenId) -> u64 {
+        self.dict.get(&token_id.to_string()).unwrap_or_default()
+    }
+
+    pub fn increment(&self, token_id: &TokenId) {
+        let version = self.get(token_id).checked_add(1).unwrap_or_revert();
+        self.dict.set(&token_id.to_string(), version);
+    }
+}
+
+pub struct TraitCounts {
+    dict: Dict,
+}
+
+impl TraitCounts {
+    pub fn instance() -> TraitCounts {
+        TraitCounts {
+            dict: Dict::instance(TRAIT_COUNTS_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(TRAIT_COUNTS_DICT)
+    }
+
+    fn trait_key(key: &str, value: &str) -> String {
+        let bytes = (String::from(key), String::from(value))
+            .to_bytes()
+            .unwrap_or_revert();
+        hex::encode(runtime::blake2b(bytes))
+    }
+
+    pub fn get(&self, key: &str, value: &str) -> u64 {
+        self.dict
+            .get(&Self::trait_key(key, value))
+            .unwrap_or_default()
+    }
+
+    pub fn add(&self, meta: &Meta, copies: u64) {
+        for (key, value) in meta {
+            let count = self.get(key, value).checked_add(copies).unwrap_or_revert();
+            self.dict.set(&Self::trait_key(key, value), count);
+        }
+    }
+
+    pub fn remove(&self, meta: &Meta, copies: u64) {
+        for (key, value) in meta {
+            let count = self.get(key, value).checked_sub(copies).unwrap_or_revert();
+            self.dict.set(&Self::trait_key(key, value), count);
+        }
+    }
+}
+
+pub struct Operators {
+    dict: Dict,
+}
+
+impl Operators {
+    pub fn instance() -> Operators {
+        Operators {
+            dict: Dict::instance(OPERATORS_DICT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(OPERATORS_DICT)
+    }
+
+    pub fn get(&self, owner: &Key, operator: &Key) -> bool {
+        self.dict
+            .get(&key_and_value_to_str(owner, operator))
+            .unwrap_or_default()
+    }
+
+    pub fn set(&self, owner: &Key, operator: &Key) {
+        self.dict.set(&key_and_value_to_str(owner, operator), true);
+    }
+
+    pub fn remove(&self, owner: &Key, operator: &Key) {
+        self.dict
+            .remove::<bool>(&key_and_value_to_str(owner, operator));
+    }
+}
+
+// Listed in the order CEP47::init creates them, followed by the AdminControl
+// dictionaries; extend both together.
+pub fn dictionary_names() -> Vec<String> {
+    vec![
+        OWNERS_DICT,
+        OWNED_TOKENS_BY_INDEX_DICT,
+        OWNED_INDEXES_BY_TOKEN_DICT,
+        BALANCES_DICT,
+        METADATA_DICT,
+        ALLOWANCES_DICT,
+        APPROVAL_COUNTS_DICT,
+        MINT_TIMES_DICT,
+        MINTERS_DICT,
+        OPERATORS_DICT,
+        MINTED_COUNT_DICT,
+        ALL_TOKENS_BY_INDEX_DICT,
+        ALL_INDEXES_BY_TOKEN_DICT,
+        LAST_TRANSFER_DICT,
+        EXPIRIES_DICT,
+        BURNED_TOKENS_DICT,
+        META_TEMPLATES_DICT,
+        TOKEN_TEMPLATES_DICT,
+        SUPPORTED_INTERFACES_DICT,
+        RECENT_EVENTS_DICT,
+        USED_META_NONCES_DICT,
+        TOKEN_ROYALTIES_DICT,
+        TYPED_METADATA_DICT,
+        MINT_LOCKED_IDS_DICT,
+        BURNED_TOKENS_BY_INDEX_DICT,
+        USED_META_VALUES_DICT,
+        FROZEN_TOKENS_DICT,
+        APPROVAL_LOCKS_DICT,
+        MINT_QUEUE_DICT,
+        HOLDERS_BY_INDEX_DICT,
+        HOLDER_INDEXES_DICT,
+        TOKEN_ACL_DICT,
+        USED_MINT_KEYS_DICT,
+        VIEW_COUNTS_DICT,
+        TRANSFER_ALLOWLIST_DICT,
+        TOKEN_FEES_DICT,
+        META_VERSIONS_DICT,
+        TRAIT_COUNTS_DICT,
+        TEMPLATE_USAGE_DICT,
+    ]
+    .into_iter()
+    .chain(ADMIN_DICTIONARY_NAMES.iter().copied())
+    .map(String::from)
+    .collect()
+}
+
+pub fn name() -> String {
+    get_key(NAME).unwrap_or_revert()
+}
+
+pub fn set_name(name: String) {
+    set_key(NAME, name);
+}
+
+pub fn meta_signer() -> Option<PublicKey> {
+    get_key(META_SIGNER).unwrap_or_default()
+}
+
+pub fn set_meta_signer(signer: Option<PublicKey>) {
+    set_key(META_SIGNER, signer);
+}
+
+pub fn creator() -> Key {
+    get_key(CREATOR).unwrap_or_revert()
+}
+
+pub fn set_creator(creator: Key) {
+    set_key(CREATOR, creator);
+}
+
+pub fn symbol() -> String {
+    get_key(SYMBOL).unwrap_or_revert()
+}
+
+pub fn set_symbol(symbol: String) {
+    set_key(SYMBOL, symbol);
+}
+
+pub fn meta() -> Meta {
+    get_key(META).unwrap_or_revert()
+}
+
+pub fn set_meta(meta: Meta) {
+    set_key(META, meta);
+}
+
+pub fn default_meta() -> Meta {
+    get_key(DEFAULT_META).unwrap_or_default()
+}
+
+pub fn set_default_meta(meta: Meta) {
+    set_key(DEFAULT_META, meta);
+}
+
+pub fn total_supply() -> U256 {
+    get_key(TOTAL_SUPPLY).unwrap_or_default()
+}
+
+pub fn genesis_supply() -> U256 {
+    get_key(GENESIS_SUPPLY).unwrap_or_default()
+}
+
+pub fn set_genesis_supply(genesis_supply: U256) {
+    set_key(GENESIS_SUPPLY, genesis_supply);
+}
+
+pub fn set_total_supply(total_supply: U256) {
+    set_key(TOTAL_SUPPLY, total_supply);
+}
+
+pub fn nonce() -> u32 {
+    get_key(NONCE).unwrap_or_default()
+}
+
+pub fn set_nonce(nonce: u32) {
+    set_key(NONCE, nonce);
+}
+
+pub fn token_id_salt() -> Option<String> {
+    get_key(TOKEN_ID_SALT).unwrap_or_default()
+}
+
+pub fn set_token_id_salt(salt: Option<String>) {
+    set_key(TOKEN_ID_SALT, salt);
+}
+
+pub fn max_mints_per_block() -> Option<u32> {
+    get_key(MAX_MINTS_PER_BLOCK).unwrap_or_default()
+}
+
+pub fn set_max_mints_per_block(limit: Option<u32>) {
+    set_key(MAX_MINTS_PER_BLOCK, limit);
+}
+
+pub fn max_approvals_per_owner() -> Option<u32> {
+    get_key(MAX_APPROVALS_PER_OWNER).unwrap_or_default()
+}
+
+pub fn set_max_approvals_per_owner(limit: Option<u32>) {
+    set_key(MAX_APPROVALS_PER_OWNER, limit);
+}
+
+pub fn mints_in_block() -> u32 {
+    get_key(MINTS_IN_BLOCK).unwrap_or_default()
+}
+
+pub fn set_mints_in_block(count: u32) {
+    set_key(MINTS_IN_BLOCK, count);
+}
+
+pub fn last_mint_block_time() -> u64 {
+    get_key(LAST_MINT_BLOCK_TIME).unwrap_or_default()
+}
+
+pub fn unique_meta_key() -> Option<String> {
+    get_key(UNIQUE_META_KEY).unwrap_or_default()
+}
+
+pub fn set_unique_meta_key(key: Option<String>) {
+    set_key(UNIQUE_META_KEY, key);
+}
+
+pub fn holder_count() -> u64 {
+    get_key(HOLDER_COUNT).unwrap_or_default()
+}
+
+pub fn index_reset_cursor() -> Option<(Key, U256)> {
+    get_key(INDEX_RESET_CURSOR).unwrap_or_default()
+}
+
+pub fn set_index_reset_cursor(cursor: Option<(Key, U256)>) {
+    set_key(INDEX_RESET_CURSOR, cursor);
+}
+
+pub fn set_holder_count(count: u64) {
+    set_key(HOLDER_COUNT, count);
+}
+
+pub fn burned_count() -> U256 {
+    get_key(BURNED_COUNT).unwrap_or_default()
+}
+
+pub fn set_burned_count(count: U256) {
+    set_key(BURNED_COUNT, count);
+}
+
+pub fn last_mint_time() -> u64 {
+    get_key(LAST_MINT_TIME).unwrap_or_default()
+}
+
+pub fn set_last_mint_time(block_time: u64) {
+    set_key(LAST_MINT_TIME, block_time);
+}
+
+pub fn set_last_mint_block_time(block_time: u64) {
+    set_key(LAST_MINT_BLOCK_TIME, block_time);
+}
+
+pub fn recall_window() -> u64 {
+    get_key(RECALL_WINDOW).unwrap_or_default()
+}
+
+pub fn set_recall_window(window: u64) {
+    set_key(RECALL_WINDOW, window);
+}
+
+pub fn total_supply_cap() -> Option<U256> {
+    get_key(TOTAL_SUPPLY_CAP).unwrap_or_default()
+}
+
+pub fn set_total_supply_cap(cap: Option<U256>) {
+    set_key(TOTAL_SUPPLY_CAP, cap);
+}
+
+pub fn min_supply() -> Option<U256> {
+    get_key(MIN_SUPPLY).unwrap_or_default()
+}
+
+pub fn set_min_supply(min_supply: Option<U256>) {
+    set_key(MIN_SUPPLY, min_supply);
+}
+
+pub fn metadata_delta_events() -> bool {
+    get_key(METADATA_DELTA_EVENTS).unwrap_or_default()
+}
+
+pub fn set_metadata_delta_events(enabled: bool) {
+    set_key(METADATA_DELTA_EVENTS, enabled);
+}
+
+pub fn stamp_on_transfer() -> bool {
+    get_key(STAMP_ON_TRANSFER).unwrap_or_default()
+}
+
+pub fn set_stamp_on_transfer(enabled: bool) {
+    set_key(STAMP_ON_TRANSFER, enabled);
+}
+
+pub fn transfer_restricted() -> bool {
+    get_key(TRANSFER_RESTRICTED).unwrap_or_default()
+}
+
+pub fn set_transfer_restricted(enabled: bool) {
+    set_key(TRANSFER_RESTRICTED, enabled);
+}
+
+pub fn notify_on_mint() -> bool {
+    get_key(NOTIFY_ON_MINT).unwrap_or_default()
+}
+
+pub fn set_notify_on_mint(enabled: bool) {
+    set_key(NOTIFY_ON_MINT, enabled);
+}
+
+pub fn burn_meta_events() -> bool {
+    get_key(BURN_META_EVENTS).unwrap_or_default()
+}
+
+pub fn set_burn_meta_events(enabled: bool) {
+    set_key(BURN_META_EVENTS, enabled);
+}
+
+pub fn debug_errors() -> bool {
+    get_key(DEBUG_ERRORS).unwrap_or_default()
+}
+
+pub fn set_debug_errors(enabled: bool) {
+    set_key(DEBUG_ERRORS, enabled);
+}
+
+pub fn last_error() -> Option<String> {
+    get_key(LAST_ERROR).unwrap_or_default()
+}
+
+pub fn set_last_error(context: Option<String>) {
+    set_key(LAST_ERROR, context);
+}
+
+pub fn mint_lock_period() -> u64 {
+    get_key(MINT_LOCK_PERIOD).unwrap_or_default()
+}
+
+pub fn set_mint_lock_period(period: u64) {
+    set_key(MINT_LOCK_PERIOD, period);
+}
+
+pub fn immutable_metadata() -> bool {
+    get_key(IMMUTABLE_METADATA).unwrap_or_default()
+}
+
+pub fn set_immutable_metadata(immutable: bool) {
+    set_key(IMMUTABLE_METADATA, immutable);
+}
+
+pub fn require_explicit_ids() -> bool {
+    get_key(REQUIRE_EXPLICIT_IDS).unwrap_or_default()
+}
+
+pub fn set_require_explicit_ids(required: bool) {
+    set_key(REQUIRE_EXPLICIT_IDS, required);
+}
+
+pub fn events_enabled() -> bool {
+    get_key(EVENTS_ENABLED).unwrap_or(true)
+}
+
+pub fn set_events_enabled(enabled: bool) {
+    set_key(EVENTS_ENABLED, enabled);
+}
+
+pub fn max_meta_bytes() -> u32 {
+    get_key(MAX_META_BYTES).unwrap_or_default()
+}
+
+pub fn set_max_meta_bytes(limit: u32) {
+    set_key(MAX_META_BYTES, limit);
+}
+
+pub fn paused() -> bool {
+    get_key(PAUSED).unwrap_or_default()
+}
+
+pub fn set_paused(paused: bool) {
+    set_key(PAUSED, paused);
+}
+
+pub fn transfers_paused() -> bool {
+    get_key(TRANSFERS_PAUSED).unwrap_or_default()
+}
+
+pub fn set_transfers_paused(paused: bool) {
+    set_key(TRANSFERS_PAUSED, paused);
+}
+
+pub fn recent_events_capacity() -> u32 {
+    get_key(RECENT_EVENTS_CAPACITY).unwrap_or_default()
+}
+
+pub fn set_recent_events_capacity(capacity: u32) {
+    set_key(RECENT_EVENTS_CAPACITY, capacity);
+}
+
+pub fn recent_events_count() -> u64 {
+    get_key(RECENT_EVENTS_COUNT).unwrap_or_default()
+}
+
+pub fn set_recent_events_count(count: u64) {
+    set_key(RECENT_EVENTS_COUNT, count);
+}
+
+fn record_recent_event(param: &BTreeMap<&str, String>) {
+    let capacity = recent_events_capacity() as u64;
+    if capacity == 0 {
+        return;
+    }
+    let count = recent_events_count();
+    let bytes = Bytes::from(param.to_bytes().unwrap_or_revert());
+    RecentEvents::instance().set(count % capacity, bytes);
+    set_recent_events_count(count + 1);
+}
+
+pub fn transfer_fee_bps() -> u16 {
+    get_key(TRANSFER_FEE_BPS).unwrap_or_default()
+}
+
+pub fn set_transfer_fee_bps(bps: u16) {
+    set_key(TRANSFER_FEE_BPS, bps);
+}
+
+pub fn fee_treasury() -> Option<Key> {
+    get_key(FEE_TREASURY).unwrap_or_default()
+}
+
+pub fn set_fee_treasury(treasury: Option<Key>) {
+    set_key(FEE_TREASURY, treasury);
+}
+
+pub fn total_approvals() -> U256 {
+    get_key(TOTAL_APPROVALS).unwrap_or_default()
+}
+
+pub fn set_total_approvals(total: U256) {
+    set_key(TOTAL_APPROVALS, total);
+}
+
+pub fn transfer_cooldown() -> u64 {
+    get_key(TRANSFER_COOLDOWN).unwrap_or_default()
+}
+
+pub fn set_transfer_cooldown(cooldown: u64) {
+    set_key(TRANSFER_COOLDOWN, cooldown);
+}
+
+pub fn view_recorder() -> Option<Key> {
+    get_key(VIEW_RECORDER).unwrap_or_default()
+}
+
+pub fn set_view_recorder(recorder: Option<Key>) {
+    set_key(VIEW_RECORDER, recorder);
+}
+
+pub fn dynamic_meta_contract() -> Option<ContractHash> {
+    get_key(DYNAMIC_META_CONTRACT).unwrap_or_default()
+}
+
+pub fn set_dynamic_meta_contract(contract: Option<ContractHash>) {
+    set_key(DYNAMIC_META_CONTRACT, contract);
+}
+
+pub fn mint_queue_head() -> u64 {
+    get_key(MINT_QUEUE_HEAD).unwrap_or_default()
+}
+
+pub fn set_mint_queue_head(head: u64) {
+    set_key(MINT_QUEUE_HEAD, head);
+}
+
+pub fn mint_queue_tail() -> u64 {
+    get_key(MINT_QUEUE_TAIL).unwrap_or_default()
+}
+
+pub fn set_mint_queue_tail(tail: u64) {
+    set_key(MINT_QUEUE_TAIL, tail);
+}
+
+pub fn meta_resolver_version() -> u32 {
+    get_key(META_RESOLVER_VERSION).unwrap_or_default()
+}
+
+pub fn set_meta_resolver_version(version: u32) {
+    set_key(META_RESOLVER_VERSION, version);
+}
+
+pub fn recipient_policy() -> RecipientPolicy {
+    RecipientPolicy::from_u8(get_key(RECIPIENT_POLICY).unwrap_or_default()).unwrap_or_revert()
+}
+
+pub fn set_recipient_policy(policy: RecipientPolicy) {
+    set_key(RECIPIENT_POLICY, policy as u8);
+}
+
+pub fn event_schema_version() -> u32 {
+    get_key(EVENT_SCHEMA_VERSION).unwrap_or_default()
+}
+
+pub fn set_event_schema_version(version: u32) {
+    set_key(EVENT_SCHEMA_VERSION, version);
+}
+
+pub fn contract_package_hash() -> ContractPackageHash {
+    let call_stacks = get_call_stack();
+    let last_entry = call_stacks.last().unwrap_or_revert();
+    let package_hash: Option<ContractPackageHash> = match last_entry {
+        CallStackElement::StoredContract {
+            contract_package_hash,
+            contract_hash: _,
+        } => Some(*contract_package_hash),
+        _ => None,
+    };
+    package_hash.unwrap_or_revert()
+}
+
+pub fn emit(event: &CEP47Event) {
+    let mut events = Vec::new();
+    let package = contract_package_hash();
+    match event {
+        CEP47Event::Mint {
+            recipient,
+            token_ids,
+        } => {
+            let creator = creator();
+            for token_id in token_ids {
+                let mut param = BTreeMap::new();
+                param.insert(CONTRACT_PACKAGE_HASH, package.to_string());
+                param.insert("event_type", "cep47_mint_one".to_string());
+                param.insert("recipient", recipient.to_string());
+                param.insert("creator", creator.to_string());
+                param.insert("token_id", token_id.to_string());
+                events.push(param);
+            }
+        }
+        CEP47Event::Burn { owner, token_ids } => {
+            for token_id in token_ids {
+                let mut param = BTreeMap::new();
+                param.insert(CONTRACT_PACKAGE_HASH, package.to_string());
+                param.insert("event_type", "cep47_burn_one".to_string());
+                param.insert("owner", owner.to_string());
+                param.insert("token_id", token_id.to_string());
+                events.push(param);
+            }
+        }
+        CEP47Event::BurnWithMeta {
+            owner,
+            token_ids,
+            token_metas,
+        } => {
+            for (token_id, token_meta) in token_ids.iter().zip(token_metas) {
+                let mut param = BTreeMap::new();
+                param.insert(CONTRACT_PACKAGE_HASH, package.to_string());
+                param.insert("event_type", "cep47_burn_one".to_string());
+                param.insert("owner", owner.to_string());
+                param.insert("token_id", token_id.to_string());
+                events.push(param);
+                for (key, value) in token_meta {
+                    let mut param = BTreeMap::new();
+                    param.insert(CONTRACT_PACKAGE_HASH, package.to_string());
+                    param.insert("event_type", "cep47_burn_meta".to_string());
+                    param.insert("token_id", token_id.to_string());
+                    param.insert("key", key.clone());
+                    param.insert("value", value.clone());
+                    events.push(param);
+                }
+            }
+        }
+        CEP47Event::Approve {
+            owner,
+            spender,
+            token_ids,
+        } => {
+            for token_id in token_ids {
+                let mut param = BTreeMap::new();
+                param.insert(CONTRACT_PACKAGE_HASH, package.to_string());
+                param.insert("event_type", "cep47_approve_token".to_string());
+                param.insert("owner", owner.to_string());
+                param.insert("spender", spender.to_string());
+                param.insert("token_id", token_id.to_string());
+                events.push(param);
+            }
+        }
+        CEP47Event::ApprovalChanged {
+            owner,
+            token_id,
+            old_spender,
+            new_spender,
+        } => {
+            let mut param = BTreeMap::new();
+            param.insert(CONTRACT_PACKAGE_HASH, package.to_string());
+            param.insert("event_type", "cep47_approval_changed".to_string());
+            param.insert("owner", owner.to_string());
+            param.insert("token_id", token_id.to_string());
+            param.insert("old_spender", old_spender.to_string());
+            param.insert("new_spender", new_spender.to_string());
+            events.push(param);
+        }
+        CEP47Event::Revoke { owner, token_ids } => {
+            for token_id in token_ids {
+                let mut param = BTreeMap::new();
+                param.insert(CONTRACT_PACKAGE_HASH, package.to_string());
+                param.insert("event_type", "cep47_revoke_token".to_string());
+                param.insert("owner", owner.to_string());
+                param.insert("token_id", token_id.to_string());
+                events.push(param);
+            }
         }
         CEP47Event::Transfer {
             sender,
@@ -298,15 +1889,183 @@ pub fn emit(event: &CEP47Event) {
                 events.push(param);
             }
         }
+        CEP47Event::Redeemed {
+            token_id,
+            owner,
+            shipping_ref,
+        } => {
+            let mut param = BTreeMap::new();
+            param.insert(CONTRACT_PACKAGE_HASH, package.to_string());
+            param.insert("event_type", "cep47_redeem_token".to_string());
+            param.insert("owner", owner.to_string());
+            param.insert("token_id", token_id.to_string());
+            param.insert("shipping_ref", shipping_ref.clone());
+            events.push(param);
+        }
+        CEP47Event::Recalled {
+            owner,
+            minter,
+            token_id,
+        } => {
+            let mut param = BTreeMap::new();
+            param.insert(CONTRACT_PACKAGE_HASH, package.to_string());
+            param.insert("event_type", "cep47_recall_token".to_string());
+            param.insert("owner", owner.to_string());
+            param.insert("minter", minter.to_string());
+            param.insert("token_id", token_id.to_string());
+            events.push(param);
+        }
+        CEP47Event::AdminBurn { owner, token_ids } => {
+            let mut param = BTreeMap::new();
+            param.insert(CONTRACT_PACKAGE_HASH, package.to_string());
+            param.insert("event_type", "cep47_admin_burn".to_string());
+            param.insert("owner", owner.to_string());
+            let token_ids: Vec<String> = token_ids.iter().map(|id| id.to_string()).collect();
+            param.insert("token_ids", token_ids.join(","));
+            events.push(param);
+        }
+        CEP47Event::DelegateApproval {
+            owner,
+            spender,
+            new_spender,
+            token_id,
+        } => {
+            let mut param = BTreeMap::new();
+            param.insert(CONTRACT_PACKAGE_HASH, package.to_string());
+            param.insert("event_type", "cep47_delegate_approval".to_string());
+            param.insert("owner", owner.to_string());
+            param.insert("spender", spender.to_string());
+            param.insert("new_spender", new_spender.to_string());
+            param.insert("token_id", token_id.to_string());
+            events.push(param);
+        }
+        CEP47Event::ApprovalForAll {
+            owner,
+            operator,
+            approved,
+        } => {
+            let mut param = BTreeMap::new();
+            param.insert(CONTRACT_PACKAGE_HASH, package.to_string());
+            param.insert("event_type", "cep47_approval_for_all".to_string());
+            param.insert("owner", owner.to_string());
+            param.insert("operator", operator.to_string());
+            param.insert("approved", approved.to_string());
+            events.push(param);
+        }
+        CEP47Event::ApprovalForAllMany {
+            owner,
+            operators,
+            approved,
+        } => {
+            let mut param = BTreeMap::new();
+            param.insert(CONTRACT_PACKAGE_HASH, package.to_string());
+            param.insert("event_type", "cep47_approval_for_all_many".to_string());
+            param.insert("owner", owner.to_string());
+            let operators: Vec<String> = operators.iter().map(|key| key.to_string()).collect();
+            param.insert("operators", operators.join(","));
+            param.insert("approved", approved.to_string());
+            events.push(param);
+        }
+        CEP47Event::Pause { paused } => {
+            let event_type = if *paused {
+                "cep47_pause"
+            } else {
+                "cep47_unpause"
+            };
+            let mut param = BTreeMap::new();
+            param.insert(CONTRACT_PACKAGE_HASH, package.to_string());
+            param.insert("event_type", event_type.to_string());
+            events.push(param);
+        }
+        CEP47Event::TransfersPause { paused } => {
+            let event_type = if *paused {
+                "cep47_transfers_pause"
+            } else {
+                "cep47_transfers_unpause"
+            };
+            let mut param = BTreeMap::new();
+            param.insert(CONTRACT_PACKAGE_HASH, package.to_string());
+            param.insert("event_type", event_type.to_string());
+            events.push(param);
+        }
+        CEP47Event::TransferFee { amount, treasury } => {
+            let mut param = BTreeMap::new();
+            param.insert(CONTRACT_PACKAGE_HASH, package.to_string());
+            param.insert("event_type", "cep47_transfer_fee".to_string());
+            param.insert("amount", amount.to_string());
+            param.insert("treasury", treasury.to_string());
+            events.push(param);
+        }
+        CEP47Event::SupplyAnnouncement {
+            total_supply,
+            total_minted,
+            total_burned,
+        } => {
+            let mut param = BTreeMap::new();
+            param.insert(CONTRACT_PACKAGE_HASH, package.to_string());
+            param.insert("event_type", "cep47_supply_announcement".to_string());
+            param.insert("total_supply", total_supply.to_string());
+            param.insert("total_minted", total_minted.to_string());
+            param.insert("total_burned", total_burned.to_string());
+            events.push(param);
+        }
+        CEP47Event::RoyaltiesUpdate { token_ids } => {
+            let mut param = BTreeMap::new();
+            param.insert(CONTRACT_PACKAGE_HASH, package.to_string());
+            param.insert("event_type", "cep47_royalties_update".to_string());
+            let token_ids: Vec<String> = token_ids.iter().map(|id| id.to_string()).collect();
+            param.insert("token_ids", token_ids.join(","));
+            events.push(param);
+        }
         CEP47Event::MetadataUpdate { token_id } => {
             let mut param = BTreeMap::new();
             param.insert(CONTRACT_PACKAGE_HASH, package.to_string());
             param.insert("event_type", "cep47_metadata_update".to_string());
             param.insert("token_id", token_id.to_string());
+            param.insert("resolver_version", meta_resolver_version().to_string());
+            events.push(param);
+        }
+        CEP47Event::MetadataDelta {
+            token_id,
+            changed,
+            removed,
+        } => {
+            for (key, value) in changed {
+                let mut param = BTreeMap::new();
+                param.insert(CONTRACT_PACKAGE_HASH, package.to_string());
+                param.insert("event_type", "cep47_metadata_delta".to_string());
+                param.insert("token_id", token_id.to_string());
+                param.insert("change", "set".to_string());
+                param.insert("key", key.clone());
+                param.insert("value", value.clone());
+                events.push(param);
+            }
+            for key in removed {
+                let mut param = BTreeMap::new();
+                param.insert(CONTRACT_PACKAGE_HASH, package.to_string());
+                param.insert("event_type", "cep47_metadata_delta".to_string());
+                param.insert("token_id", token_id.to_string());
+                param.insert("change", "removed".to_string());
+                param.insert("key", key.clone());
+                events.push(param);
+            }
+        }
+        CEP47Event::ConfigUpdate {
+            field,
+            old_value,
+            new_value,
+        } => {
+            let mut param = BTreeMap::new();
+            param.insert(CONTRACT_PACKAGE_HASH, package.to_string());
+            param.insert("event_type", "cep47_config_update".to_string());
+            param.insert("field", field.clone());
+            param.insert("old_value", old_value.clone());
+            param.insert("new_value", new_value.clone());
             events.push(param);
         }
     };
     for param in events {
+        record_recent_event(&param);
         let _: URef = storage::new_uref(param);
     }
 }
@@ -1,7 +1,7 @@
-use alloc::vec::Vec;
-use casper_types::Key;
+use alloc::{string::String, vec::Vec};
+use casper_types::{Key, U256};
 
-use crate::TokenId;
+use crate::{Meta, TokenId};
 
 pub enum CEP47Event {
     Mint {
@@ -12,17 +12,90 @@ pub enum CEP47Event {
         owner: Key,
         token_ids: Vec<TokenId>,
     },
+    BurnWithMeta {
+        owner: Key,
+        token_ids: Vec<TokenId>,
+        token_metas: Vec<Meta>,
+    },
     Approve {
         owner: Key,
         spender: Key,
         token_ids: Vec<TokenId>,
     },
+    ApprovalChanged {
+        owner: Key,
+        token_id: TokenId,
+        old_spender: Key,
+        new_spender: Key,
+    },
+    Revoke {
+        owner: Key,
+        token_ids: Vec<TokenId>,
+    },
     Transfer {
         sender: Key,
         recipient: Key,
         token_ids: Vec<TokenId>,
     },
+    Redeemed {
+        token_id: TokenId,
+        owner: Key,
+        shipping_ref: String,
+    },
+    Recalled {
+        owner: Key,
+        minter: Key,
+        token_id: TokenId,
+    },
+    AdminBurn {
+        owner: Key,
+        token_ids: Vec<TokenId>,
+    },
+    DelegateApproval {
+        owner: Key,
+        spender: Key,
+        new_spender: Key,
+        token_id: TokenId,
+    },
+    ApprovalForAll {
+        owner: Key,
+        operator: Key,
+        approved: bool,
+    },
+    ApprovalForAllMany {
+        owner: Key,
+        operators: Vec<Key>,
+        approved: bool,
+    },
+    Pause {
+        paused: bool,
+    },
+    TransfersPause {
+        paused: bool,
+    },
+    TransferFee {
+        amount: U256,
+        treasury: Key,
+    },
+    SupplyAnnouncement {
+        total_supply: U256,
+        total_minted: U256,
+        total_burned: U256,
+    },
+    RoyaltiesUpdate {
+        token_ids: Vec<TokenId>,
+    },
     MetadataUpdate {
         token_id: TokenId,
     },
+    MetadataDelta {
+        token_id: TokenId,
+        changed: Vec<(String, String)>,
+        removed: Vec<String>,
+    },
+    ConfigUpdate {
+        field: String,
+        old_value: String,
+        new_value: String,
+    },
 }
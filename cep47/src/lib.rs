@@ -5,11 +5,24 @@ extern crate alloc;
 mod cep47;
 pub mod data;
 pub mod event;
+mod receipt;
 
-pub use cep47::{Error, CEP47};
+pub use cep47::{
+    Error, BPS_DENOMINATOR, CEP47, COMPUTE_META_ENTRY_POINT, EVENT_SCHEMA_VERSION,
+    FEATURE_BURN_META_EVENTS, FEATURE_DEBUG_ERRORS, FEATURE_DYNAMIC_META, FEATURE_EVENTS_ENABLED,
+    FEATURE_IMMUTABLE_METADATA, FEATURE_METADATA_DELTA_EVENTS, FEATURE_NOTIFY_ON_MINT,
+    FEATURE_PAUSED, FEATURE_SIGNED_META, FEATURE_SUPPLY_CAP, FEATURE_TRANSFERS_PAUSED,
+    FEATURE_TRANSFER_FEE, FEATURE_TRANSFER_RESTRICTED, FEATURE_UNIQUE_META_KEY, INTERFACE_CEP47,
+    INTERFACE_ENUMERABLE, INTERFACE_METADATA_TEMPLATES, INTERFACE_MUTABLE_METADATA,
+    INTERFACE_OPERATOR_APPROVALS, INTERFACE_SAFE_TRANSFER, MAX_QUERY_BATCH_SIZE, MAX_RECENT_EVENTS,
+    MAX_SHIPPING_REF_LENGTH, ON_CEP47_MINTED_ENTRY_POINT, ON_CEP47_RECEIVED_ENTRY_POINT,
+    RARITY_SCALE, TRANSFERER_ROLE,
+};
 pub use contract_utils;
+pub use receipt::MintReceipt;
 
 use alloc::{collections::BTreeMap, string::String};
 use casper_types::U256;
 pub type TokenId = U256;
 pub type Meta = BTreeMap<String, String>;
+pub type TypedMeta = BTreeMap<String, (u8, String)>;
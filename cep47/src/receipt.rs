@@ -0,0 +1,51 @@
+use alloc::vec::Vec;
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, U256,
+};
+
+use crate::TokenId;
+
+pub struct MintReceipt {
+    pub token_ids: Vec<TokenId>,
+    pub new_total_supply: U256,
+    pub block_time: u64,
+}
+
+impl CLTyped for MintReceipt {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for MintReceipt {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.append(&mut self.token_ids.to_bytes()?);
+        result.append(&mut self.new_total_supply.to_bytes()?);
+        result.append(&mut self.block_time.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.token_ids.serialized_length()
+            + self.new_total_supply.serialized_length()
+            + self.block_time.serialized_length()
+    }
+}
+
+impl FromBytes for MintReceipt {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (token_ids, remainder) = Vec::<TokenId>::from_bytes(bytes)?;
+        let (new_total_supply, remainder) = U256::from_bytes(remainder)?;
+        let (block_time, remainder) = u64::from_bytes(remainder)?;
+        Ok((
+            MintReceipt {
+                token_ids,
+                new_total_supply,
+                block_time,
+            },
+            remainder,
+        ))
+    }
+}
@@ -0,0 +1,105 @@
+#![no_main]
+#![no_std]
+
+#[macro_use]
+extern crate alloc;
+
+use alloc::{boxed::Box, format, string::String, vec::Vec};
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    contracts::NamedKeys, ApiError, CLType, CLTyped, CLValue, EntryPoint, EntryPointAccess,
+    EntryPointType, EntryPoints, Key, Parameter, U256,
+};
+
+const ON_CEP47_RECEIVED: &str = "on_cep47_received";
+const ON_CEP47_MINTED: &str = "on_cep47_minted";
+
+fn accepts() -> bool {
+    storage::read(
+        runtime::get_key("accept")
+            .unwrap_or_revert()
+            .into_uref()
+            .unwrap_or_revert(),
+    )
+    .unwrap_or_revert()
+    .unwrap_or_revert()
+}
+
+#[no_mangle]
+fn on_cep47_received() {
+    let _operator = runtime::get_named_arg::<Key>("operator");
+    let _from = runtime::get_named_arg::<Key>("from");
+    let _token_ids = runtime::get_named_arg::<Vec<U256>>("token_ids");
+    let ret = if accepts() {
+        String::from(ON_CEP47_RECEIVED)
+    } else {
+        String::from("rejected")
+    };
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn on_cep47_minted() {
+    let _recipient = runtime::get_named_arg::<Key>("recipient");
+    let _token_ids = runtime::get_named_arg::<Vec<U256>>("token_ids");
+    if !accepts() {
+        runtime::revert(ApiError::User(1));
+    }
+}
+
+#[no_mangle]
+fn call() {
+    let contract_name: String = runtime::get_named_arg("contract_name");
+    let accept: bool = runtime::get_named_arg("accept");
+
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(EntryPoint::new(
+        ON_CEP47_RECEIVED,
+        vec![
+            Parameter::new("operator", Key::cl_type()),
+            Parameter::new("from", Key::cl_type()),
+            Parameter::new("token_ids", CLType::List(Box::new(U256::cl_type()))),
+        ],
+        String::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        ON_CEP47_MINTED,
+        vec![
+            Parameter::new("recipient", Key::cl_type()),
+            Parameter::new("token_ids", CLType::List(Box::new(U256::cl_type()))),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(String::from("accept"), storage::new_uref(accept).into());
+
+    let package_key = format!("{}_package_hash", contract_name);
+    let (contract_hash, _) = storage::new_contract(
+        entry_points,
+        Some(named_keys),
+        Some(package_key.clone()),
+        None,
+    );
+    let package_hash = runtime::get_key(&package_key).unwrap_or_revert();
+
+    runtime::put_key(
+        &format!("{}_contract_hash", contract_name),
+        contract_hash.into(),
+    );
+    runtime::put_key(
+        &format!("{}_contract_hash_wrapped", contract_name),
+        storage::new_uref(contract_hash).into(),
+    );
+    runtime::put_key(
+        &format!("{}_package_hash_wrapped", contract_name),
+        storage::new_uref(package_hash).into(),
+    );
+}
@@ -0,0 +1,17 @@
+#![no_main]
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{string::String, vec::Vec};
+use casper_contract::contract_api::{runtime, storage};
+use casper_types::{runtime_args, ContractHash, RuntimeArgs};
+
+#[no_mangle]
+fn call() {
+    let contract_hash: ContractHash = runtime::get_named_arg("contract_hash");
+
+    let names: Vec<String> =
+        runtime::call_contract(contract_hash, "dictionary_names", runtime_args! {});
+    runtime::put_key("dictionary_names_result", storage::new_uref(names).into());
+}
@@ -0,0 +1,58 @@
+#![no_main]
+#![no_std]
+
+#[macro_use]
+extern crate alloc;
+
+use alloc::{
+    collections::BTreeMap,
+    format,
+    string::{String, ToString},
+};
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    CLTyped, CLValue, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Key, Parameter,
+    U256,
+};
+
+type Meta = BTreeMap<String, String>;
+
+#[no_mangle]
+fn compute_meta() {
+    let token_id = runtime::get_named_arg::<U256>("token_id");
+    let owner = runtime::get_named_arg::<Key>("owner");
+    let mut meta = Meta::new();
+    meta.insert("token_id".to_string(), token_id.to_string());
+    meta.insert("holder".to_string(), owner.to_string());
+    runtime::ret(CLValue::from_t(meta).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn call() {
+    let contract_name: String = runtime::get_named_arg("contract_name");
+
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(EntryPoint::new(
+        "compute_meta",
+        vec![
+            Parameter::new("token_id", U256::cl_type()),
+            Parameter::new("owner", Key::cl_type()),
+        ],
+        Meta::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    let (contract_hash, _) = storage::new_contract(entry_points, None, None, None);
+
+    runtime::put_key(
+        &format!("{}_contract_hash", contract_name),
+        contract_hash.into(),
+    );
+    runtime::put_key(
+        &format!("{}_contract_hash_wrapped", contract_name),
+        storage::new_uref(contract_hash).into(),
+    );
+}
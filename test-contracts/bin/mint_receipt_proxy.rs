@@ -0,0 +1,28 @@
+#![no_main]
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use casper_contract::contract_api::{runtime, storage};
+use casper_types::{runtime_args, ContractHash, Key, RuntimeArgs, U256};
+use cep47::{Meta, MintReceipt};
+
+#[no_mangle]
+fn call() {
+    let contract_hash: ContractHash = runtime::get_named_arg("contract_hash");
+    let recipient: Key = runtime::get_named_arg("recipient");
+    let token_ids: Option<Vec<U256>> = runtime::get_named_arg("token_ids");
+    let token_metas: Vec<Meta> = runtime::get_named_arg("token_metas");
+
+    let receipt: MintReceipt = runtime::call_contract(
+        contract_hash,
+        "mint_with_receipt",
+        runtime_args! {
+            "recipient" => recipient,
+            "token_ids" => token_ids,
+            "token_metas" => token_metas
+        },
+    );
+    runtime::put_key("mint_receipt_result", storage::new_uref(receipt).into());
+}
@@ -0,0 +1,24 @@
+#![no_main]
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use casper_contract::contract_api::{runtime, storage};
+use casper_types::{runtime_args, ContractHash, Key, RuntimeArgs, U256};
+use cep47::Meta;
+
+#[no_mangle]
+fn call() {
+    let contract_hash: ContractHash = runtime::get_named_arg("contract_hash");
+    let recipients_and_metas: Vec<(Key, Meta)> = runtime::get_named_arg("recipients_and_metas");
+
+    let minted: Vec<(Key, U256)> = runtime::call_contract(
+        contract_hash,
+        "mint_to_many",
+        runtime_args! {
+            "recipients_and_metas" => recipients_and_metas
+        },
+    );
+    runtime::put_key("mint_to_many_result", storage::new_uref(minted).into());
+}
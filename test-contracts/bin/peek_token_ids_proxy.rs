@@ -0,0 +1,35 @@
+#![no_main]
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use casper_contract::contract_api::{runtime, storage};
+use casper_types::{runtime_args, ContractHash, Key, RuntimeArgs, U256};
+use cep47::Meta;
+
+// Peeks and mints within one deploy, so both run in the same block.
+#[no_mangle]
+fn call() {
+    let contract_hash: ContractHash = runtime::get_named_arg("contract_hash");
+    let recipient: Key = runtime::get_named_arg("recipient");
+    let token_metas: Vec<Meta> = runtime::get_named_arg("token_metas");
+
+    let peeked: Vec<U256> = runtime::call_contract(
+        contract_hash,
+        "peek_token_ids",
+        runtime_args! {
+            "n" => token_metas.len() as u32
+        },
+    );
+    let _: () = runtime::call_contract(
+        contract_hash,
+        "mint",
+        runtime_args! {
+            "recipient" => recipient,
+            "token_ids" => Option::<Vec<U256>>::None,
+            "token_metas" => token_metas
+        },
+    );
+    runtime::put_key("peek_token_ids_result", storage::new_uref(peeked).into());
+}
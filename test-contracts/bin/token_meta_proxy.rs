@@ -0,0 +1,26 @@
+#![no_main]
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{collections::BTreeMap, string::String};
+use casper_contract::contract_api::{runtime, storage};
+use casper_types::{runtime_args, ContractHash, RuntimeArgs, U256};
+
+type Meta = BTreeMap<String, String>;
+
+#[no_mangle]
+fn call() {
+    let contract_hash: ContractHash = runtime::get_named_arg("contract_hash");
+    let entry_point: String = runtime::get_named_arg("entry_point");
+    let token_id: U256 = runtime::get_named_arg("token_id");
+
+    let meta: Option<Meta> = runtime::call_contract(
+        contract_hash,
+        &entry_point,
+        runtime_args! {
+            "token_id" => token_id
+        },
+    );
+    runtime::put_key("token_meta_result", storage::new_uref(meta).into());
+}
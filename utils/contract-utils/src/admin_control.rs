@@ -1,13 +1,28 @@
-use casper_contract::contract_api::runtime;
-use casper_types::{ApiError, Key};
+use alloc::{string::String, vec::Vec};
 
-use crate::{ContractContext, ContractStorage, Dict};
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{bytesrepr::ToBytes, ApiError, Key};
+
+use crate::{get_key, set_key, ContractContext, ContractStorage, Dict};
 
 const ADMINS_DICT: &str = "admins";
+const ADMIN_APPROVALS_DICT: &str = "admin_approvals";
+const ADMIN_LIST: &str = "admin_list";
+const ADMIN_THRESHOLD: &str = "admin_threshold";
+const ADMIN_EPOCH: &str = "admin_epoch";
+const ADMINS_RENOUNCED: &str = "admins_renounced";
+
+// Dictionaries created by AdminControl::init.
+pub const ADMIN_DICTIONARY_NAMES: [&str; 2] = [ADMINS_DICT, ADMIN_APPROVALS_DICT];
 
 pub trait AdminControl<Storage: ContractStorage>: ContractContext<Storage> {
     fn init(&mut self) {
         Admins::init();
+        AdminApprovals::init();
+        set_key(ADMIN_LIST, Vec::<Key>::new());
+        set_key(ADMIN_THRESHOLD, 1u8);
+        set_key(ADMIN_EPOCH, 0u64);
+        set_key(ADMINS_RENOUNCED, false);
     }
 
     fn add_admin(&mut self, address: Key) {
@@ -18,21 +33,117 @@ pub trait AdminControl<Storage: ContractStorage>: ContractContext<Storage> {
     fn disable_admin(&mut self, address: Key) {
         self.assert_caller_is_admin();
         Admins::instance().disable_admin(&address);
+        let mut admins = self.admins();
+        admins.retain(|admin| admin != &address);
+        set_key(ADMIN_LIST, admins);
+        self.bump_admin_epoch();
     }
 
     fn add_admin_without_checked(&mut self, address: Key) {
         Admins::instance().add_admin(&address);
+        let mut admins = self.admins();
+        if !admins.contains(&address) {
+            admins.push(address);
+            set_key(ADMIN_LIST, admins);
+            self.bump_admin_epoch();
+        }
     }
 
     fn assert_caller_is_admin(&self) {
+        self.assert_not_renounced();
         let caller = self.get_caller();
         if !self.is_admin(caller) {
             runtime::revert(ApiError::User(20));
         }
     }
 
+    // Single answer to "may this key act as admin", renunciation included.
     fn is_admin(&self, address: Key) -> bool {
-        Admins::instance().is_admin(&address)
+        !self.admins_renounced() && Admins::instance().is_admin(&address)
+    }
+
+    fn am_i_admin(&self) -> bool {
+        self.is_admin(self.get_caller())
+    }
+
+    fn assert_not_renounced(&self) {
+        if self.admins_renounced() {
+            runtime::revert(ApiError::PermissionDenied);
+        }
+    }
+
+    fn admins_renounced(&self) -> bool {
+        get_key(ADMINS_RENOUNCED).unwrap_or_default()
+    }
+
+    // Irreversible: every admin check fails from now on.
+    fn renounce_admin(&mut self) {
+        set_key(ADMINS_RENOUNCED, true);
+    }
+
+    fn admins(&self) -> Vec<Key> {
+        get_key(ADMIN_LIST).unwrap_or_default()
+    }
+
+    fn admin_threshold(&self) -> u8 {
+        get_key(ADMIN_THRESHOLD).unwrap_or(1)
+    }
+
+    // Replaces the whole admin set.
+    fn set_admins(&mut self, admins: Vec<Key>, threshold: u8) {
+        let mut unique: Vec<Key> = Vec::new();
+        for admin in admins {
+            if !unique.contains(&admin) {
+                unique.push(admin);
+            }
+        }
+        if threshold == 0 || threshold as usize > unique.len() {
+            runtime::revert(ApiError::InvalidArgument);
+        }
+        for admin in self.admins() {
+            Admins::instance().disable_admin(&admin);
+        }
+        for admin in &unique {
+            Admins::instance().add_admin(admin);
+        }
+        set_key(ADMIN_LIST, unique);
+        set_key(ADMIN_THRESHOLD, threshold);
+        self.bump_admin_epoch();
+    }
+
+    // Approvals are keyed by epoch, so any change to the admin set discards pending ones.
+    fn bump_admin_epoch(&mut self) {
+        let epoch: u64 = get_key(ADMIN_EPOCH).unwrap_or_default();
+        set_key(ADMIN_EPOCH, epoch + 1);
+    }
+
+    // Records the caller's approval of `action` with the given serialized args and
+    // returns true once `admin_threshold` distinct admins have approved it.
+    fn approve_admin_action(&mut self, action: &str, args: Vec<u8>) -> bool {
+        self.assert_caller_is_admin();
+        let threshold = self.admin_threshold();
+        if threshold <= 1 {
+            return true;
+        }
+        let epoch: u64 = get_key(ADMIN_EPOCH).unwrap_or_default();
+        let action_id = hex::encode(runtime::blake2b(
+            (String::from(action), args, epoch)
+                .to_bytes()
+                .unwrap_or_revert(),
+        ));
+        let approvals = AdminApprovals::instance();
+        let mut approvers = approvals.get(&action_id);
+        let caller = self.get_caller();
+        if !approvers.contains(&caller) {
+            approvers.push(caller);
+        }
+        if approvers.len() >= threshold as usize {
+            approvals.remove(&action_id);
+            true
+        } else {
+            approvals.set(&action_id, approvers);
+            false
+        }
     }
 }
 
@@ -62,3 +173,30 @@ impl Admins {
         self.dict.remove_by_key::<()>(key);
     }
 }
+
+struct AdminApprovals {
+    dict: Dict,
+}
+
+impl AdminApprovals {
+    pub fn instance() -> AdminApprovals {
+        AdminApprovals {
+            dict: Dict::instance(ADMIN_APPROVALS_DICT),
+        }
+    }
+    pub fn init() {
+        Dict::init(ADMIN_APPROVALS_DICT);
+    }
+
+    pub fn get(&self, action_id: &str) -> Vec<Key> {
+        self.dict.get(action_id).unwrap_or_default()
+    }
+
+    pub fn set(&self, action_id: &str, approvers: Vec<Key>) {
+        self.dict.set(action_id, approvers);
+    }
+
+    pub fn remove(&self, action_id: &str) {
+        self.dict.remove::<Vec<Key>>(action_id);
+    }
+}
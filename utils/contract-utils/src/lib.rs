@@ -8,7 +8,7 @@ mod contract_context;
 mod contract_storage;
 mod data;
 
-pub use admin_control::AdminControl;
+pub use admin_control::{AdminControl, ADMIN_DICTIONARY_NAMES};
 pub use contract_context::ContractContext;
 pub use contract_storage::{ContractStorage, OnChainContractStorage};
 pub use data::{get_key, key_and_value_to_str, key_to_str, set_key, Dict};
@@ -1,7 +1,9 @@
 use std::path::PathBuf;
 
 use casper_types::{
-    account::AccountHash, bytesrepr::FromBytes, CLTyped, ContractHash, RuntimeArgs,
+    account::AccountHash,
+    bytesrepr::{FromBytes, ToBytes},
+    CLTyped, ContractHash, RuntimeArgs,
 };
 
 use crate::{utils::DeploySource, TestEnv};
@@ -40,6 +42,21 @@ impl TestContract {
             .query_dictionary(self.contract_hash(), dict_name, key)
     }
 
+    pub fn write_dictionary_item<T: CLTyped + ToBytes>(
+        &self,
+        dict_name: &str,
+        key: String,
+        value: T,
+    ) {
+        self.env
+            .write_dictionary_item(self.contract_hash(), dict_name, key, value)
+    }
+
+    pub fn dictionary_item_written(&self, dict_name: &str, key: String) -> bool {
+        self.env
+            .dictionary_item_written(self.contract_hash(), dict_name, key)
+    }
+
     pub fn query_named_key<T: CLTyped + FromBytes>(&self, key: String) -> T {
         let contract_name = format!("{}_contract_hash", self.name);
         self.env
@@ -1,8 +1,15 @@
-use std::sync::{Arc, Mutex};
+use std::{
+    collections::BTreeMap,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
 
 use casper_engine_test_support::{InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST};
+use casper_execution_engine::shared::{additive_map::AdditiveMap, transform::Transform};
 use casper_types::{
-    account::AccountHash, bytesrepr::FromBytes, CLTyped, Key, PublicKey, RuntimeArgs, SecretKey,
+    account::AccountHash,
+    bytesrepr::{Bytes, FromBytes, ToBytes},
+    CLType, CLTyped, CLValue, Key, PublicKey, RuntimeArgs, SecretKey, StoredValue, URef, U512,
 };
 
 use crate::utils::{deploy, fund_account, query, query_dictionary_item, DeploySource};
@@ -20,16 +27,30 @@ impl TestEnv {
     }
 
     pub fn run(&self, sender: AccountHash, session_code: DeploySource, session_args: RuntimeArgs) {
+        let mut state = self.state.lock().unwrap();
+        let block_time = state.block_time;
         deploy(
-            &mut self.state.lock().unwrap().builder,
+            &mut state.builder,
             &sender,
             &session_code,
             session_args,
             true,
-            None,
+            block_time,
         )
     }
 
+    pub fn run_session(&self, sender: AccountHash, wasm: &str, session_args: RuntimeArgs) {
+        self.run(
+            sender,
+            DeploySource::Code(PathBuf::from(wasm)),
+            session_args,
+        );
+    }
+
+    pub fn set_block_time(&self, block_time: u64) {
+        self.state.lock().unwrap().block_time = Some(block_time);
+    }
+
     pub fn next_user(&self) -> AccountHash {
         self.state.lock().unwrap().next_user()
     }
@@ -56,6 +77,47 @@ impl TestEnv {
             .unwrap()
             .query_account_named_key(account, path)
     }
+
+    pub fn last_events(&self) -> Vec<BTreeMap<String, String>> {
+        self.state.lock().unwrap().last_events()
+    }
+
+    pub fn last_gas_cost(&self) -> U512 {
+        self.state
+            .lock()
+            .unwrap()
+            .builder
+            .last_exec_gas_cost()
+            .value()
+    }
+
+    pub fn write_dictionary_item<T: CLTyped + ToBytes>(
+        &self,
+        contract_hash: [u8; 32],
+        dict_name: &str,
+        key: String,
+        value: T,
+    ) {
+        self.state.lock().unwrap().write_dictionary_item(
+            contract_hash,
+            dict_name.to_string(),
+            key,
+            value,
+        )
+    }
+
+    pub fn dictionary_item_written(
+        &self,
+        contract_hash: [u8; 32],
+        dict_name: &str,
+        key: String,
+    ) -> bool {
+        self.state.lock().unwrap().dictionary_item_written(
+            contract_hash,
+            dict_name.to_string(),
+            key,
+        )
+    }
 }
 
 impl Default for TestEnv {
@@ -67,6 +129,7 @@ impl Default for TestEnv {
 struct TestEnvState {
     builder: InMemoryWasmTestBuilder,
     accounts: Vec<AccountHash>,
+    block_time: Option<u64>,
 }
 
 impl TestEnvState {
@@ -85,7 +148,11 @@ impl TestEnvState {
                 .commit();
         }
 
-        TestEnvState { builder, accounts }
+        TestEnvState {
+            builder,
+            accounts,
+            block_time: None,
+        }
     }
 
     pub fn _new_with_users(user_secrets: &[[u8; 32]]) -> TestEnvState {
@@ -104,7 +171,11 @@ impl TestEnvState {
                 .commit();
         }
 
-        TestEnvState { builder, accounts }
+        TestEnvState {
+            builder,
+            accounts,
+            block_time: None,
+        }
     }
 
     pub fn next_user(&mut self) -> AccountHash {
@@ -123,7 +194,7 @@ impl TestEnvState {
             &session_code,
             session_args,
             true,
-            None,
+            self.block_time,
         )
     }
 
@@ -159,4 +230,78 @@ impl TestEnvState {
     ) -> T {
         query(&self.builder, Key::Account(account), path)
     }
+
+    pub fn last_events(&self) -> Vec<BTreeMap<String, String>> {
+        let transforms = self.builder.get_transforms();
+        let last = transforms.last().expect("No deploy was executed.");
+        last.iter()
+            .filter_map(|(key, transform)| match (key, transform) {
+                (Key::URef(_), Transform::Write(StoredValue::CLValue(value))) => {
+                    value.clone().into_t::<BTreeMap<String, String>>().ok()
+                }
+                _ => None,
+            })
+            .filter(|event| event.contains_key("event_type"))
+            .collect()
+    }
+
+    pub fn write_dictionary_item<T: CLTyped + ToBytes>(
+        &mut self,
+        contract_hash: [u8; 32],
+        dict_name: String,
+        dictionary_item_key: String,
+        value: T,
+    ) {
+        let dictionary_uref = self.dictionary_uref(contract_hash, &dict_name);
+        let address = Key::dictionary(dictionary_uref, dictionary_item_key.as_bytes());
+
+        // Dictionary items are stored wrapped together with their seed uref and item key.
+        let mut bytes = CLValue::from_t(value).unwrap().to_bytes().unwrap();
+        bytes.append(
+            &mut Bytes::from(dictionary_uref.addr().to_vec())
+                .to_bytes()
+                .unwrap(),
+        );
+        bytes.append(
+            &mut Bytes::from(dictionary_item_key.as_bytes().to_vec())
+                .to_bytes()
+                .unwrap(),
+        );
+        let stored_value = StoredValue::CLValue(CLValue::from_components(CLType::Any, bytes));
+
+        let mut effects = AdditiveMap::new();
+        effects.insert(address, Transform::Write(stored_value));
+        let post_state_hash = self.builder.get_post_state_hash();
+        self.builder.commit_transforms(post_state_hash, effects);
+    }
+
+    pub fn dictionary_item_written(
+        &self,
+        contract_hash: [u8; 32],
+        dict_name: String,
+        dictionary_item_key: String,
+    ) -> bool {
+        let dictionary_uref = self.dictionary_uref(contract_hash, &dict_name);
+        let address = Key::dictionary(dictionary_uref, dictionary_item_key.as_bytes());
+
+        let transforms = self.builder.get_transforms();
+        let last = transforms.last().expect("No deploy was executed.");
+        last.iter()
+            .any(|(key, transform)| *key == address && matches!(transform, Transform::Write(_)))
+    }
+
+    fn dictionary_uref(&self, contract_hash: [u8; 32], dict_name: &str) -> URef {
+        let named_keys = match self
+            .builder
+            .query(None, Key::Hash(contract_hash), &[])
+            .expect("should be stored value.")
+        {
+            StoredValue::Contract(contract) => contract.named_keys().clone(),
+            _ => panic!("Provided key is not a contract"),
+        };
+        *named_keys
+            .get(dict_name)
+            .and_then(Key::as_uref)
+            .expect("No dictionary uref was found in named keys")
+    }
 }